@@ -22,7 +22,10 @@ mod tests {
 
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(&env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        client
+            .mock_all_auths()
+            .initialize(&Address::generate(&env), &native.address());
 
         let owner = Address::generate(&env);
         let spender = Address::generate(&env);
@@ -17,6 +17,7 @@ pub fn compute_spending_summary(
 
     let mut total_deposits: i128 = 0;
     let mut total_withdrawals: i128 = 0;
+    let mut total_refunds: i128 = 0;
     let mut tx_count: u32 = 0;
 
     for tx in txs.iter() {
@@ -32,18 +33,36 @@ pub fn compute_spending_summary(
             TransactionType::Deposit => {
                 total_deposits += tx.amount;
             }
+            TransactionType::Refund => {
+                total_refunds += tx.amount;
+            }
             TransactionType::Withdrawal
             | TransactionType::BountyFunding
-            | TransactionType::MilestonePayment => {
+            | TransactionType::MilestonePayment
+            | TransactionType::StreamFunding
+            | TransactionType::VestingFunding
+            | TransactionType::BatchWithdrawal
+            | TransactionType::ExpensePayment => {
                 total_withdrawals += tx.amount;
             }
-            TransactionType::AllowanceGrant => {}
+            // internal transfers stay in the guild; counting them would
+            // double-count the funds on both treasuries
+            TransactionType::InternalTransfer
+            | TransactionType::InternalTransferReceived
+            | TransactionType::AllowanceGrant
+            | TransactionType::AddSigner
+            | TransactionType::RemoveSigner
+            | TransactionType::RotateSigner
+            | TransactionType::ChangeThreshold
+            | TransactionType::SetHighValueThreshold
+            | TransactionType::SetTimelock
+            | TransactionType::PayrollOrder => {}
         }
     }
 
-    let net_flow = total_deposits - total_withdrawals;
+    let net_flow = total_deposits + total_refunds - total_withdrawals;
     let avg_tx_amount = if tx_count > 0 {
-        (total_deposits + total_withdrawals) / (tx_count as i128)
+        (total_deposits + total_withdrawals + total_refunds) / (tx_count as i128)
     } else {
         0
     };
@@ -52,6 +71,7 @@ pub fn compute_spending_summary(
         treasury_id,
         total_deposits,
         total_withdrawals,
+        total_refunds,
         net_flow,
         tx_count,
         avg_tx_amount,
@@ -116,6 +136,14 @@ pub fn compute_category_breakdown(
     let mut milestone_count: u32 = 0;
     let mut allowance_amount: i128 = 0;
     let mut allowance_count: u32 = 0;
+    let mut stream_amount: i128 = 0;
+    let mut stream_count: u32 = 0;
+    let mut vesting_amount: i128 = 0;
+    let mut vesting_count: u32 = 0;
+    let mut transfer_amount: i128 = 0;
+    let mut transfer_count: u32 = 0;
+    let mut refund_amount: i128 = 0;
+    let mut refund_count: u32 = 0;
 
     for tx in txs.iter() {
         if !is_executed(&tx) {
@@ -129,7 +157,9 @@ pub fn compute_category_breakdown(
                 deposit_amount += tx.amount;
                 deposit_count += 1;
             }
-            TransactionType::Withdrawal => {
+            TransactionType::Withdrawal
+            | TransactionType::BatchWithdrawal
+            | TransactionType::ExpensePayment => {
                 withdrawal_amount += tx.amount;
                 withdrawal_count += 1;
             }
@@ -145,6 +175,30 @@ pub fn compute_category_breakdown(
                 allowance_amount += tx.amount;
                 allowance_count += 1;
             }
+            TransactionType::StreamFunding => {
+                stream_amount += tx.amount;
+                stream_count += 1;
+            }
+            TransactionType::VestingFunding => {
+                vesting_amount += tx.amount;
+                vesting_count += 1;
+            }
+            TransactionType::InternalTransfer => {
+                transfer_amount += tx.amount;
+                transfer_count += 1;
+            }
+            TransactionType::Refund => {
+                refund_amount += tx.amount;
+                refund_count += 1;
+            }
+            TransactionType::InternalTransferReceived
+            | TransactionType::AddSigner
+            | TransactionType::RemoveSigner
+            | TransactionType::RotateSigner
+            | TransactionType::ChangeThreshold
+            | TransactionType::SetHighValueThreshold
+            | TransactionType::SetTimelock
+            | TransactionType::PayrollOrder => {}
         }
     }
 
@@ -185,6 +239,34 @@ pub fn compute_category_breakdown(
             tx_count: allowance_count,
         });
     }
+    if stream_count > 0 {
+        result.push_back(CategoryBreakdown {
+            category_name: String::from_str(env, "StreamFunding"),
+            total_amount: stream_amount,
+            tx_count: stream_count,
+        });
+    }
+    if vesting_count > 0 {
+        result.push_back(CategoryBreakdown {
+            category_name: String::from_str(env, "VestingFunding"),
+            total_amount: vesting_amount,
+            tx_count: vesting_count,
+        });
+    }
+    if transfer_count > 0 {
+        result.push_back(CategoryBreakdown {
+            category_name: String::from_str(env, "InternalTransfer"),
+            total_amount: transfer_amount,
+            tx_count: transfer_count,
+        });
+    }
+    if refund_count > 0 {
+        result.push_back(CategoryBreakdown {
+            category_name: String::from_str(env, "Refund"),
+            total_amount: refund_amount,
+            tx_count: refund_count,
+        });
+    }
 
     result
 }
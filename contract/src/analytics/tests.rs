@@ -4,11 +4,12 @@ mod tests {
         BudgetUtilization, CategoryBreakdown, SpendingForecast, SpendingSummary, SpendingTrend,
         TreasurySnapshot,
     };
+    use crate::guild::types::Role;
     use crate::treasury::types::{TransactionStatus, TransactionType};
     use crate::StellarGuildsContract;
     use crate::StellarGuildsContractClient;
     use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
-    use soroban_sdk::{Address, Env, String, Vec};
+    use soroban_sdk::{token, Address, Env, String, Vec};
 
     fn setup_env() -> Env {
         let env = Env::default();
@@ -32,10 +33,23 @@ mod tests {
     fn register_and_init_contract(env: &Env) -> Address {
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+        client
+            .mock_all_auths()
+            .initialize(&Address::generate(env), &native.address());
         contract_id
     }
 
+    fn mint_native(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        to: &Address,
+        amount: i128,
+    ) {
+        let native = client.get_native_token();
+        token::StellarAssetClient::new(env, &native).mint(to, &amount);
+    }
+
     fn setup_guild(client: &StellarGuildsContractClient<'_>, env: &Env, owner: &Address) -> u64 {
         let name = String::from_str(env, "Test Guild");
         let description = String::from_str(env, "A test guild");
@@ -53,6 +67,15 @@ mod tests {
 
         env.mock_all_auths();
 
+        // only guild admins can create treasuries
+        let guild_owner = client
+            .get_all_members(&guild_id)
+            .iter()
+            .find(|m| m.role == Role::Owner)
+            .unwrap()
+            .address;
+        client.add_member(&guild_id, &owner, &Role::Admin, &guild_owner);
+
         let mut signers = Vec::new(env);
         signers.push_back(owner.clone());
         signers.push_back(signer1.clone());
@@ -79,9 +102,11 @@ mod tests {
 
         // Two deposits at different timestamps
         set_ledger_timestamp(&env, 2000);
+        mint_native(&env, &client, &depositor, 1000i128);
         client.deposit_treasury(&treasury_id, &depositor, &1000i128, &None);
 
         set_ledger_timestamp(&env, 3000);
+        mint_native(&env, &client, &depositor, 500i128);
         client.deposit_treasury(&treasury_id, &depositor, &500i128, &None);
 
         // Query the full period
@@ -110,6 +135,7 @@ mod tests {
 
         // Deposit at timestamp 2000
         set_ledger_timestamp(&env, 2000);
+        mint_native(&env, &client, &depositor, 1000i128);
         client.deposit_treasury(&treasury_id, &depositor, &1000i128, &None);
 
         // Query period that does NOT contain any transactions
@@ -139,6 +165,7 @@ mod tests {
 
         // Deposit
         set_ledger_timestamp(&env, 2000);
+        mint_native(&env, &client, &depositor, 2000i128);
         client.deposit_treasury(&treasury_id, &depositor, &2000i128, &None);
 
         // Propose + approve + execute withdrawal
@@ -175,6 +202,7 @@ mod tests {
         // Set budget and fund treasury
         let category = String::from_str(&env, "withdrawal");
         client.set_budget(&treasury_id, &category, &1000i128, &3600u64, &owner);
+        mint_native(&env, &client, &depositor, 5000i128);
         client.deposit_treasury(&treasury_id, &depositor, &5000i128, &None);
 
         // Spend 300 of 1000 budget (= 30%)
@@ -210,8 +238,10 @@ mod tests {
 
         // Multiple deposits
         set_ledger_timestamp(&env, 2000);
+        mint_native(&env, &client, &depositor, 1000i128);
         client.deposit_treasury(&treasury_id, &depositor, &1000i128, &None);
         set_ledger_timestamp(&env, 2500);
+        mint_native(&env, &client, &depositor, 500i128);
         client.deposit_treasury(&treasury_id, &depositor, &500i128, &None);
 
         let breakdown = client.get_category_breakdown(&treasury_id, &1500u64, &3500u64);
@@ -239,10 +269,12 @@ mod tests {
 
         // Period 1: deposit 1000
         set_ledger_timestamp(&env, 2000);
+        mint_native(&env, &client, &depositor, 1000i128);
         client.deposit_treasury(&treasury_id, &depositor, &1000i128, &None);
 
         // Period 2: deposit 2000 (100% increase)
         set_ledger_timestamp(&env, 5000);
+        mint_native(&env, &client, &depositor, 2000i128);
         client.deposit_treasury(&treasury_id, &depositor, &2000i128, &None);
 
         let trend = client.get_spending_trend(&treasury_id, &1500u64, &3000u64, &4000u64, &6000u64);
@@ -268,14 +300,17 @@ mod tests {
         // Create deposits across multiple periods
         // Period 1 (1000-2000): 1000
         set_ledger_timestamp(&env, 1500);
+        mint_native(&env, &client, &depositor, 1000i128);
         client.deposit_treasury(&treasury_id, &depositor, &1000i128, &None);
 
         // Period 2 (2000-3000): 2000
         set_ledger_timestamp(&env, 2500);
+        mint_native(&env, &client, &depositor, 2000i128);
         client.deposit_treasury(&treasury_id, &depositor, &2000i128, &None);
 
         // Period 3 (3000-4000): 3000
         set_ledger_timestamp(&env, 3500);
+        mint_native(&env, &client, &depositor, 3000i128);
         client.deposit_treasury(&treasury_id, &depositor, &3000i128, &None);
 
         // Forecast from current time = 4000, 3 periods of 1000s each
@@ -305,12 +340,15 @@ mod tests {
 
         // Three deposits should create three snapshots
         set_ledger_timestamp(&env, 2000);
+        mint_native(&env, &client, &depositor, 100i128);
         client.deposit_treasury(&treasury_id, &depositor, &100i128, &None);
 
         set_ledger_timestamp(&env, 3000);
+        mint_native(&env, &client, &depositor, 200i128);
         client.deposit_treasury(&treasury_id, &depositor, &200i128, &None);
 
         set_ledger_timestamp(&env, 4000);
+        mint_native(&env, &client, &depositor, 300i128);
         client.deposit_treasury(&treasury_id, &depositor, &300i128, &None);
 
         let snapshots = client.get_treasury_snapshots(&treasury_id, &10u32);
@@ -343,6 +381,7 @@ mod tests {
         // Create 5 snapshots
         for i in 0u64..5 {
             set_ledger_timestamp(&env, 2000 + i * 1000);
+            mint_native(&env, &client, &depositor, 100i128);
             client.deposit_treasury(&treasury_id, &depositor, &100i128, &None);
         }
 
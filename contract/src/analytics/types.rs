@@ -17,6 +17,8 @@ pub struct SpendingSummary {
     pub treasury_id: u64,
     pub total_deposits: i128,
     pub total_withdrawals: i128,
+    /// Funds returned by bounties, streams and grants, counted in `net_flow`
+    pub total_refunds: i128,
     pub net_flow: i128,
     pub tx_count: u32,
     pub avg_tx_amount: i128,
@@ -18,30 +18,194 @@
 /// | Release escrow      | `(bounty, released)`     | `EscrowReleasedEvent`    |
 /// | Cancel bounty       | `(bounty, cancelled)`    | `BountyCancelledEvent`   |
 /// | Expire bounty       | `(bounty, expired)`      | `BountyExpiredEvent`     |
+/// | Add reward leg      | `(bounty, reward_added)` | `RewardAddedEvent`       |
+/// | Set co-claimants    | `(bounty, splits_set)`   | `SplitsSetEvent`         |
+/// | Release claim       | `(bounty, unclaimed)`    | `ClaimReleasedEvent`     |
+/// | Return claim bond   | `(bounty, bond_returned)`| `BondReturnedEvent`      |
+/// | Slash claim bond    | `(bounty, bond_slashed)` | `BondSlashedEvent`       |
+/// | Template change     | `(bounty, template_set)` | `TemplateUpdatedEvent`   |
+/// | Spawn from template | `(bounty, spawned)`      | `BountySpawnedEvent`     |
+/// | Set reviewers       | `(bounty, reviewers_set)`| `ReviewersSetEvent`      |
+/// | Review submission   | `(bounty, reviewed)`     | `BountyReviewedEvent`    |
+///
+/// A bounty pays its primary `token`/`reward_amount` plus any number of extra
+/// reward legs, each escrowed independently. Release, refunds and dispute splits
+/// apply to every leg; co-claimant splits divide each leg by agreed basis points.
+///
+/// Bounties created with a `ClaimBond` lock that bond from the claimer at claim
+/// time. It is returned on approval, cancellation or a release within the grace
+/// window, and slashed to the funders on abandonment or a lost dispute.
+///
+/// Bounties may name dedicated reviewers and require M-of-N approvals; they are
+/// then completed through `review_bounty` instead of `approve_completion`.
+///
+/// Guilds can store bounty templates (see `recurring`) and spawn bounties from
+/// them on demand or on a recurring schedule driven by a keeper.
+///
+/// Treasury-backed bounties (`treasury_id` set) are funded by a `BountyFunding`
+/// treasury transaction instead of `fund_bounty`; the `(bounty, funded)` event is
+/// emitted when that transaction executes, and refunds go back to the treasury.
 
 pub mod escrow;
+pub mod recurring;
 pub mod storage;
 pub mod types;
 
 use crate::bounty::escrow::{lock_funds, release_funds};
-use crate::bounty::storage::{get_bounty, get_guild_bounties, get_next_bounty_id, store_bounty};
+use crate::bounty::storage::{
+    add_review, get_bounty, get_guild_bounties, get_next_bounty_id, get_reviews, store_bounty,
+};
 use crate::bounty::types::{
-    BountyApprovedEvent, BountyCancelledEvent, BountyClaimedEvent, BountyCreatedEvent,
-    BountyExpiredEvent, BountyFundedEvent, EscrowReleasedEvent, WorkSubmittedEvent,
+    BondReturnedEvent, BondSlashedEvent, BountyApprovedEvent, BountyCancelledEvent,
+    BountyClaimedEvent, BountyCreatedEvent, BountyExpiredEvent, BountyFundedEvent, BountyReview,
+    BountyReviewedEvent, ClaimBond, ClaimReleasedEvent, ClaimantShare, EscrowReleasedEvent,
+    ReviewersSetEvent, RewardAddedEvent, RewardLeg, SplitsSetEvent, WorkSubmittedEvent,
 };
 use crate::dispute::storage as dispute_storage;
 use crate::dispute::types::DisputeReference;
 use crate::events::emit::emit_event;
 use crate::events::topics::{
     ACT_APPROVED, ACT_CANCELLED, ACT_CLAIMED, ACT_CREATED, ACT_EXPIRED, ACT_FUNDED,
-    ACT_RELEASED, ACT_SUBMITTED, MOD_BOUNTY,
+    ACT_RELEASED, ACT_REWARD_ADDED, ACT_SPLITS_SET, ACT_SUBMITTED, ACT_UNCLAIMED, MOD_BOUNTY,
+    ACT_BOND_RETURNED, ACT_BOND_SLASHED, ACT_REVIEWED, ACT_REVIEWERS_SET,
 };
 use crate::guild::membership::has_permission;
 use crate::guild::types::Role;
-use soroban_sdk::{Address, Env, String, Vec};
+use crate::reputation::scoring::record_contribution;
+use crate::reputation::types::ContributionType;
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
 pub use types::{Bounty, BountyStatus};
 
+/// Maximum number of reward legs in addition to the primary token
+const MAX_EXTRA_REWARDS: u32 = 4;
+/// Maximum number of co-claimants sharing one bounty
+const MAX_CO_CLAIMANTS: u32 = 10;
+/// Basis points representing a 100% share
+const FULL_SHARE_BPS: u32 = 10_000;
+/// Maximum number of dedicated reviewers per bounty
+const MAX_REVIEWERS: u32 = 10;
+
+/// All reward legs of a bounty, the primary token first.
+pub fn reward_legs(env: &Env, bounty: &Bounty) -> Vec<RewardLeg> {
+    let mut legs = Vec::new(env);
+    legs.push_back(RewardLeg {
+        token: bounty.token.clone(),
+        amount: bounty.reward_amount,
+        funded_amount: bounty.funded_amount,
+    });
+    for leg in bounty.extra_rewards.iter() {
+        legs.push_back(leg);
+    }
+    legs
+}
+
+/// Whether any reward leg still holds escrowed funds.
+pub fn has_locked_funds(bounty: &Bounty) -> bool {
+    bounty.funded_amount > 0 || bounty.extra_rewards.iter().any(|leg| leg.funded_amount > 0)
+}
+
+/// Zero the escrowed amount of every reward leg (funds have been paid out).
+pub fn clear_escrow(bounty: &mut Bounty) {
+    bounty.funded_amount = 0;
+    let mut i = 0;
+    while i < bounty.extra_rewards.len() {
+        let mut leg = bounty.extra_rewards.get(i).unwrap();
+        leg.funded_amount = 0;
+        bounty.extra_rewards.set(i, leg);
+        i += 1;
+    }
+}
+
+/// Return the locked claim bond to the claimer.
+///
+/// # Events emitted
+/// - `(bounty, bond_returned)` → `BondReturnedEvent`  (if a bond was locked)
+pub fn return_bond(env: &Env, bounty: &mut Bounty) {
+    if bounty.locked_bond <= 0 {
+        return;
+    }
+    let claimer = bounty.claimer.clone().expect("No claimer for this bounty");
+    let amount = bounty.locked_bond;
+
+    release_funds(env, bounty.id, &bounty.token, &claimer, amount);
+    bounty.locked_bond = 0;
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_BOND_RETURNED,
+        BondReturnedEvent {
+            bounty_id: bounty.id,
+            claimer,
+            amount,
+        },
+    );
+}
+
+/// Slash the locked claim bond to the bounty's funders: the backing treasury for
+/// treasury-funded bounties, otherwise the creator.
+///
+/// # Events emitted
+/// - `(bounty, bond_slashed)` → `BondSlashedEvent`  (if a bond was locked)
+pub fn slash_bond(env: &Env, bounty: &mut Bounty) {
+    if bounty.locked_bond <= 0 {
+        return;
+    }
+    let claimer = bounty.claimer.clone().expect("No claimer for this bounty");
+    let amount = bounty.locked_bond;
+
+    let recipient = match bounty.treasury_id {
+        Some(tid) => {
+            crate::treasury::credit_bounty_refund(
+                env,
+                tid,
+                bounty.id,
+                &bounty.token,
+                amount,
+                crate::treasury::TransactionType::Deposit,
+                "bond_slash",
+            );
+            env.current_contract_address()
+        }
+        None => {
+            release_funds(env, bounty.id, &bounty.token, &bounty.creator, amount);
+            bounty.creator.clone()
+        }
+    };
+    bounty.locked_bond = 0;
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_BOND_SLASHED,
+        BondSlashedEvent {
+            bounty_id: bounty.id,
+            claimer,
+            amount,
+            recipient,
+        },
+    );
+}
+
+/// Settle the claim bond once a dispute over the bounty ends: slashed when the
+/// claimer lost, returned otherwise.
+pub fn settle_bond_after_dispute(env: &Env, bounty: &mut Bounty, loser: Option<Address>) {
+    if loser.is_some() && loser == bounty.claimer {
+        slash_bond(env, bounty);
+    } else {
+        return_bond(env, bounty);
+    }
+}
+
+fn is_fully_funded(bounty: &Bounty) -> bool {
+    bounty.funded_amount >= bounty.reward_amount
+        && bounty
+            .extra_rewards
+            .iter()
+            .all(|leg| leg.funded_amount >= leg.amount)
+}
+
 /// Create a new bounty
 ///
 /// # Events emitted
@@ -55,6 +219,8 @@ pub fn create_bounty(
     reward_amount: i128,
     token: Address,
     expiry: u64,
+    treasury_id: Option<u64>,
+    bond: Option<ClaimBond>,
 ) -> u64 {
     creator.require_auth();
 
@@ -69,15 +235,41 @@ pub fn create_bounty(
     if expiry <= created_at {
         panic!("Expiry must be in the future");
     }
-    if title.len() == 0 || title.len() > 256 {
+    if title.is_empty() || title.len() > 256 {
         panic!("Title must be between 1 and 256 characters");
     }
     if description.len() > 2048 {
         panic!("Description must be at most 2048 characters");
     }
+    if let Some(ref b) = bond {
+        if b.amount <= 0 {
+            panic!("Invalid bond amount: must be positive");
+        }
+    }
+
+    if let Some(tid) = treasury_id {
+        let treasury = crate::treasury::storage::get_treasury(env, tid)
+            .expect("Treasury not found");
+        if treasury.guild_id != guild_id {
+            panic!("Treasury does not belong to this guild");
+        }
+    }
 
     let bounty_id = get_next_bounty_id(env);
 
+    // Treasury-backed rewards are drawn through a multisig `BountyFunding` proposal.
+    let funding_tx_id = match treasury_id {
+        Some(tid) if reward_amount > 0 => Some(crate::treasury::propose_bounty_funding(
+            env,
+            tid,
+            creator.clone(),
+            bounty_id,
+            reward_amount,
+            token.clone(),
+        )),
+        _ => None,
+    };
+
     let status = if reward_amount == 0 {
         BountyStatus::Open
     } else {
@@ -98,6 +290,19 @@ pub fn create_bounty(
         submission_url: None,
         created_at,
         expires_at: expiry,
+        treasury_id,
+        funding_tx_id,
+        extra_rewards: Vec::new(env),
+        splits: Vec::new(env),
+        bond_amount: bond.as_ref().map(|b| b.amount).unwrap_or(0),
+        bond_grace_period: bond.as_ref().map(|b| b.grace_period).unwrap_or(0),
+        locked_bond: 0,
+        claimed_at: None,
+        template_id: None,
+        reviewers: Vec::new(env),
+        required_approvals: 0,
+        approvals: Vec::new(env),
+        rejections: Vec::new(env),
     };
     store_bounty(env, &bounty);
 
@@ -118,12 +323,28 @@ pub fn create_bounty(
     bounty_id
 }
 
-/// Fund a bounty with tokens
+/// Fund the primary reward leg of a bounty
 ///
 /// # Events emitted
 /// - `(bounty, funded)`  → `BountyFundedEvent`
 /// - `(bounty, expired)` → `BountyExpiredEvent`  (if bounty found to be expired)
 pub fn fund_bounty(env: &Env, bounty_id: u64, funder: Address, amount: i128) -> bool {
+    let token = get_bounty(env, bounty_id).expect("Bounty not found").token;
+    fund_bounty_token(env, bounty_id, funder, token, amount)
+}
+
+/// Fund the reward leg of a bounty denominated in `token`
+///
+/// # Events emitted
+/// - `(bounty, funded)`  → `BountyFundedEvent`
+/// - `(bounty, expired)` → `BountyExpiredEvent`  (if bounty found to be expired)
+pub fn fund_bounty_token(
+    env: &Env,
+    bounty_id: u64,
+    funder: Address,
+    token: Address,
+    amount: i128,
+) -> bool {
     funder.require_auth();
 
     if amount <= 0 {
@@ -132,6 +353,10 @@ pub fn fund_bounty(env: &Env, bounty_id: u64, funder: Address, amount: i128) ->
 
     let mut bounty = get_bounty(env, bounty_id).expect("Bounty not found");
 
+    if token == bounty.token && bounty.treasury_id.is_some() {
+        panic!("Bounty is funded from treasury");
+    }
+
     let now = env.ledger().timestamp();
     if now > bounty.expires_at {
         bounty.status = BountyStatus::Expired;
@@ -145,11 +370,24 @@ pub fn fund_bounty(env: &Env, bounty_id: u64, funder: Address, amount: i128) ->
         _ => panic!("Bounty cannot be funded in current status"),
     }
 
-    lock_funds(env, &bounty.token, &funder, amount);
-
-    bounty.funded_amount += amount;
-    let is_fully_funded = bounty.funded_amount >= bounty.reward_amount;
+    let total_funded = if token == bounty.token {
+        lock_funds(env, bounty_id, &token, &funder, amount);
+        bounty.funded_amount += amount;
+        bounty.funded_amount
+    } else {
+        let index = bounty
+            .extra_rewards
+            .iter()
+            .position(|leg| leg.token == token)
+            .expect("Token is not a reward of this bounty") as u32;
+        lock_funds(env, bounty_id, &token, &funder, amount);
+        let mut leg = bounty.extra_rewards.get(index).unwrap();
+        leg.funded_amount += amount;
+        bounty.extra_rewards.set(index, leg.clone());
+        leg.funded_amount
+    };
 
+    let is_fully_funded = is_fully_funded(&bounty);
     if is_fully_funded && bounty.status == BountyStatus::AwaitingFunds {
         bounty.status = BountyStatus::Open;
     }
@@ -162,8 +400,9 @@ pub fn fund_bounty(env: &Env, bounty_id: u64, funder: Address, amount: i128) ->
         BountyFundedEvent {
             bounty_id,
             funder,
+            token,
             amount,
-            total_funded: bounty.funded_amount,
+            total_funded,
             is_fully_funded,
         },
     );
@@ -171,6 +410,164 @@ pub fn fund_bounty(env: &Env, bounty_id: u64, funder: Address, amount: i128) ->
     true
 }
 
+/// Add a reward leg in another token to a bounty that has not been claimed yet
+///
+/// The bounty returns to `AwaitingFunds` until the new leg is fully escrowed.
+///
+/// # Events emitted
+/// - `(bounty, reward_added)` → `RewardAddedEvent`
+pub fn add_bounty_reward(
+    env: &Env,
+    bounty_id: u64,
+    caller: Address,
+    token: Address,
+    amount: i128,
+) -> bool {
+    caller.require_auth();
+
+    if amount <= 0 {
+        panic!("Invalid reward amount: must be positive");
+    }
+
+    let mut bounty = get_bounty(env, bounty_id).expect("Bounty not found");
+
+    let is_creator = bounty.creator == caller;
+    if !is_creator && !has_permission(env, bounty.guild_id, caller, Role::Admin) {
+        panic!("Unauthorized: Only creator or guild admin can add rewards");
+    }
+
+    match bounty.status {
+        BountyStatus::AwaitingFunds | BountyStatus::Open => {}
+        _ => panic!("Rewards can only be added before the bounty is claimed"),
+    }
+    if env.ledger().timestamp() > bounty.expires_at {
+        panic!("Bounty has expired");
+    }
+
+    if token == bounty.token || bounty.extra_rewards.iter().any(|leg| leg.token == token) {
+        panic!("Reward token already present");
+    }
+    if bounty.extra_rewards.len() >= MAX_EXTRA_REWARDS {
+        panic!("Too many reward tokens");
+    }
+
+    bounty.extra_rewards.push_back(RewardLeg {
+        token: token.clone(),
+        amount,
+        funded_amount: 0,
+    });
+    bounty.status = BountyStatus::AwaitingFunds;
+    store_bounty(env, &bounty);
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_REWARD_ADDED,
+        RewardAddedEvent {
+            bounty_id,
+            token,
+            amount,
+        },
+    );
+
+    true
+}
+
+/// Credit an executed treasury `BountyFunding` transaction to the bounty escrow.
+///
+/// Called by the treasury after it has debited its own balance; the tokens are
+/// already held by the contract so no transfer takes place.
+///
+/// # Events emitted
+/// - `(bounty, funded)` → `BountyFundedEvent`
+pub fn apply_treasury_funding(
+    env: &Env,
+    bounty_id: u64,
+    treasury_id: u64,
+    token: &Address,
+    amount: i128,
+) {
+    let mut bounty = get_bounty(env, bounty_id).expect("Bounty not found");
+
+    if bounty.treasury_id != Some(treasury_id) {
+        panic!("Bounty is not funded by this treasury");
+    }
+    if bounty.token != *token {
+        panic!("Token mismatch");
+    }
+    if bounty.status != BountyStatus::AwaitingFunds {
+        panic!("Bounty cannot be funded in current status");
+    }
+    if env.ledger().timestamp() > bounty.expires_at {
+        panic!("Bounty has expired");
+    }
+
+    bounty.funded_amount += amount;
+    let is_fully_funded = is_fully_funded(&bounty);
+    if is_fully_funded {
+        bounty.status = BountyStatus::Open;
+    }
+    store_bounty(env, &bounty);
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_FUNDED,
+        BountyFundedEvent {
+            bounty_id,
+            funder: env.current_contract_address(),
+            token: token.clone(),
+            amount,
+            total_funded: bounty.funded_amount,
+            is_fully_funded,
+        },
+    );
+}
+
+/// Return the escrowed funds of a bounty to whoever funded it and clear the
+/// escrow. The primary leg goes back to the backing treasury for treasury-funded
+/// bounties; every other leg is refunded to the creator.
+///
+/// Returns the address that received the primary leg. The caller is responsible
+/// for persisting the bounty.
+pub fn refund_escrow(env: &Env, bounty: &mut Bounty) -> Address {
+    let primary_recipient = match bounty.treasury_id {
+        Some(tid) => {
+            crate::treasury::credit_bounty_refund(
+                env,
+                tid,
+                bounty.id,
+                &bounty.token,
+                bounty.funded_amount,
+                crate::treasury::TransactionType::Refund,
+                "bounty_refund",
+            );
+            env.current_contract_address()
+        }
+        None => {
+            if bounty.funded_amount > 0 {
+                release_funds(
+                    env,
+                    bounty.id,
+                    &bounty.token,
+                    &bounty.creator,
+                    bounty.funded_amount,
+                );
+            }
+            bounty.creator.clone()
+        }
+    };
+
+    for leg in bounty.extra_rewards.iter() {
+        if leg.funded_amount > 0 {
+            release_funds(env, bounty.id, &leg.token, &bounty.creator, leg.funded_amount);
+        }
+    }
+
+    clear_escrow(bounty);
+    primary_recipient
+}
+
 /// Claim a bounty (first-come-first-served)
 ///
 /// # Events emitted
@@ -192,16 +589,138 @@ pub fn claim_bounty(env: &Env, bounty_id: u64, claimer: Address) -> bool {
     if bounty.status != BountyStatus::Open {
         panic!("Bounty is not open for claiming");
     }
+    if bounty.reviewers.contains(&claimer) {
+        panic!("Reviewers cannot claim this bounty");
+    }
+
+    let bond_amount = bounty.bond_amount;
+    if bond_amount > 0 {
+        lock_funds(env, bounty_id, &bounty.token, &claimer, bond_amount);
+    }
 
     bounty.status = BountyStatus::Claimed;
     bounty.claimer = Some(claimer.clone());
+    bounty.locked_bond = bond_amount;
+    bounty.claimed_at = Some(now);
     store_bounty(env, &bounty);
 
     emit_event(
         env,
         MOD_BOUNTY,
         ACT_CLAIMED,
-        BountyClaimedEvent { bounty_id, claimer },
+        BountyClaimedEvent {
+            bounty_id,
+            claimer,
+            bond_amount,
+        },
+    );
+
+    true
+}
+
+/// Voluntarily give up a claim and reopen the bounty
+///
+/// The claim bond is returned if released within the bond's grace window and
+/// slashed to the funders otherwise.
+///
+/// # Events emitted
+/// - `(bounty, unclaimed)`     → `ClaimReleasedEvent`
+/// - `(bounty, bond_returned)` → `BondReturnedEvent`  (within the grace window)
+/// - `(bounty, bond_slashed)`  → `BondSlashedEvent`   (after the grace window)
+pub fn release_claim(env: &Env, bounty_id: u64, claimer: Address) -> bool {
+    claimer.require_auth();
+
+    if dispute_storage::is_reference_locked(env, &DisputeReference::Bounty, bounty_id) {
+        panic!("Bounty is in active dispute");
+    }
+
+    let mut bounty = get_bounty(env, bounty_id).expect("Bounty not found");
+
+    if bounty.status != BountyStatus::Claimed {
+        panic!("Bounty is not in claimed status");
+    }
+    if bounty.claimer != Some(claimer.clone()) {
+        panic!("Unauthorized: Only the claimer can release the claim");
+    }
+
+    let claimed_at = bounty.claimed_at.unwrap_or(0);
+    if env.ledger().timestamp() <= claimed_at + bounty.bond_grace_period {
+        return_bond(env, &mut bounty);
+    } else {
+        slash_bond(env, &mut bounty);
+    }
+
+    bounty.status = BountyStatus::Open;
+    bounty.claimer = None;
+    bounty.claimed_at = None;
+    bounty.splits = Vec::new(env);
+    store_bounty(env, &bounty);
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_UNCLAIMED,
+        ClaimReleasedEvent { bounty_id, claimer },
+    );
+
+    true
+}
+
+/// Share a claimed bounty between a small team by agreed percentages
+///
+/// Every co-claimant, including the original claimer, must authorize the split.
+/// Shares are in basis points and must total 10000.
+///
+/// # Events emitted
+/// - `(bounty, splits_set)` → `SplitsSetEvent`
+pub fn set_bounty_splits(env: &Env, bounty_id: u64, splits: Vec<ClaimantShare>) -> bool {
+    let mut bounty = get_bounty(env, bounty_id).expect("Bounty not found");
+
+    let claimer = bounty.claimer.clone().expect("No claimer for this bounty");
+    if bounty.status != BountyStatus::Claimed {
+        panic!("Bounty is not in claimed status");
+    }
+
+    if splits.is_empty() || splits.len() > MAX_CO_CLAIMANTS {
+        panic!("Invalid splits: between 1 and 10 co-claimants required");
+    }
+
+    let mut total_bps: u32 = 0;
+    let mut includes_claimer = false;
+    for (i, share) in splits.iter().enumerate() {
+        if share.share_bps == 0 {
+            panic!("Invalid splits: share must be positive");
+        }
+        if splits.iter().skip(i + 1).any(|s| s.claimer == share.claimer) {
+            panic!("Invalid splits: duplicate co-claimant");
+        }
+        if share.claimer == claimer {
+            includes_claimer = true;
+        }
+        if bounty.reviewers.contains(&share.claimer) {
+            panic!("Invalid splits: reviewers cannot be co-claimants");
+        }
+        total_bps += share.share_bps;
+    }
+    if !includes_claimer {
+        panic!("Invalid splits: claimer must be included");
+    }
+    if total_bps != FULL_SHARE_BPS {
+        panic!("Invalid splits: shares must total 10000 bps");
+    }
+
+    for share in splits.iter() {
+        share.claimer.require_auth();
+    }
+
+    bounty.splits = splits.clone();
+    store_bounty(env, &bounty);
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_SPLITS_SET,
+        SplitsSetEvent { bounty_id, splits },
     );
 
     true
@@ -226,6 +745,8 @@ pub fn submit_work(env: &Env, bounty_id: u64, submission_url: String) -> bool {
 
     bounty.status = BountyStatus::UnderReview;
     bounty.submission_url = Some(submission_url.clone());
+    bounty.approvals = Vec::new(env);
+    bounty.rejections = Vec::new(env);
     store_bounty(env, &bounty);
 
     emit_event(
@@ -257,8 +778,12 @@ pub fn approve_completion(env: &Env, bounty_id: u64, approver: Address) -> bool
     if bounty.status != BountyStatus::UnderReview {
         panic!("Bounty is not under review");
     }
+    if !bounty.reviewers.is_empty() {
+        panic!("Bounty requires reviewer approval");
+    }
 
     bounty.status = BountyStatus::Completed;
+    return_bond(env, &mut bounty);
     store_bounty(env, &bounty);
 
     emit_event(
@@ -271,6 +796,181 @@ pub fn approve_completion(env: &Env, bounty_id: u64, approver: Address) -> bool
     true
 }
 
+/// Name the dedicated reviewers of a bounty and how many of them must approve
+///
+/// Can be changed until work is submitted. Neither the claimer nor any
+/// co-claimant may be a reviewer.
+///
+/// # Events emitted
+/// - `(bounty, reviewers_set)` → `ReviewersSetEvent`
+pub fn set_bounty_reviewers(
+    env: &Env,
+    bounty_id: u64,
+    caller: Address,
+    reviewers: Vec<Address>,
+    required_approvals: u32,
+) -> bool {
+    caller.require_auth();
+
+    let mut bounty = get_bounty(env, bounty_id).expect("Bounty not found");
+
+    let is_creator = bounty.creator == caller;
+    if !is_creator && !has_permission(env, bounty.guild_id, caller, Role::Admin) {
+        panic!("Unauthorized: Only creator or guild admin can set reviewers");
+    }
+
+    match bounty.status {
+        BountyStatus::AwaitingFunds | BountyStatus::Open | BountyStatus::Claimed => {}
+        _ => panic!("Reviewers cannot be changed in current status"),
+    }
+
+    if reviewers.is_empty() || reviewers.len() > MAX_REVIEWERS {
+        panic!("Invalid reviewers: between 1 and 10 reviewers required");
+    }
+    if required_approvals == 0 || required_approvals > reviewers.len() {
+        panic!("Invalid reviewers: required approvals out of range");
+    }
+    for (i, reviewer) in reviewers.iter().enumerate() {
+        if reviewers.iter().skip(i + 1).any(|r| r == reviewer) {
+            panic!("Invalid reviewers: duplicate reviewer");
+        }
+        if bounty.claimer == Some(reviewer.clone())
+            || bounty.splits.iter().any(|s| s.claimer == reviewer)
+        {
+            panic!("Invalid reviewers: claimer cannot review");
+        }
+    }
+
+    bounty.reviewers = reviewers.clone();
+    bounty.required_approvals = required_approvals;
+    store_bounty(env, &bounty);
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_REVIEWERS_SET,
+        ReviewersSetEvent {
+            bounty_id,
+            reviewers,
+            required_approvals,
+        },
+    );
+
+    true
+}
+
+/// Record a reviewer's approval or rejection of the submitted work
+///
+/// The bounty completes once `required_approvals` reviewers approve. Once so
+/// many reviewers reject that the remaining ones can no longer reach
+/// `required_approvals`, the bounty goes back to `Claimed` so the claimer can
+/// resubmit, discarding the reviews of the rejected submission. A reviewer's
+/// first review of a bounty earns them a `BountyReviewed` reputation
+/// contribution.
+///
+/// # Events emitted
+/// - `(bounty, reviewed)` → `BountyReviewedEvent`
+/// - `(bounty, approved)` → `BountyApprovedEvent`  (when the threshold is reached)
+pub fn review_bounty(
+    env: &Env,
+    bounty_id: u64,
+    reviewer: Address,
+    approve: bool,
+    comment_hash: BytesN<32>,
+) -> bool {
+    reviewer.require_auth();
+
+    let mut bounty = get_bounty(env, bounty_id).expect("Bounty not found");
+
+    if bounty.reviewers.is_empty() {
+        panic!("Bounty has no dedicated reviewers");
+    }
+    if !bounty.reviewers.contains(&reviewer) {
+        panic!("Unauthorized: Not a reviewer of this bounty");
+    }
+    if bounty.status != BountyStatus::UnderReview {
+        panic!("Bounty is not under review");
+    }
+    if bounty.approvals.contains(&reviewer) {
+        panic!("Reviewer already approved this submission");
+    }
+    if bounty.rejections.contains(&reviewer) {
+        panic!("Reviewer already rejected this submission");
+    }
+    // reputation is awarded once per reviewer and bounty, however many
+    // rounds of review the bounty goes through
+    let first_review = !get_reviews(env, bounty_id)
+        .iter()
+        .any(|review| review.reviewer == reviewer);
+
+    add_review(
+        env,
+        bounty_id,
+        &BountyReview {
+            reviewer: reviewer.clone(),
+            approved: approve,
+            comment_hash: comment_hash.clone(),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    let completed = if approve {
+        bounty.approvals.push_back(reviewer.clone());
+        bounty.approvals.len() >= bounty.required_approvals
+    } else {
+        bounty.rejections.push_back(reviewer.clone());
+        let remaining = bounty.reviewers.len() - bounty.rejections.len();
+        if remaining < bounty.required_approvals {
+            bounty.status = BountyStatus::Claimed;
+            bounty.approvals = Vec::new(env);
+            bounty.rejections = Vec::new(env);
+        }
+        false
+    };
+
+    if completed {
+        bounty.status = BountyStatus::Completed;
+        return_bond(env, &mut bounty);
+    }
+    store_bounty(env, &bounty);
+
+    if first_review {
+        record_contribution(
+            env,
+            bounty.guild_id,
+            &reviewer,
+            ContributionType::BountyReviewed,
+            bounty_id,
+        );
+    }
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_REVIEWED,
+        BountyReviewedEvent {
+            bounty_id,
+            reviewer: reviewer.clone(),
+            approved: approve,
+            comment_hash,
+        },
+    );
+
+    if completed {
+        emit_event(
+            env,
+            MOD_BOUNTY,
+            ACT_APPROVED,
+            BountyApprovedEvent {
+                bounty_id,
+                approver: reviewer,
+            },
+        );
+    }
+
+    true
+}
+
 /// Release escrow funds to the bounty claimer
 ///
 /// # Events emitted
@@ -288,29 +988,59 @@ pub fn release_escrow(env: &Env, bounty_id: u64) -> bool {
 
     let claimer = bounty.claimer.clone().expect("No claimer for this bounty");
 
-    if bounty.funded_amount > 0 {
-        let amount = bounty.funded_amount;
-        release_funds(env, &bounty.token, &claimer, amount);
-        bounty.funded_amount = 0;
-        store_bounty(env, &bounty);
+    if !has_locked_funds(&bounty) {
+        return true;
+    }
 
-        emit_event(
-            env,
-            MOD_BOUNTY,
-            ACT_RELEASED,
-            EscrowReleasedEvent {
-                bounty_id,
-                recipient: claimer,
-                amount,
-                token: bounty.token,
-            },
-        );
+    let mut shares = bounty.splits.clone();
+    if shares.is_empty() {
+        shares.push_back(ClaimantShare {
+            claimer,
+            share_bps: FULL_SHARE_BPS,
+        });
+    }
+
+    for leg in reward_legs(env, &bounty).iter() {
+        if leg.funded_amount <= 0 {
+            continue;
+        }
+
+        // The last co-claimant absorbs rounding dust so each leg is paid out in full.
+        let mut remaining = leg.funded_amount;
+        let last = shares.len() - 1;
+        for (i, share) in shares.iter().enumerate() {
+            let amount = if i as u32 == last {
+                remaining
+            } else {
+                leg.funded_amount * share.share_bps as i128 / FULL_SHARE_BPS as i128
+            };
+            remaining -= amount;
+            if amount == 0 {
+                continue;
+            }
+
+            release_funds(env, bounty_id, &leg.token, &share.claimer, amount);
+            emit_event(
+                env,
+                MOD_BOUNTY,
+                ACT_RELEASED,
+                EscrowReleasedEvent {
+                    bounty_id,
+                    recipient: share.claimer,
+                    amount,
+                    token: leg.token.clone(),
+                },
+            );
+        }
     }
 
+    clear_escrow(&mut bounty);
+    store_bounty(env, &bounty);
+
     true
 }
 
-/// Cancel a bounty and refund escrowed funds to the creator
+/// Cancel a bounty and refund escrowed funds to the creator (or backing treasury)
 ///
 /// # Events emitted
 /// - `(bounty, cancelled)` → `BountyCancelledEvent`
@@ -337,12 +1067,18 @@ pub fn cancel_bounty(env: &Env, bounty_id: u64, canceller: Address) -> bool {
         panic!("Unauthorized: Only creator or guild admin can cancel");
     }
 
+    // The claimer is not at fault when the bounty is withdrawn.
+    return_bond(env, &mut bounty);
+
     let refund_amount = bounty.funded_amount;
-    let refund_recipient = bounty.creator.clone();
+    let refund_recipient = if has_locked_funds(&bounty) {
+        refund_escrow(env, &mut bounty)
+    } else {
+        bounty.creator.clone()
+    };
 
-    if refund_amount > 0 {
-        release_funds(env, &bounty.token, &refund_recipient, refund_amount);
-        bounty.funded_amount = 0;
+    if let Some(tx_id) = bounty.funding_tx_id {
+        crate::treasury::withdraw_bounty_funding(env, tx_id);
     }
 
     bounty.status = BountyStatus::Cancelled;
@@ -386,9 +1122,19 @@ pub fn expire_bounty(env: &Env, bounty_id: u64) -> bool {
         return false;
     }
 
-    if bounty.funded_amount > 0 {
-        release_funds(env, &bounty.token, &bounty.creator, bounty.funded_amount);
-        bounty.funded_amount = 0;
+    // A claim that never produced a submission before expiry counts as abandoned.
+    if bounty.status == BountyStatus::Claimed {
+        slash_bond(env, &mut bounty);
+    } else {
+        return_bond(env, &mut bounty);
+    }
+
+    if has_locked_funds(&bounty) {
+        refund_escrow(env, &mut bounty);
+    }
+
+    if let Some(tx_id) = bounty.funding_tx_id {
+        crate::treasury::withdraw_bounty_funding(env, tx_id);
     }
 
     bounty.status = BountyStatus::Expired;
@@ -409,6 +1155,10 @@ pub fn get_guild_bounties_list(env: &Env, guild_id: u64) -> Vec<Bounty> {
     get_guild_bounties(env, guild_id)
 }
 
+pub fn get_bounty_reviews(env: &Env, bounty_id: u64) -> Vec<BountyReview> {
+    get_reviews(env, bounty_id)
+}
+
 #[allow(dead_code)]
 pub fn cancel_bounty_auth(env: &Env, bounty_id: u64, canceller: Address) -> bool {
     cancel_bounty(env, bounty_id, canceller)
@@ -0,0 +1,313 @@
+use crate::bounty::storage::{
+    get_guild_bounties, get_guild_templates, get_next_bounty_id, get_next_template_id,
+    get_template, store_bounty, store_template,
+};
+use crate::bounty::types::{
+    Bounty, BountyCreatedEvent, BountySpawnedEvent, BountyStatus, BountyTemplate,
+    TemplateUpdatedEvent,
+};
+use crate::events::emit::emit_event;
+use crate::events::topics::{ACT_CREATED, ACT_SPAWNED, ACT_TEMPLATE_SET, MOD_BOUNTY};
+use crate::guild::membership::has_permission;
+use crate::guild::types::Role;
+use soroban_sdk::{Address, Env, String, Vec};
+
+/// Create a bounty template for a guild
+///
+/// # Events emitted
+/// - `(bounty, template_set)` → `TemplateUpdatedEvent`
+#[allow(clippy::too_many_arguments)]
+pub fn create_template(
+    env: &Env,
+    guild_id: u64,
+    creator: Address,
+    title: String,
+    description: String,
+    reward_amount: i128,
+    token: Address,
+    duration: u64,
+    treasury_id: Option<u64>,
+) -> u64 {
+    creator.require_auth();
+
+    if !has_permission(env, guild_id, creator.clone(), Role::Admin) {
+        panic!("Unauthorized: Creator must be a guild admin or owner");
+    }
+    if reward_amount < 0 {
+        panic!("Invalid reward amount: must be non-negative");
+    }
+    if duration == 0 {
+        panic!("Duration must be positive");
+    }
+    if title.is_empty() || title.len() > 256 {
+        panic!("Title must be between 1 and 256 characters");
+    }
+    if description.len() > 2048 {
+        panic!("Description must be at most 2048 characters");
+    }
+    if let Some(tid) = treasury_id {
+        let treasury =
+            crate::treasury::storage::get_treasury(env, tid).expect("Treasury not found");
+        if treasury.guild_id != guild_id {
+            panic!("Treasury does not belong to this guild");
+        }
+    }
+
+    let template = BountyTemplate {
+        id: get_next_template_id(env),
+        guild_id,
+        creator,
+        title,
+        description,
+        reward_amount,
+        token,
+        duration,
+        treasury_id,
+        period_seconds: 0,
+        next_spawn_at: 0,
+        active: true,
+        spawned_count: 0,
+    };
+    store_template(env, &template);
+    emit_template_updated(env, &template);
+
+    template.id
+}
+
+/// Set (or clear, with `period_seconds == 0`) the recurrence of a template
+///
+/// # Events emitted
+/// - `(bounty, template_set)` → `TemplateUpdatedEvent`
+pub fn schedule_template(
+    env: &Env,
+    template_id: u64,
+    caller: Address,
+    period_seconds: u64,
+    first_spawn_at: u64,
+) -> bool {
+    caller.require_auth();
+
+    let mut template = get_template(env, template_id).expect("Template not found");
+    if !has_permission(env, template.guild_id, caller, Role::Admin) {
+        panic!("Unauthorized: Caller must be a guild admin or owner");
+    }
+    if !template.active {
+        panic!("Template is inactive");
+    }
+
+    template.period_seconds = period_seconds;
+    template.next_spawn_at = first_spawn_at;
+    store_template(env, &template);
+    emit_template_updated(env, &template);
+
+    true
+}
+
+/// Deactivate a template so it no longer spawns bounties
+///
+/// # Events emitted
+/// - `(bounty, template_set)` → `TemplateUpdatedEvent`
+pub fn deactivate_template(env: &Env, template_id: u64, caller: Address) -> bool {
+    caller.require_auth();
+
+    let mut template = get_template(env, template_id).expect("Template not found");
+    if !has_permission(env, template.guild_id, caller, Role::Admin) {
+        panic!("Unauthorized: Caller must be a guild admin or owner");
+    }
+
+    template.active = false;
+    template.period_seconds = 0;
+    store_template(env, &template);
+    emit_template_updated(env, &template);
+
+    true
+}
+
+/// Spawn a bounty from a template on demand
+///
+/// # Events emitted
+/// - `(bounty, created)` → `BountyCreatedEvent`
+/// - `(bounty, spawned)` → `BountySpawnedEvent`
+pub fn spawn_from_template(env: &Env, template_id: u64, caller: Address) -> u64 {
+    caller.require_auth();
+
+    let mut template = get_template(env, template_id).expect("Template not found");
+    if !has_permission(env, template.guild_id, caller, Role::Admin) {
+        panic!("Unauthorized: Caller must be a guild admin or owner");
+    }
+    if !template.active {
+        panic!("Template is inactive");
+    }
+
+    let bounty_id = spawn(env, &mut template);
+    store_template(env, &template);
+    bounty_id
+}
+
+/// Keeper entrypoint: spawn the next scheduled bounty of a recurring template
+///
+/// Anyone may call this once `next_spawn_at` has passed. Missed periods are
+/// skipped rather than back-filled, so at most one bounty is spawned per call.
+/// Fails once the template's creator is no longer a guild admin.
+///
+/// # Events emitted
+/// - `(bounty, created)` → `BountyCreatedEvent`
+/// - `(bounty, spawned)` → `BountySpawnedEvent`
+pub fn run_schedule(env: &Env, template_id: u64) -> u64 {
+    let mut template = get_template(env, template_id).expect("Template not found");
+
+    if !template.active || template.period_seconds == 0 {
+        panic!("Template is not scheduled");
+    }
+
+    let now = env.ledger().timestamp();
+    if now < template.next_spawn_at {
+        panic!("Next bounty is not due yet");
+    }
+
+    let bounty_id = spawn(env, &mut template);
+
+    let elapsed = now - template.next_spawn_at;
+    template.next_spawn_at = now - elapsed % template.period_seconds + template.period_seconds;
+    store_template(env, &template);
+
+    bounty_id
+}
+
+/// Get all bounties spawned from a template
+pub fn get_template_bounties(env: &Env, template_id: u64) -> Vec<Bounty> {
+    let template = get_template(env, template_id).expect("Template not found");
+
+    let mut result = Vec::new(env);
+    for bounty in get_guild_bounties(env, template.guild_id).iter() {
+        if bounty.template_id == Some(template_id) {
+            result.push_back(bounty);
+        }
+    }
+    result
+}
+
+pub fn get_template_data(env: &Env, template_id: u64) -> BountyTemplate {
+    get_template(env, template_id).expect("Template not found")
+}
+
+pub fn get_guild_templates_list(env: &Env, guild_id: u64) -> Vec<BountyTemplate> {
+    get_guild_templates(env, guild_id)
+}
+
+/// Create a fresh bounty from `template`, auto-funding it from the template's
+/// treasury allowance when configured. The caller persists the template.
+fn spawn(env: &Env, template: &mut BountyTemplate) -> u64 {
+    // Spawned bounties and their funding act in the creator's name, so a
+    // creator who has since lost admin rights can no longer spend through them.
+    if !has_permission(
+        env,
+        template.guild_id,
+        template.creator.clone(),
+        Role::Admin,
+    ) {
+        panic!("Unauthorized: Template creator is no longer a guild admin");
+    }
+
+    let bounty_id = get_next_bounty_id(env);
+    let created_at = env.ledger().timestamp();
+    let expires_at = created_at + template.duration;
+
+    let status = if template.reward_amount == 0 {
+        BountyStatus::Open
+    } else {
+        BountyStatus::AwaitingFunds
+    };
+
+    let bounty = Bounty {
+        id: bounty_id,
+        guild_id: template.guild_id,
+        creator: template.creator.clone(),
+        title: template.title.clone(),
+        description: template.description.clone(),
+        reward_amount: template.reward_amount,
+        funded_amount: 0,
+        token: template.token.clone(),
+        status,
+        claimer: None,
+        submission_url: None,
+        created_at,
+        expires_at,
+        treasury_id: template.treasury_id,
+        funding_tx_id: None,
+        extra_rewards: Vec::new(env),
+        splits: Vec::new(env),
+        bond_amount: 0,
+        bond_grace_period: 0,
+        locked_bond: 0,
+        claimed_at: None,
+        template_id: Some(template.id),
+        reviewers: Vec::new(env),
+        required_approvals: 0,
+        approvals: Vec::new(env),
+        rejections: Vec::new(env),
+    };
+    store_bounty(env, &bounty);
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_CREATED,
+        BountyCreatedEvent {
+            bounty_id,
+            guild_id: template.guild_id,
+            creator: template.creator.clone(),
+            reward_amount: template.reward_amount,
+            token: template.token.clone(),
+            expires_at,
+        },
+    );
+
+    let mut funded = false;
+    if let Some(tid) = template.treasury_id {
+        if template.reward_amount > 0 {
+            let tx_id = crate::treasury::fund_bounty_from_allowance(
+                env,
+                tid,
+                &template.creator,
+                bounty_id,
+                &template.token,
+                template.reward_amount,
+            );
+            let mut bounty = crate::bounty::get_bounty_data(env, bounty_id);
+            bounty.funding_tx_id = Some(tx_id);
+            store_bounty(env, &bounty);
+            funded = true;
+        }
+    }
+
+    template.spawned_count += 1;
+
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_SPAWNED,
+        BountySpawnedEvent {
+            template_id: template.id,
+            bounty_id,
+            funded,
+        },
+    );
+
+    bounty_id
+}
+
+fn emit_template_updated(env: &Env, template: &BountyTemplate) {
+    emit_event(
+        env,
+        MOD_BOUNTY,
+        ACT_TEMPLATE_SET,
+        TemplateUpdatedEvent {
+            template_id: template.id,
+            guild_id: template.guild_id,
+            period_seconds: template.period_seconds,
+            next_spawn_at: template.next_spawn_at,
+            active: template.active,
+        },
+    );
+}
@@ -1,10 +1,14 @@
-use crate::bounty::types::Bounty;
+use crate::bounty::types::{Bounty, BountyReview, BountyTemplate};
 use soroban_sdk::{symbol_short, Env, Map, Symbol, Vec};
 
 // Storage keys
 const BOUNTIES_KEY: Symbol = symbol_short!("bounties");
 const BOUNTY_CNT_KEY: Symbol = symbol_short!("b_cnt");
 const GUILD_BOUNTIES_KEY: Symbol = symbol_short!("g_bnties");
+const TEMPLATES_KEY: Symbol = symbol_short!("b_tmpls");
+const TEMPLATE_CNT_KEY: Symbol = symbol_short!("b_tcnt");
+const GUILD_TEMPLATES_KEY: Symbol = symbol_short!("g_btmpls");
+const REVIEWS_KEY: Symbol = symbol_short!("b_revws");
 
 /// Initialize bounty storage
 #[allow(dead_code)]
@@ -98,3 +102,111 @@ pub fn get_guild_bounties(env: &Env, guild_id: u64) -> Vec<Bounty> {
     }
     result
 }
+
+/// Get the next bounty template ID and increment
+pub fn get_next_template_id(env: &Env) -> u64 {
+    let counter: u64 = env
+        .storage()
+        .persistent()
+        .get(&TEMPLATE_CNT_KEY)
+        .unwrap_or(0u64);
+
+    let next_id = counter + 1;
+    env.storage().persistent().set(&TEMPLATE_CNT_KEY, &next_id);
+
+    next_id
+}
+
+/// Store a bounty template and update the guild index
+pub fn store_template(env: &Env, template: &BountyTemplate) {
+    let mut templates: Map<u64, BountyTemplate> = env
+        .storage()
+        .persistent()
+        .get(&TEMPLATES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let is_new = !templates.contains_key(template.id);
+    templates.set(template.id, template.clone());
+    env.storage().persistent().set(&TEMPLATES_KEY, &templates);
+
+    if is_new {
+        let mut guild_templates: Map<u64, Vec<u64>> = env
+            .storage()
+            .persistent()
+            .get(&GUILD_TEMPLATES_KEY)
+            .unwrap_or_else(|| Map::new(env));
+
+        let mut list = guild_templates
+            .get(template.guild_id)
+            .unwrap_or_else(|| Vec::new(env));
+
+        list.push_back(template.id);
+        guild_templates.set(template.guild_id, list);
+        env.storage()
+            .persistent()
+            .set(&GUILD_TEMPLATES_KEY, &guild_templates);
+    }
+}
+
+/// Get a bounty template by ID
+pub fn get_template(env: &Env, template_id: u64) -> Option<BountyTemplate> {
+    let templates: Map<u64, BountyTemplate> = env
+        .storage()
+        .persistent()
+        .get(&TEMPLATES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    templates.get(template_id)
+}
+
+/// Get all bounty templates for a guild
+pub fn get_guild_templates(env: &Env, guild_id: u64) -> Vec<BountyTemplate> {
+    let guild_templates: Map<u64, Vec<u64>> = env
+        .storage()
+        .persistent()
+        .get(&GUILD_TEMPLATES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let ids = guild_templates
+        .get(guild_id)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let templates: Map<u64, BountyTemplate> = env
+        .storage()
+        .persistent()
+        .get(&TEMPLATES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let mut result = Vec::new(env);
+    for id in ids.iter() {
+        if let Some(t) = templates.get(id) {
+            result.push_back(t);
+        }
+    }
+    result
+}
+
+/// Append a reviewer decision to a bounty's review log
+pub fn add_review(env: &Env, bounty_id: u64, review: &BountyReview) {
+    let mut reviews: Map<u64, Vec<BountyReview>> = env
+        .storage()
+        .persistent()
+        .get(&REVIEWS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let mut list = reviews.get(bounty_id).unwrap_or_else(|| Vec::new(env));
+    list.push_back(review.clone());
+    reviews.set(bounty_id, list);
+    env.storage().persistent().set(&REVIEWS_KEY, &reviews);
+}
+
+/// Get all reviewer decisions recorded for a bounty
+pub fn get_reviews(env: &Env, bounty_id: u64) -> Vec<BountyReview> {
+    let reviews: Map<u64, Vec<BountyReview>> = env
+        .storage()
+        .persistent()
+        .get(&REVIEWS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    reviews.get(bounty_id).unwrap_or_else(|| Vec::new(env))
+}
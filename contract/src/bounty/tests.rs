@@ -6,12 +6,13 @@
 //! NOTE: These tests use the contract client to test through the main lib.rs
 //! contract interface, ensuring proper contract context execution.
 
-use crate::bounty::types::BountyStatus;
+use crate::bounty::types::{BountyStatus, ClaimBond, ClaimantShare};
 use crate::guild::types::Role;
+use crate::reputation::types::ContributionType;
 use crate::StellarGuildsContract;
 use crate::StellarGuildsContractClient;
 use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
-use soroban_sdk::{token, Address, Env, String};
+use soroban_sdk::{token, Address, BytesN, Env, String};
 
 // ============ Test Helpers ============
 
@@ -37,7 +38,10 @@ fn set_ledger_timestamp(env: &Env, timestamp: u64) {
 fn register_and_init_contract(env: &Env) -> Address {
     let contract_id = env.register_contract(None, StellarGuildsContract);
     let client = StellarGuildsContractClient::new(env, &contract_id);
-    client.initialize();
+    let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client
+        .mock_all_auths()
+        .initialize(&Address::generate(env), &native.address());
     contract_id
 }
 
@@ -1305,3 +1309,774 @@ fn test_admin_can_cancel_bounty() {
     let bounty = client.get_bounty(&bounty_id);
     assert_eq!(bounty.status, BountyStatus::Cancelled);
 }
+
+// ============ Treasury-Funded Bounty Tests ============
+
+fn setup_treasury(
+    client: &StellarGuildsContractClient<'_>,
+    env: &Env,
+    guild_id: u64,
+    owner: &Address,
+    token: &Address,
+    amount: i128,
+) -> (u64, Address) {
+    let signer = Address::generate(env);
+    let mut signers = soroban_sdk::Vec::new(env);
+    signers.push_back(owner.clone());
+    signers.push_back(signer.clone());
+    let treasury_id = client.initialize_treasury(&guild_id, &signers, &2u32);
+
+    mint_tokens(env, token, owner, amount);
+    client.deposit_treasury(&treasury_id, owner, &amount, &Some(token.clone()));
+
+    (treasury_id, signer)
+}
+
+#[test]
+fn test_treasury_bounty_funded_on_execution() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let (treasury_id, signer) = setup_treasury(&client, &env, guild_id, &owner, &token, 1000);
+
+    let bounty_id = client.create_treasury_bounty(
+        &guild_id,
+        &owner,
+        &treasury_id,
+        &String::from_str(&env, "Task"),
+        &String::from_str(&env, "Description"),
+        &300i128,
+        &token,
+        &2000u64,
+        &None,
+    );
+
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::AwaitingFunds);
+    assert_eq!(bounty.treasury_id, Some(treasury_id));
+    let tx_id = bounty.funding_tx_id.unwrap();
+
+    client.approve_transaction(&tx_id, &signer);
+    client.execute_transaction(&tx_id, &owner);
+
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::Open);
+    assert_eq!(bounty.funded_amount, 300);
+    assert_eq!(
+        client.get_treasury_balance(&treasury_id, &Some(token.clone())),
+        700
+    );
+    // Tokens stay in the contract, now held in the bounty escrow
+    assert_eq!(get_token_balance(&env, &token, &contract_id), 1000);
+
+    let history = client.get_transaction_history(&treasury_id, &10u32);
+    let funding = history.iter().find(|tx| tx.id == tx_id).unwrap();
+    assert_eq!(
+        funding.tx_type,
+        crate::treasury::types::TransactionType::BountyFunding
+    );
+    assert_eq!(funding.reference_id, Some(bounty_id));
+}
+
+#[test]
+fn test_treasury_bounty_cancel_refunds_treasury() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let (treasury_id, signer) = setup_treasury(&client, &env, guild_id, &owner, &token, 1000);
+
+    let bounty_id = client.create_treasury_bounty(
+        &guild_id,
+        &owner,
+        &treasury_id,
+        &String::from_str(&env, "Task"),
+        &String::from_str(&env, "Description"),
+        &300i128,
+        &token,
+        &2000u64,
+        &None,
+    );
+    let tx_id = client.get_bounty(&bounty_id).funding_tx_id.unwrap();
+    client.approve_transaction(&tx_id, &signer);
+    client.execute_transaction(&tx_id, &owner);
+
+    client.cancel_bounty(&bounty_id, &owner);
+
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::Cancelled);
+    assert_eq!(bounty.funded_amount, 0);
+    assert_eq!(
+        client.get_treasury_balance(&treasury_id, &Some(token.clone())),
+        1000
+    );
+    assert_eq!(get_token_balance(&env, &token, &owner), 0);
+}
+
+#[test]
+fn test_closed_treasury_bounty_rejects_pending_funding() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let (treasury_id, signer) = setup_treasury(&client, &env, guild_id, &owner, &token, 1000);
+
+    let cancelled_id = client.create_treasury_bounty(
+        &guild_id,
+        &owner,
+        &treasury_id,
+        &String::from_str(&env, "Cancelled"),
+        &String::from_str(&env, "Description"),
+        &300i128,
+        &token,
+        &5000u64,
+        &None,
+    );
+    let expired_id = client.create_treasury_bounty(
+        &guild_id,
+        &owner,
+        &treasury_id,
+        &String::from_str(&env, "Expired"),
+        &String::from_str(&env, "Description"),
+        &200i128,
+        &token,
+        &2000u64,
+        &None,
+    );
+    let cancelled_tx = client.get_bounty(&cancelled_id).funding_tx_id.unwrap();
+    let expired_tx = client.get_bounty(&expired_id).funding_tx_id.unwrap();
+    client.approve_transaction(&cancelled_tx, &signer);
+
+    client.cancel_bounty(&cancelled_id, &owner);
+    set_ledger_timestamp(&env, 2001);
+    assert!(client.expire_bounty(&expired_id));
+
+    let history = client.get_transaction_history(&treasury_id, &10u32);
+    for tx_id in [cancelled_tx, expired_tx] {
+        let funding = history.iter().find(|tx| tx.id == tx_id).unwrap();
+        assert_eq!(
+            funding.status,
+            crate::treasury::types::TransactionStatus::Rejected
+        );
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+    }
+    assert_eq!(
+        client.get_treasury_balance(&treasury_id, &Some(token.clone())),
+        1000
+    );
+}
+
+#[test]
+#[should_panic(expected = "Bounty is funded from treasury")]
+fn test_treasury_bounty_rejects_direct_funding() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let (treasury_id, _signer) = setup_treasury(&client, &env, guild_id, &owner, &token, 1000);
+
+    let bounty_id = client.create_treasury_bounty(
+        &guild_id,
+        &owner,
+        &treasury_id,
+        &String::from_str(&env, "Task"),
+        &String::from_str(&env, "Description"),
+        &300i128,
+        &token,
+        &2000u64,
+        &None,
+    );
+
+    mint_tokens(&env, &token, &owner, 300);
+    client.fund_bounty(&bounty_id, &owner, &300i128);
+}
+
+// ============ Multi-Token & Split Reward Tests ============
+
+#[test]
+fn test_multi_token_bounty_split_release() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let usdc = create_mock_token(&env, &owner);
+    let guild_token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+
+    let bounty_id = client.create_bounty(
+        &guild_id,
+        &owner,
+        &String::from_str(&env, "Task"),
+        &String::from_str(&env, "Description"),
+        &100i128,
+        &usdc,
+        &2000u64,
+    );
+    client.add_bounty_reward(&bounty_id, &owner, &guild_token, &51i128);
+
+    mint_tokens(&env, &usdc, &owner, 100);
+    mint_tokens(&env, &guild_token, &owner, 51);
+    client.fund_bounty(&bounty_id, &owner, &100i128);
+    assert_eq!(
+        client.get_bounty(&bounty_id).status,
+        BountyStatus::AwaitingFunds
+    );
+    client.fund_bounty_token(&bounty_id, &owner, &guild_token, &51i128);
+    assert_eq!(client.get_bounty(&bounty_id).status, BountyStatus::Open);
+
+    client.claim_bounty(&bounty_id, &alice);
+
+    let mut splits = soroban_sdk::Vec::new(&env);
+    splits.push_back(ClaimantShare {
+        claimer: alice.clone(),
+        share_bps: 7000,
+    });
+    splits.push_back(ClaimantShare {
+        claimer: bob.clone(),
+        share_bps: 3000,
+    });
+    client.set_bounty_splits(&bounty_id, &splits);
+
+    client.submit_work(&bounty_id, &String::from_str(&env, "https://example.com"));
+    client.approve_completion(&bounty_id, &owner);
+    client.release_escrow(&bounty_id);
+
+    assert_eq!(get_token_balance(&env, &usdc, &alice), 70);
+    assert_eq!(get_token_balance(&env, &usdc, &bob), 30);
+    // Rounding dust goes to the last co-claimant
+    assert_eq!(get_token_balance(&env, &guild_token, &alice), 35);
+    assert_eq!(get_token_balance(&env, &guild_token, &bob), 16);
+
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.funded_amount, 0);
+    assert_eq!(bounty.extra_rewards.get(0).unwrap().funded_amount, 0);
+}
+
+#[test]
+fn test_multi_token_bounty_cancel_refunds_every_leg() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let usdc = create_mock_token(&env, &owner);
+    let guild_token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+
+    let bounty_id = client.create_bounty(
+        &guild_id,
+        &owner,
+        &String::from_str(&env, "Task"),
+        &String::from_str(&env, "Description"),
+        &100i128,
+        &usdc,
+        &2000u64,
+    );
+    client.add_bounty_reward(&bounty_id, &owner, &guild_token, &50i128);
+
+    mint_tokens(&env, &usdc, &sponsor, 100);
+    mint_tokens(&env, &guild_token, &sponsor, 50);
+    client.fund_bounty_token(&bounty_id, &sponsor, &usdc, &100i128);
+    client.fund_bounty_token(&bounty_id, &sponsor, &guild_token, &50i128);
+
+    client.cancel_bounty(&bounty_id, &owner);
+
+    assert_eq!(get_token_balance(&env, &usdc, &owner), 100);
+    assert_eq!(get_token_balance(&env, &guild_token, &owner), 50);
+    assert_eq!(get_token_balance(&env, &guild_token, &contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid splits: shares must total 10000 bps")]
+fn test_set_bounty_splits_invalid_total_fails() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+
+    let bounty_id = client.create_bounty(
+        &guild_id,
+        &owner,
+        &String::from_str(&env, "Task"),
+        &String::from_str(&env, "Description"),
+        &0i128,
+        &token,
+        &2000u64,
+    );
+    client.claim_bounty(&bounty_id, &alice);
+
+    let mut splits = soroban_sdk::Vec::new(&env);
+    splits.push_back(ClaimantShare {
+        claimer: alice,
+        share_bps: 5000,
+    });
+    splits.push_back(ClaimantShare {
+        claimer: bob,
+        share_bps: 4000,
+    });
+    client.set_bounty_splits(&bounty_id, &splits);
+}
+
+// ============ Claim Bond Tests ============
+
+fn create_bonded(
+    client: &StellarGuildsContractClient<'_>,
+    env: &Env,
+    guild_id: u64,
+    owner: &Address,
+    token: &Address,
+) -> u64 {
+    let bond = ClaimBond {
+        amount: 20,
+        grace_period: 100,
+    };
+    client.create_bonded_bounty(
+        &guild_id,
+        owner,
+        &String::from_str(env, "Task"),
+        &String::from_str(env, "Description"),
+        &0i128,
+        token,
+        &5000u64,
+        &bond,
+    )
+}
+
+#[test]
+fn test_claim_bond_locked_and_returned_on_approval() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let claimer = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let bounty_id = create_bonded(&client, &env, guild_id, &owner, &token);
+
+    mint_tokens(&env, &token, &claimer, 20);
+    client.claim_bounty(&bounty_id, &claimer);
+    assert_eq!(get_token_balance(&env, &token, &claimer), 0);
+    assert_eq!(client.get_bounty(&bounty_id).locked_bond, 20);
+
+    client.submit_work(&bounty_id, &String::from_str(&env, "https://example.com"));
+    client.approve_completion(&bounty_id, &owner);
+
+    assert_eq!(get_token_balance(&env, &token, &claimer), 20);
+    assert_eq!(client.get_bounty(&bounty_id).locked_bond, 0);
+}
+
+#[test]
+fn test_release_claim_within_grace_returns_bond() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let claimer = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let bounty_id = create_bonded(&client, &env, guild_id, &owner, &token);
+
+    mint_tokens(&env, &token, &claimer, 20);
+    client.claim_bounty(&bounty_id, &claimer);
+
+    set_ledger_timestamp(&env, 1100);
+    client.release_claim(&bounty_id, &claimer);
+
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::Open);
+    assert!(bounty.claimer.is_none());
+    assert_eq!(get_token_balance(&env, &token, &claimer), 20);
+}
+
+#[test]
+fn test_abandoned_claim_slashes_bond_to_creator() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let claimer = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let bounty_id = create_bonded(&client, &env, guild_id, &owner, &token);
+
+    mint_tokens(&env, &token, &claimer, 20);
+    client.claim_bounty(&bounty_id, &claimer);
+
+    // Claimer never submits; the bounty expires with the claim still open
+    set_ledger_timestamp(&env, 6000);
+    client.expire_bounty(&bounty_id);
+
+    assert_eq!(client.get_bounty(&bounty_id).status, BountyStatus::Expired);
+    assert_eq!(get_token_balance(&env, &token, &claimer), 0);
+    assert_eq!(get_token_balance(&env, &token, &owner), 20);
+}
+
+// ============ Template & Recurring Bounty Tests ============
+
+#[test]
+fn test_recurring_template_spawns_each_period() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+
+    let template_id = client.create_bounty_template(
+        &guild_id,
+        &owner,
+        &String::from_str(&env, "Weekly triage"),
+        &String::from_str(&env, "Triage new issues"),
+        &0i128,
+        &token,
+        &500u64,
+        &None,
+    );
+    client.schedule_bounty_template(&template_id, &owner, &100u64, &1000u64);
+
+    let first = client.run_bounty_schedule(&template_id);
+    let bounty = client.get_bounty(&first);
+    assert_eq!(bounty.template_id, Some(template_id));
+    assert_eq!(bounty.status, BountyStatus::Open);
+    assert_eq!(bounty.expires_at, 1500);
+
+    // Missed periods are skipped, not back-filled
+    set_ledger_timestamp(&env, 1250);
+    client.run_bounty_schedule(&template_id);
+
+    let template = client.get_bounty_template(&template_id);
+    assert_eq!(template.spawned_count, 2);
+    assert_eq!(template.next_spawn_at, 1300);
+    assert_eq!(client.get_template_bounties(&template_id).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Next bounty is not due yet")]
+fn test_recurring_template_not_due_fails() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+
+    let template_id = client.create_bounty_template(
+        &guild_id,
+        &owner,
+        &String::from_str(&env, "Weekly triage"),
+        &String::from_str(&env, "Triage new issues"),
+        &0i128,
+        &token,
+        &500u64,
+        &None,
+    );
+    client.schedule_bounty_template(&template_id, &owner, &100u64, &1000u64);
+
+    client.run_bounty_schedule(&template_id);
+    set_ledger_timestamp(&env, 1050);
+    client.run_bounty_schedule(&template_id);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: Template creator is no longer a guild admin")]
+fn test_recurring_template_stops_after_creator_demoted() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    client.add_member(&guild_id, &admin, &Role::Admin, &owner);
+
+    let template_id = client.create_bounty_template(
+        &guild_id,
+        &admin,
+        &String::from_str(&env, "Weekly triage"),
+        &String::from_str(&env, "Triage new issues"),
+        &0i128,
+        &token,
+        &500u64,
+        &None,
+    );
+    client.schedule_bounty_template(&template_id, &admin, &100u64, &1000u64);
+    client.run_bounty_schedule(&template_id);
+
+    client.update_role(&guild_id, &admin, &Role::Member, &owner);
+    set_ledger_timestamp(&env, 1100);
+    client.run_bounty_schedule(&template_id);
+}
+
+#[test]
+fn test_template_auto_funds_from_treasury_allowance() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let (treasury_id, _signer) = setup_treasury(&client, &env, guild_id, &owner, &token, 1000);
+    client.grant_allowance(
+        &treasury_id,
+        &owner,
+        &500i128,
+        &Some(token.clone()),
+        &3600u64,
+        &owner,
+    );
+
+    let template_id = client.create_bounty_template(
+        &guild_id,
+        &owner,
+        &String::from_str(&env, "Docs refresh"),
+        &String::from_str(&env, "Update docs"),
+        &300i128,
+        &token,
+        &500u64,
+        &Some(treasury_id),
+    );
+
+    let bounty_id = client.spawn_bounty_from_template(&template_id, &owner);
+
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::Open);
+    assert_eq!(bounty.funded_amount, 300);
+    assert!(bounty.funding_tx_id.is_some());
+    assert_eq!(
+        client.get_treasury_balance(&treasury_id, &Some(token.clone())),
+        700
+    );
+}
+
+// ============ Reviewer Tests ============
+
+fn setup_reviewed_bounty(
+    client: &StellarGuildsContractClient<'_>,
+    env: &Env,
+    guild_id: u64,
+    owner: &Address,
+    token: &Address,
+    reviewers: &soroban_sdk::Vec<Address>,
+) -> u64 {
+    let bounty_id = client.create_bounty(
+        &guild_id,
+        owner,
+        &String::from_str(env, "Task"),
+        &String::from_str(env, "Description"),
+        &100i128,
+        token,
+        &5000u64,
+    );
+    mint_tokens(env, token, owner, 100);
+    client.fund_bounty(&bounty_id, owner, &100i128);
+    client.set_bounty_reviewers(&bounty_id, owner, reviewers, &2u32);
+    bounty_id
+}
+
+#[test]
+fn test_multi_reviewer_approval_flow() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let claimer = Address::generate(&env);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let r3 = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let mut reviewers = soroban_sdk::Vec::new(&env);
+    reviewers.push_back(r1.clone());
+    reviewers.push_back(r2.clone());
+    reviewers.push_back(r3.clone());
+    let bounty_id = setup_reviewed_bounty(&client, &env, guild_id, &owner, &token, &reviewers);
+
+    let url = String::from_str(&env, "https://example.com");
+    let comment = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.claim_bounty(&bounty_id, &claimer);
+    client.submit_work(&bounty_id, &url);
+
+    // One rejection still leaves two reviewers who can approve
+    client.review_bounty(&bounty_id, &r1, &true, &comment);
+    client.review_bounty(&bounty_id, &r2, &false, &comment);
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::UnderReview);
+    assert_eq!(bounty.approvals.len(), 1);
+
+    // A second rejection makes 2-of-3 unreachable and sends the work back
+    client.review_bounty(&bounty_id, &r3, &false, &comment);
+    let bounty = client.get_bounty(&bounty_id);
+    assert_eq!(bounty.status, BountyStatus::Claimed);
+    assert!(bounty.approvals.is_empty());
+
+    client.submit_work(&bounty_id, &url);
+    client.review_bounty(&bounty_id, &r1, &true, &comment);
+    client.review_bounty(&bounty_id, &r2, &false, &comment);
+    assert_eq!(
+        client.get_bounty(&bounty_id).status,
+        BountyStatus::UnderReview
+    );
+    client.review_bounty(&bounty_id, &r3, &true, &comment);
+    assert_eq!(
+        client.get_bounty(&bounty_id).status,
+        BountyStatus::Completed
+    );
+
+    client.release_escrow(&bounty_id);
+    assert_eq!(get_token_balance(&env, &token, &claimer), 100);
+
+    let reviews = client.get_bounty_reviews(&bounty_id);
+    assert_eq!(reviews.len(), 6);
+    assert_eq!(reviews.get(1).unwrap().approved, false);
+    assert_eq!(reviews.get(1).unwrap().comment_hash, comment);
+
+    // r1 reviewed twice but is credited once
+    let contributions = client.get_reputation_contributions(&guild_id, &r1, &10u32);
+    assert_eq!(contributions.len(), 1);
+    assert_eq!(
+        contributions.get(0).unwrap().contribution_type,
+        ContributionType::BountyReviewed
+    );
+}
+
+#[test]
+#[should_panic(expected = "Bounty requires reviewer approval")]
+fn test_admin_approval_blocked_when_reviewers_set() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let claimer = Address::generate(&env);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let mut reviewers = soroban_sdk::Vec::new(&env);
+    reviewers.push_back(r1);
+    reviewers.push_back(r2);
+    let bounty_id = setup_reviewed_bounty(&client, &env, guild_id, &owner, &token, &reviewers);
+
+    client.claim_bounty(&bounty_id, &claimer);
+    client.submit_work(&bounty_id, &String::from_str(&env, "https://example.com"));
+    client.approve_completion(&bounty_id, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Reviewers cannot claim this bounty")]
+fn test_reviewer_cannot_claim() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let guild_id = setup_guild(&client, &env, &owner);
+    let mut reviewers = soroban_sdk::Vec::new(&env);
+    reviewers.push_back(r1.clone());
+    reviewers.push_back(r2);
+    let bounty_id = setup_reviewed_bounty(&client, &env, guild_id, &owner, &token, &reviewers);
+
+    client.claim_bounty(&bounty_id, &r1);
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 /// Status of a bounty lifecycle
 #[contracttype]
@@ -13,6 +13,38 @@ pub enum BountyStatus {
     AwaitingFunds = 6,
 }
 
+/// Additional reward leg paid in a token other than the bounty's primary `token`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RewardLeg {
+    /// Address of the token contract for this leg
+    pub token: Address,
+    /// Amount of tokens defined as reward for this leg
+    pub amount: i128,
+    /// Amount of tokens currently escrowed for this leg
+    pub funded_amount: i128,
+}
+
+/// Agreed share of a bounty payout for one member of a claiming team
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClaimantShare {
+    /// Address receiving this share
+    pub claimer: Address,
+    /// Share of every reward leg in basis points (10000 = 100%)
+    pub share_bps: u32,
+}
+
+/// Claim bond a claimer must lock in the bounty's token to claim it
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClaimBond {
+    /// Bond amount in the bounty's primary token
+    pub amount: i128,
+    /// Seconds after claiming during which the claim can be released without penalty
+    pub grace_period: u64,
+}
+
 /// Bounty struct containing all bounty metadata and state
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -43,6 +75,75 @@ pub struct Bounty {
     pub created_at: u64,
     /// Expiration timestamp (seconds)
     pub expires_at: u64,
+    /// Treasury the reward is drawn from (None when funded from personal wallets)
+    pub treasury_id: Option<u64>,
+    /// Linked `BountyFunding` treasury transaction, if any
+    pub funding_tx_id: Option<u64>,
+    /// Reward legs in other tokens, each escrowed independently of the primary leg
+    pub extra_rewards: Vec<RewardLeg>,
+    /// Co-claimant payout split (empty = the claimer receives everything)
+    pub splits: Vec<ClaimantShare>,
+    /// Claim bond required from claimers (0 = no bond)
+    pub bond_amount: i128,
+    /// Grace window (seconds after claiming) for releasing a claim without penalty
+    pub bond_grace_period: u64,
+    /// Bond currently locked by the claimer
+    pub locked_bond: i128,
+    /// Timestamp of the current claim
+    pub claimed_at: Option<u64>,
+    /// Template this bounty was spawned from, if any
+    pub template_id: Option<u64>,
+    /// Dedicated reviewers (empty = any guild admin may approve)
+    pub reviewers: Vec<Address>,
+    /// Reviewer approvals needed before the bounty completes
+    pub required_approvals: u32,
+    /// Reviewers who approved the current submission
+    pub approvals: Vec<Address>,
+    /// Reviewers who rejected the current submission
+    pub rejections: Vec<Address>,
+}
+
+/// A recorded reviewer decision on a bounty submission
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BountyReview {
+    pub reviewer: Address,
+    pub approved: bool,
+    /// Hash of the off-chain review comment
+    pub comment_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// Reusable bounty definition stored per guild, optionally spawned on a schedule
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountyTemplate {
+    /// Unique identifier for the template
+    pub id: u64,
+    /// ID of the guild this template belongs to
+    pub guild_id: u64,
+    /// Address of the template creator (guild admin/owner); recorded as bounty creator
+    pub creator: Address,
+    /// Title given to spawned bounties
+    pub title: String,
+    /// Description given to spawned bounties
+    pub description: String,
+    /// Reward amount of spawned bounties
+    pub reward_amount: i128,
+    /// Reward token of spawned bounties
+    pub token: Address,
+    /// How long each spawned bounty stays open (seconds)
+    pub duration: u64,
+    /// Treasury auto-funding spawned bounties through the creator's allowance
+    pub treasury_id: Option<u64>,
+    /// Recurrence period in seconds (0 = spawned manually only)
+    pub period_seconds: u64,
+    /// Earliest timestamp at which a keeper may spawn the next bounty
+    pub next_spawn_at: u64,
+    /// Inactive templates can no longer spawn bounties
+    pub active: bool,
+    /// Number of bounties spawned so far
+    pub spawned_count: u32,
 }
 
 // ============ Events ============
@@ -59,23 +160,89 @@ pub struct BountyCreatedEvent {
     pub expires_at: u64,
 }
 
+/// Event emitted when a bounty template is created or its schedule changes
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TemplateUpdatedEvent {
+    pub template_id: u64,
+    pub guild_id: u64,
+    pub period_seconds: u64,
+    pub next_spawn_at: u64,
+    pub active: bool,
+}
+
+/// Event emitted when a bounty is spawned from a template
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountySpawnedEvent {
+    pub template_id: u64,
+    pub bounty_id: u64,
+    pub funded: bool,
+}
+
 /// Event emitted when a bounty is funded
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BountyFundedEvent {
     pub bounty_id: u64,
     pub funder: Address,
+    pub token: Address,
     pub amount: i128,
     pub total_funded: i128,
     pub is_fully_funded: bool,
 }
 
+/// Event emitted when a reward leg is added to a bounty
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RewardAddedEvent {
+    pub bounty_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+
 /// Event emitted when a bounty is claimed
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BountyClaimedEvent {
     pub bounty_id: u64,
     pub claimer: Address,
+    pub bond_amount: i128,
+}
+
+/// Event emitted when a claimer gives up a claim and the bounty reopens
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ClaimReleasedEvent {
+    pub bounty_id: u64,
+    pub claimer: Address,
+}
+
+/// Event emitted when a claim bond is returned to the claimer
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BondReturnedEvent {
+    pub bounty_id: u64,
+    pub claimer: Address,
+    pub amount: i128,
+}
+
+/// Event emitted when a claim bond is slashed to the bounty's funders
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BondSlashedEvent {
+    pub bounty_id: u64,
+    pub claimer: Address,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+/// Event emitted when co-claimants agree on a payout split
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SplitsSetEvent {
+    pub bounty_id: u64,
+    pub splits: Vec<ClaimantShare>,
 }
 
 /// Event emitted when work is submitted
@@ -87,6 +254,25 @@ pub struct WorkSubmittedEvent {
     pub submission_url: String,
 }
 
+/// Event emitted when a bounty's reviewer set changes
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReviewersSetEvent {
+    pub bounty_id: u64,
+    pub reviewers: Vec<Address>,
+    pub required_approvals: u32,
+}
+
+/// Event emitted when a reviewer approves or rejects a submission
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BountyReviewedEvent {
+    pub bounty_id: u64,
+    pub reviewer: Address,
+    pub approved: bool,
+    pub comment_hash: BytesN<32>,
+}
+
 /// Event emitted when work is approved
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -21,6 +21,7 @@ use crate::milestone::storage as milestone_storage;
 use crate::milestone::types::ProjectStatus;
 
 const VOTING_PERIOD_SECONDS: u64 = 7 * 24 * 60 * 60;
+const REVEAL_PERIOD_SECONDS: u64 = 2 * 24 * 60 * 60;
 const MAX_REASON_LEN: u32 = 1024;
 const MAX_EVIDENCE_LEN: u32 = 1024;
 
@@ -39,6 +40,50 @@ pub fn create_dispute(
     defendant: Address,
     reason: String,
     evidence_url: String,
+) -> u64 {
+    open_dispute(
+        env,
+        reference_id,
+        plaintiff,
+        defendant,
+        reason,
+        evidence_url,
+        false,
+    )
+}
+
+/// Create a dispute whose jurors vote by commit-reveal: they commit sealed
+/// votes until the voting deadline and reveal them during the following
+/// reveal period. Unrevealed commitments count toward quorum only.
+///
+/// Takes the same arguments as [`create_dispute`].
+pub fn create_private_dispute(
+    env: &Env,
+    reference_id: u64,
+    plaintiff: Address,
+    defendant: Address,
+    reason: String,
+    evidence_url: String,
+) -> u64 {
+    open_dispute(
+        env,
+        reference_id,
+        plaintiff,
+        defendant,
+        reason,
+        evidence_url,
+        true,
+    )
+}
+
+fn open_dispute(
+    env: &Env,
+    reference_id: u64,
+    plaintiff: Address,
+    defendant: Address,
+    reason: String,
+    evidence_url: String,
+    commit_reveal: bool,
 ) -> u64 {
     plaintiff.require_auth();
 
@@ -62,7 +107,7 @@ pub fn create_dispute(
             if b.status == BountyStatus::Cancelled || b.status == BountyStatus::Expired {
                 panic!("bounty not disputable");
             }
-            if b.funded_amount <= 0 {
+            if !crate::bounty::has_locked_funds(&b) {
                 panic!("bounty has no locked funds");
             }
             (DisputeReference::Bounty, b.guild_id)
@@ -88,6 +133,11 @@ pub fn create_dispute(
 
     let now = env.ledger().timestamp();
     let voting_deadline = now + VOTING_PERIOD_SECONDS;
+    let reveal_deadline = if commit_reveal {
+        Some(voting_deadline + REVEAL_PERIOD_SECONDS)
+    } else {
+        None
+    };
     let dispute_id = dispute_storage::get_next_dispute_id(env);
 
     let dispute = Dispute {
@@ -109,6 +159,8 @@ pub fn create_dispute(
         vote_count: 0,
         resolved_at: None,
         resolution_executed: false,
+        reveal_deadline,
+        commit_count: 0,
     };
 
     dispute_storage::store_dispute(env, &dispute);
@@ -174,6 +226,27 @@ pub fn cast_vote(
     voting::cast_vote(env, dispute_id, voter, decision)
 }
 
+/// Commit a sealed vote on a commit-reveal dispute.
+pub fn commit_vote(
+    env: &Env,
+    dispute_id: u64,
+    voter: Address,
+    commitment: soroban_sdk::BytesN<32>,
+) -> bool {
+    voting::commit_vote(env, dispute_id, voter, commitment)
+}
+
+/// Reveal a sealed vote once the voting deadline has passed.
+pub fn reveal_vote(
+    env: &Env,
+    dispute_id: u64,
+    voter: Address,
+    decision: crate::dispute::types::VoteDecision,
+    salt: soroban_sdk::BytesN<32>,
+) -> bool {
+    voting::reveal_vote(env, dispute_id, voter, decision, salt)
+}
+
 /// Calculate a voter's weight based on their guild role.
 pub fn calculate_vote_weight(env: &Env, guild_id: u64, voter: Address) -> u32 {
     voting::calculate_vote_weight(env, guild_id, &voter)
@@ -41,7 +41,13 @@ fn decide_winner(
 /// Tally votes for a dispute without mutating state.
 pub fn tally_votes(env: &Env, dispute_id: u64) -> Resolution {
     let dispute = storage::get_dispute(env, dispute_id).expect("dispute not found");
-    let quorum = quorum_reached(env, dispute.guild_id, dispute.vote_count);
+    // jurors who committed but never revealed still count as participating
+    let participants = if dispute.reveal_deadline.is_some() {
+        dispute.commit_count
+    } else {
+        dispute.vote_count
+    };
+    let quorum = quorum_reached(env, dispute.guild_id, participants);
 
     let winner = if quorum {
         match decide_winner(
@@ -80,6 +86,11 @@ pub fn resolve_dispute(env: &Env, dispute_id: u64) -> Resolution {
     if now < dispute.voting_deadline {
         panic!("voting period still active");
     }
+    if let Some(reveal_deadline) = dispute.reveal_deadline {
+        if now <= reveal_deadline {
+            panic!("reveal period still active");
+        }
+    }
 
     let mut resolution = tally_votes(env, dispute_id);
 
@@ -96,12 +107,12 @@ pub fn resolve_dispute(env: &Env, dispute_id: u64) -> Resolution {
 
             if bounty.status != BountyStatus::Cancelled
                 && bounty.status != BountyStatus::Expired
-                && bounty.funded_amount > 0
+                && crate::bounty::has_locked_funds(&bounty)
             {
-                release_funds(env, &bounty.token, &bounty.creator, bounty.funded_amount);
-                bounty.funded_amount = 0;
-                bounty_storage::store_bounty(env, &bounty);
+                crate::bounty::refund_escrow(env, &mut bounty);
             }
+            crate::bounty::settle_bond_after_dispute(env, &mut bounty, None);
+            bounty_storage::store_bounty(env, &bounty);
         }
 
         let event = crate::dispute::types::DisputeExpiredEvent { dispute_id };
@@ -152,42 +163,61 @@ pub fn execute_resolution(env: &Env, dispute_id: u64) -> Vec<FundDistribution> {
             let mut bounty =
                 bounty_storage::get_bounty(env, dispute.reference_id).expect("bounty not found");
 
-            let total = bounty.funded_amount;
-            if total > 0 {
-                match decision {
-                    VoteDecision::FavorPlaintiff => {
-                        release_funds(env, &bounty.token, &dispute.plaintiff, total);
-                        distributions.push_back(FundDistribution {
-                            recipient: dispute.plaintiff.clone(),
-                            amount: total,
-                        });
-                    }
-                    VoteDecision::FavorDefendant => {
-                        release_funds(env, &bounty.token, &dispute.defendant, total);
-                        distributions.push_back(FundDistribution {
-                            recipient: dispute.defendant.clone(),
-                            amount: total,
-                        });
-                    }
+            // Every reward leg is split the same way, each in its own token.
+            for leg in crate::bounty::reward_legs(env, &bounty).iter() {
+                let total = leg.funded_amount;
+                if total <= 0 {
+                    continue;
+                }
+
+                let (plaintiff_amt, defendant_amt) = match decision {
+                    VoteDecision::FavorPlaintiff => (total, 0),
+                    VoteDecision::FavorDefendant => (0, total),
                     VoteDecision::Split => {
                         let half = total / 2;
-                        let remainder = total - half;
-                        release_funds(env, &bounty.token, &dispute.plaintiff, half);
-                        release_funds(env, &bounty.token, &dispute.defendant, remainder);
-                        distributions.push_back(FundDistribution {
-                            recipient: dispute.plaintiff.clone(),
-                            amount: half,
-                        });
-                        distributions.push_back(FundDistribution {
-                            recipient: dispute.defendant.clone(),
-                            amount: remainder,
-                        });
+                        (half, total - half)
                     }
-                }
+                };
 
-                bounty.funded_amount = 0;
-                bounty_storage::store_bounty(env, &bounty);
+                if plaintiff_amt > 0 {
+                    release_funds(
+                        env,
+                        bounty.id,
+                        &leg.token,
+                        &dispute.plaintiff,
+                        plaintiff_amt,
+                    );
+                    distributions.push_back(FundDistribution {
+                        recipient: dispute.plaintiff.clone(),
+                        amount: plaintiff_amt,
+                        token: Some(leg.token.clone()),
+                    });
+                }
+                if defendant_amt > 0 {
+                    release_funds(
+                        env,
+                        bounty.id,
+                        &leg.token,
+                        &dispute.defendant,
+                        defendant_amt,
+                    );
+                    distributions.push_back(FundDistribution {
+                        recipient: dispute.defendant.clone(),
+                        amount: defendant_amt,
+                        token: Some(leg.token.clone()),
+                    });
+                }
             }
+
+            let loser = match decision {
+                VoteDecision::FavorPlaintiff => Some(dispute.defendant.clone()),
+                VoteDecision::FavorDefendant => Some(dispute.plaintiff.clone()),
+                VoteDecision::Split => None,
+            };
+            crate::bounty::settle_bond_after_dispute(env, &mut bounty, loser);
+
+            crate::bounty::clear_escrow(&mut bounty);
+            bounty_storage::store_bounty(env, &bounty);
         }
         DisputeReference::Milestone => {
             let mut milestone = milestone_storage::get_milestone(env, dispute.reference_id)
@@ -225,6 +255,7 @@ pub fn execute_resolution(env: &Env, dispute_id: u64) -> Vec<FundDistribution> {
                     distributions.push_back(FundDistribution {
                         recipient: dispute.plaintiff.clone(),
                         amount: plaintiff_amt,
+                        token: project.token.clone(),
                     });
                 }
                 if defendant_amt > 0 {
@@ -238,6 +269,7 @@ pub fn execute_resolution(env: &Env, dispute_id: u64) -> Vec<FundDistribution> {
                     distributions.push_back(FundDistribution {
                         recipient: dispute.defendant.clone(),
                         amount: defendant_amt,
+                        token: project.token.clone(),
                     });
                 }
 
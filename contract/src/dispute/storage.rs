@@ -1,11 +1,13 @@
 use soroban_sdk::{symbol_short, Address, Env, Map, Symbol};
 
 use crate::dispute::types::{Dispute, DisputeReference, Vote};
+use crate::governance::types::VoteCommitment;
 
 const DISPUTES_KEY: Symbol = symbol_short!("dsp_all");
 const DISPUTE_COUNTER_KEY: Symbol = symbol_short!("dsp_cnt");
 const DISPUTE_VOTES_KEY: Symbol = symbol_short!("dsp_vot");
 const REF_LOCKS_KEY: Symbol = symbol_short!("dsp_ref");
+const DISPUTE_COMMITS_KEY: Symbol = symbol_short!("dsp_cmt");
 
 /// Get the next dispute ID and increment the counter.
 pub fn get_next_dispute_id(env: &Env) -> u64 {
@@ -74,6 +76,36 @@ pub fn get_vote(env: &Env, dispute_id: u64, voter: &Address) -> Option<Vote> {
     dispute_votes.get(voter.clone())
 }
 
+/// Persist a juror's sealed vote for a dispute.
+pub fn store_commitment(env: &Env, dispute_id: u64, commitment: &VoteCommitment) {
+    let mut commits_map: Map<u64, Map<Address, VoteCommitment>> = env
+        .storage()
+        .persistent()
+        .get(&DISPUTE_COMMITS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let mut dispute_commits = commits_map.get(dispute_id).unwrap_or_else(|| Map::new(env));
+
+    dispute_commits.set(commitment.voter.clone(), commitment.clone());
+    commits_map.set(dispute_id, dispute_commits);
+
+    env.storage()
+        .persistent()
+        .set(&DISPUTE_COMMITS_KEY, &commits_map);
+}
+
+/// Fetch a juror's sealed vote for a dispute.
+pub fn get_commitment(env: &Env, dispute_id: u64, voter: &Address) -> Option<VoteCommitment> {
+    let commits_map: Map<u64, Map<Address, VoteCommitment>> = env
+        .storage()
+        .persistent()
+        .get(&DISPUTE_COMMITS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let dispute_commits = commits_map.get(dispute_id)?;
+    dispute_commits.get(voter.clone())
+}
+
 /// Check whether a reference is locked by an active dispute.
 pub fn is_reference_locked(
     env: &Env,
@@ -5,7 +5,7 @@ use crate::guild::types::Role;
 use crate::StellarGuildsContract;
 use crate::StellarGuildsContractClient;
 use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
-use soroban_sdk::{token, Address, Env, String, Vec};
+use soroban_sdk::{token, Address, BytesN, Env, String, Vec};
 
 fn setup_env() -> Env {
     let env = Env::default();
@@ -29,7 +29,10 @@ fn set_ledger_timestamp(env: &Env, timestamp: u64) {
 fn register_and_init_contract(env: &Env) -> Address {
     let contract_id = env.register_contract(None, StellarGuildsContract);
     let client = StellarGuildsContractClient::new(env, &contract_id);
-    client.initialize();
+    let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client
+        .mock_all_auths()
+        .initialize(&Address::generate(env), &native.address());
     contract_id
 }
 
@@ -446,3 +449,69 @@ fn test_dispute_status_updates_after_resolution() {
     let dispute = client.tally_dispute_votes(&dispute_id);
     assert_eq!(dispute.vote_count, 2);
 }
+
+#[test]
+fn test_commit_reveal_dispute_counts_unrevealed_commits_for_quorum() {
+    let env = setup_env();
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let (guild_id, owner, admin, member, contributor) = setup_guild_with_members(&client, &env);
+    let member2 = Address::generate(&env);
+    client.add_member(&guild_id, &member2, &Role::Member, &owner);
+
+    let token = create_mock_token(&env, &owner);
+    let bounty_id = create_funded_bounty(&client, &env, guild_id, &owner, &owner, &token);
+
+    let reason = String::from_str(&env, "Dispute reason");
+    let evidence = String::from_str(&env, "ipfs://evidence");
+
+    let dispute_id =
+        client.create_private_dispute(&bounty_id, &contributor, &owner, &reason, &evidence);
+
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    let commit = |voter: &Address, decision: VoteDecision| {
+        let commitment = client.compute_dispute_vote_commitment(voter, &decision, &salt);
+        client.commit_dispute_vote(&dispute_id, voter, &commitment);
+    };
+    commit(&admin, VoteDecision::FavorPlaintiff);
+    commit(&member, VoteDecision::FavorDefendant);
+    commit(&member2, VoteDecision::FavorDefendant);
+
+    // sealed disputes take no open votes, and nothing is revealed early
+    assert!(client
+        .try_cast_dispute_vote(&dispute_id, &admin, &VoteDecision::FavorPlaintiff)
+        .is_err());
+    assert!(client
+        .try_reveal_dispute_vote(&dispute_id, &admin, &VoteDecision::FavorPlaintiff, &salt)
+        .is_err());
+    assert_eq!(client.tally_dispute_votes(&dispute_id).vote_count, 0);
+
+    let voting_deadline = 1000 + 7 * 24 * 60 * 60;
+    set_ledger_timestamp(&env, voting_deadline + 1);
+
+    // a reveal must match the commitment
+    assert!(client
+        .try_reveal_dispute_vote(&dispute_id, &member, &VoteDecision::FavorPlaintiff, &salt)
+        .is_err());
+    client.reveal_dispute_vote(&dispute_id, &admin, &VoteDecision::FavorPlaintiff, &salt);
+
+    assert!(client.try_resolve_dispute(&dispute_id).is_err());
+
+    let before_plaintiff = get_token_balance(&env, &token, &contributor);
+    set_ledger_timestamp(&env, voting_deadline + 2 * 24 * 60 * 60 + 1);
+
+    // one revealed vote of five members is short of quorum on its own, but
+    // three jurors committed
+    let resolution = client.resolve_dispute(&dispute_id);
+    assert_eq!(resolution.quorum_reached, true);
+    assert_eq!(resolution.vote_count, 1);
+    assert_eq!(resolution.votes_for_plaintiff, 5);
+    assert_eq!(resolution.votes_for_defendant, 0);
+
+    let after_plaintiff = get_token_balance(&env, &token, &contributor);
+    assert_eq!(after_plaintiff - before_plaintiff, 100);
+}
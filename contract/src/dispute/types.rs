@@ -24,6 +24,17 @@ pub enum VoteDecision {
     Split,
 }
 
+impl VoteDecision {
+    /// Byte identifying the decision in jury vote commitments
+    pub fn code(&self) -> u8 {
+        match self {
+            VoteDecision::FavorPlaintiff => 0,
+            VoteDecision::FavorDefendant => 1,
+            VoteDecision::Split => 2,
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Dispute {
@@ -45,6 +56,10 @@ pub struct Dispute {
     pub vote_count: u32,
     pub resolved_at: Option<u64>,
     pub resolution_executed: bool,
+    /// End of the reveal window when jurors vote by commit-reveal
+    pub reveal_deadline: Option<u64>,
+    /// Jurors who committed a sealed vote, revealed or not
+    pub commit_count: u32,
 }
 
 #[contracttype]
@@ -62,6 +77,7 @@ pub struct Vote {
 pub struct FundDistribution {
     pub recipient: Address,
     pub amount: i128,
+    pub token: Option<Address>,
 }
 
 #[contracttype]
@@ -105,6 +121,13 @@ pub struct VoteCastEvent {
     pub weight: i128,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommittedEvent {
+    pub dispute_id: u64,
+    pub voter: Address,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeResolvedEvent {
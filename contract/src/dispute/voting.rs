@@ -1,8 +1,9 @@
-use soroban_sdk::Env;
+use soroban_sdk::{Address, BytesN, Env};
 
 use crate::dispute::storage;
-use crate::dispute::types::{DisputeStatus, Vote, VoteDecision};
-use crate::governance::types::role_weight;
+use crate::dispute::types::{Dispute, DisputeStatus, Vote, VoteCommittedEvent, VoteDecision};
+use crate::governance::commit::vote_commitment;
+use crate::governance::types::{role_weight, VoteCommitment};
 use crate::guild::storage as guild_storage;
 
 /// Calculate voting weight for a guild member based on role.
@@ -18,17 +19,8 @@ pub fn calculate_vote_weight(env: &Env, guild_id: u64, voter: &soroban_sdk::Addr
     }
 }
 
-/// Cast a weighted vote for a dispute.
-pub fn cast_vote(
-    env: &Env,
-    dispute_id: u64,
-    voter: soroban_sdk::Address,
-    decision: VoteDecision,
-) -> bool {
-    voter.require_auth();
-
-    let mut dispute = storage::get_dispute(env, dispute_id).expect("dispute not found");
-
+/// Check that `voter` may vote on `dispute` while voting is open.
+fn ensure_can_vote(env: &Env, dispute: &Dispute, voter: &Address) {
     if dispute.status == DisputeStatus::Resolved || dispute.status == DisputeStatus::Expired {
         panic!("dispute is closed");
     }
@@ -38,26 +30,35 @@ pub fn cast_vote(
         panic!("voting period ended");
     }
 
-    if voter == dispute.plaintiff || voter == dispute.defendant {
+    if *voter == dispute.plaintiff || *voter == dispute.defendant {
         panic!("parties cannot vote");
     }
 
-    if storage::get_vote(env, dispute_id, &voter).is_some() {
+    if storage::get_vote(env, dispute.id, voter).is_some() {
         panic!("voter already voted");
     }
 
     // ensure voter is a guild member
-    let _member = guild_storage::get_member(env, dispute.guild_id, &voter)
+    let _member = guild_storage::get_member(env, dispute.guild_id, voter)
         .unwrap_or_else(|| panic!("voter must be guild member"));
+}
 
+/// Record a vote cast at `timestamp` and add it to the dispute's tallies.
+fn record_vote(
+    env: &Env,
+    dispute: &mut Dispute,
+    voter: Address,
+    decision: VoteDecision,
+    timestamp: u64,
+) {
     let weight = calculate_vote_weight(env, dispute.guild_id, &voter) as i128;
 
     let vote = Vote {
         voter: voter.clone(),
-        dispute_id,
+        dispute_id: dispute.id,
         decision: decision.clone(),
         weight,
-        timestamp: now,
+        timestamp,
     };
 
     storage::store_vote(env, &vote);
@@ -70,19 +71,114 @@ pub fn cast_vote(
     }
     dispute.vote_count = dispute.vote_count.saturating_add(1);
 
+    let event = crate::dispute::types::VoteCastEvent {
+        dispute_id: dispute.id,
+        voter,
+        decision,
+        weight,
+    };
+    env.events().publish(("DisputeVote",), event);
+}
+
+/// Cast a weighted vote for a dispute.
+pub fn cast_vote(env: &Env, dispute_id: u64, voter: Address, decision: VoteDecision) -> bool {
+    voter.require_auth();
+
+    let mut dispute = storage::get_dispute(env, dispute_id).expect("dispute not found");
+    ensure_can_vote(env, &dispute, &voter);
+    if dispute.reveal_deadline.is_some() {
+        panic!("dispute uses commit-reveal voting");
+    }
+
+    record_vote(env, &mut dispute, voter, decision, env.ledger().timestamp());
+
     if dispute.status == DisputeStatus::Open {
         dispute.status = DisputeStatus::Voting;
     }
 
     storage::store_dispute(env, &dispute);
 
-    let event = crate::dispute::types::VoteCastEvent {
-        dispute_id,
-        voter,
-        decision,
-        weight,
+    true
+}
+
+/// Commit a sealed vote on a commit-reveal dispute. Each juror commits once.
+pub fn commit_vote(env: &Env, dispute_id: u64, voter: Address, commitment: BytesN<32>) -> bool {
+    voter.require_auth();
+
+    let mut dispute = storage::get_dispute(env, dispute_id).expect("dispute not found");
+    ensure_can_vote(env, &dispute, &voter);
+    if dispute.reveal_deadline.is_none() {
+        panic!("dispute does not use commit-reveal voting");
+    }
+
+    if storage::get_commitment(env, dispute_id, &voter).is_some() {
+        panic!("voter already committed");
+    }
+
+    let record = VoteCommitment {
+        voter: voter.clone(),
+        commitment,
+        committed_at: env.ledger().timestamp(),
+        revealed: false,
     };
-    env.events().publish(("DisputeVote",), event);
+    storage::store_commitment(env, dispute_id, &record);
+
+    dispute.commit_count = dispute.commit_count.saturating_add(1);
+    if dispute.status == DisputeStatus::Open {
+        dispute.status = DisputeStatus::Voting;
+    }
+
+    storage::store_dispute(env, &dispute);
+
+    let event = VoteCommittedEvent { dispute_id, voter };
+    env.events().publish(("DisputeVoteCommitted",), event);
+
+    true
+}
+
+/// Reveal a sealed vote after the voting deadline and before the reveal
+/// deadline, adding it to the tallies.
+pub fn reveal_vote(
+    env: &Env,
+    dispute_id: u64,
+    voter: Address,
+    decision: VoteDecision,
+    salt: BytesN<32>,
+) -> bool {
+    voter.require_auth();
+
+    let mut dispute = storage::get_dispute(env, dispute_id).expect("dispute not found");
+
+    if dispute.status == DisputeStatus::Resolved || dispute.status == DisputeStatus::Expired {
+        panic!("dispute is closed");
+    }
+
+    let reveal_deadline = dispute
+        .reveal_deadline
+        .unwrap_or_else(|| panic!("dispute does not use commit-reveal voting"));
+
+    let now = env.ledger().timestamp();
+    if now <= dispute.voting_deadline {
+        panic!("reveal period not started");
+    }
+    if now > reveal_deadline {
+        panic!("reveal period ended");
+    }
+
+    let mut record = storage::get_commitment(env, dispute_id, &voter)
+        .unwrap_or_else(|| panic!("vote commitment not found"));
+    if record.revealed {
+        panic!("vote already revealed");
+    }
+    if vote_commitment(env, &voter, decision.code(), &salt) != record.commitment {
+        panic!("vote does not match commitment");
+    }
+
+    record.revealed = true;
+    storage::store_commitment(env, dispute_id, &record);
+
+    record_vote(env, &mut dispute, voter, decision, record.committed_at);
+    storage::store_dispute(env, &dispute);
 
     true
 }
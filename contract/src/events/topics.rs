@@ -36,6 +36,7 @@ pub const MOD_ALLOWANCE: &str    = "allowance";
 pub const MOD_EMERGENCY: &str    = "emergency";
 pub const MOD_UPGRADE: &str      = "upgrade";
 pub const MOD_PROXY: &str        = "proxy";
+pub const MOD_VESTING: &str      = "vesting";
 
 // =========== Shared action identifiers (used across multiple modules) ===========
 
@@ -62,6 +63,15 @@ pub const ACT_ROLE_UPDATED: &str   = "role_updated";
 
 pub const ACT_CLAIMED: &str    = "claimed";
 pub const ACT_SUBMITTED: &str  = "submitted";
+pub const ACT_REWARD_ADDED: &str = "reward_added";
+pub const ACT_SPLITS_SET: &str = "splits_set";
+pub const ACT_UNCLAIMED: &str  = "unclaimed";
+pub const ACT_BOND_RETURNED: &str = "bond_returned";
+pub const ACT_BOND_SLASHED: &str = "bond_slashed";
+pub const ACT_TEMPLATE_SET: &str = "template_set";
+pub const ACT_SPAWNED: &str    = "spawned";
+pub const ACT_REVIEWERS_SET: &str = "reviewers_set";
+pub const ACT_REVIEWED: &str   = "reviewed";
 
 // =========== Payment-specific actions ===========
 
@@ -0,0 +1,67 @@
+use soroban_sdk::{Address, Env};
+
+use crate::governance::storage::{
+    get_weight_checkpoint_at, get_weight_checkpoint_count, push_weight_checkpoint,
+};
+use crate::governance::types::{role_weight, VoteSnapshot};
+use crate::guild::storage as guild_storage;
+use crate::guild::types::Role;
+use crate::reputation::scoring::{compute_governance_weight, decay_score, integer_sqrt};
+use crate::reputation::storage::get_profile;
+
+/// Record `address`'s current role and reputation in the guild. Called after
+/// every change to either, so snapshots can look up the weight in force at
+/// any earlier point.
+pub(crate) fn record_weight_checkpoint(env: &Env, guild_id: u64, address: &Address) {
+    let member = guild_storage::get_member(env, guild_id, address);
+    // non-members only need a checkpoint once they leave the guild
+    if member.is_none() && get_weight_checkpoint_count(env, guild_id, address) == 0 {
+        return;
+    }
+    // a departed member's role no longer matters
+    let role = member.as_ref().map(|m| m.role).unwrap_or(Role::Contributor);
+
+    let (score, score_at) = match get_profile(env, address, guild_id) {
+        Some(profile) => (profile.decayed_score, profile.last_decay_applied),
+        None => (0, 0),
+    };
+    push_weight_checkpoint(
+        env,
+        guild_id,
+        address,
+        member.is_some(),
+        role,
+        score,
+        score_at,
+    );
+}
+
+/// `address`'s own weight (role plus decayed reputation) as of the snapshot,
+/// or `None` if they were not a member then
+pub(crate) fn weight_at(env: &Env, snapshot: &VoteSnapshot, address: &Address) -> Option<i128> {
+    if let Some(checkpoint) =
+        get_weight_checkpoint_at(env, snapshot.guild_id, address, snapshot.checkpoint)
+    {
+        if !checkpoint.member {
+            return None;
+        }
+        let score = decay_score(checkpoint.score, checkpoint.score_at, snapshot.taken_at);
+        return Some(role_weight(&checkpoint.role) + integer_sqrt(score) as i128);
+    }
+    if get_weight_checkpoint_count(env, snapshot.guild_id, address) > 0 {
+        // their first checkpoint came after the snapshot
+        return None;
+    }
+
+    // members unchanged since checkpoints were introduced have none
+    let member = guild_storage::get_member(env, snapshot.guild_id, address)?;
+    if member.joined_at > snapshot.taken_at {
+        return None;
+    }
+    Some(compute_governance_weight(
+        env,
+        address,
+        snapshot.guild_id,
+        &member.role,
+    ))
+}
@@ -0,0 +1,111 @@
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::governance::storage::{
+    get_proposal as load_proposal, get_vote_commitments, store_vote_commitment,
+};
+use crate::governance::types::{ProposalStatus, VoteCommitment, VoteCommittedEvent, VoteDecision};
+use crate::governance::voting::{load_votable_proposal, record_binary_vote, voting_power_of};
+
+const EVENT_TOPIC_VOTE_COMMITTED: &str = "vote_committed";
+
+/// Prefix of every vote commitment preimage
+const VOTE_COMMITMENT_DOMAIN: &[u8] = b"stellar-guilds:commit:v1";
+
+/// The commitment a voter submits for `choice`: the sha256 of the domain
+/// prefix, the voter's address, the choice byte and a 32-byte secret salt.
+/// Binding the voter stops anyone from copying another voter's commitment
+/// and revealing it once the original is revealed. Also used by dispute
+/// juries.
+pub fn vote_commitment(env: &Env, voter: &Address, choice: u8, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_slice(env, VOTE_COMMITMENT_DOMAIN);
+    preimage.append(&voter.clone().to_xdr(env));
+    preimage.push_back(choice);
+    preimage.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+/// Commit to a vote on a commit-reveal proposal while voting is open.
+/// Committing again replaces the earlier commitment.
+pub fn commit_vote(env: &Env, proposal_id: u64, voter: Address, commitment: BytesN<32>) -> bool {
+    voter.require_auth();
+
+    let proposal = load_votable_proposal(env, proposal_id);
+    if proposal.reveal_end.is_none() {
+        panic!("proposal does not use commit-reveal voting");
+    }
+    // checks the voter could vote on the proposal
+    voting_power_of(env, proposal_id, &voter);
+
+    let record = VoteCommitment {
+        voter: voter.clone(),
+        commitment,
+        committed_at: env.ledger().timestamp(),
+        revealed: false,
+    };
+    store_vote_commitment(env, proposal_id, &record);
+
+    let event = VoteCommittedEvent { proposal_id, voter };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_VOTE_COMMITTED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+/// Open a commitment during the reveal window after voting closes, casting
+/// the vote as of when it was committed.
+pub fn reveal_vote(
+    env: &Env,
+    proposal_id: u64,
+    voter: Address,
+    decision: VoteDecision,
+    salt: BytesN<32>,
+) -> bool {
+    voter.require_auth();
+
+    let proposal = load_proposal(env, proposal_id).unwrap_or_else(|| panic!("proposal not found"));
+    let reveal_end = proposal
+        .reveal_end
+        .unwrap_or_else(|| panic!("proposal does not use commit-reveal voting"));
+    if !matches!(proposal.status, ProposalStatus::Active) {
+        panic!("proposal not active");
+    }
+
+    let now = env.ledger().timestamp();
+    if now <= proposal.voting_end {
+        panic!("reveal period not started");
+    }
+    if now > reveal_end {
+        panic!("reveal period closed");
+    }
+
+    let mut record = get_vote_commitments(env, proposal_id)
+        .get(voter.clone())
+        .unwrap_or_else(|| panic!("vote commitment not found"));
+    if record.revealed {
+        panic!("vote already revealed");
+    }
+    if vote_commitment(env, &voter, decision.code(), &salt) != record.commitment {
+        panic!("vote does not match commitment");
+    }
+
+    record.revealed = true;
+    store_vote_commitment(env, proposal_id, &record);
+    record_binary_vote(env, &proposal, voter, decision, record.committed_at);
+
+    true
+}
+
+/// Commitment `voter` made on a commit-reveal proposal, if any
+pub fn get_vote_commitment(env: &Env, proposal_id: u64, voter: Address) -> Option<VoteCommitment> {
+    get_vote_commitments(env, proposal_id).get(voter)
+}
+
+/// Every commitment made on a commit-reveal proposal, revealed or not
+pub fn get_proposal_vote_commitments(env: &Env, proposal_id: u64) -> Vec<VoteCommitment> {
+    get_vote_commitments(env, proposal_id).values()
+}
@@ -0,0 +1,372 @@
+use soroban_sdk::{Address, Env, Map, Symbol, Vec};
+
+use crate::governance::storage::{
+    get_delegation_checkpoint_counts, get_delegations, get_delegations_at, get_guild_delegations,
+    set_delegations,
+};
+use crate::governance::types::{
+    Delegation, DelegationScope, DelegationSplit, DelegationUpdatedEvent, VoteDelegatedEvent,
+    VoteSnapshot, VoteUndelegatedEvent,
+};
+use crate::guild::storage as guild_storage;
+
+const EVENT_TOPIC_VOTE_DELEGATED: &str = "vote_delegated";
+const EVENT_TOPIC_VOTE_UNDELEGATED: &str = "vote_undelegated";
+const EVENT_TOPIC_DELEGATION_UPDATED: &str = "delegation_updated";
+
+pub const MAX_DELEGATION_SPLITS: u32 = 5;
+/// Longest delegation chain followed when weight flows to delegates
+pub const MAX_DELEGATION_DEPTH: u32 = 16;
+
+fn is_active(delegation: &Delegation, now: u64) -> bool {
+    delegation.expires_at == 0 || delegation.expires_at > now
+}
+
+/// Whether `target` can be reached from `from` by following active
+/// delegations of any scope
+fn reaches(env: &Env, guild_id: u64, from: &Address, target: &Address) -> bool {
+    let now = env.ledger().timestamp();
+    let all = get_guild_delegations(env, guild_id);
+
+    let mut frontier: Vec<Address> = Vec::new(env);
+    frontier.push_back(from.clone());
+    let mut visited: Vec<Address> = Vec::new(env);
+
+    for _ in 0..MAX_DELEGATION_DEPTH {
+        let mut next: Vec<Address> = Vec::new(env);
+        for addr in frontier.iter() {
+            if addr == *target {
+                return true;
+            }
+            if visited.contains(&addr) {
+                continue;
+            }
+            visited.push_back(addr.clone());
+
+            for delegation in all.get(addr).unwrap_or_else(|| Vec::new(env)).iter() {
+                if !is_active(&delegation, now) {
+                    continue;
+                }
+                for split in delegation.splits.iter() {
+                    next.push_back(split.delegate);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+    }
+
+    false
+}
+
+fn apply_delegation(
+    env: &Env,
+    guild_id: u64,
+    delegator: &Address,
+    scope: DelegationScope,
+    splits: Vec<DelegationSplit>,
+    expires_at: u64,
+) {
+    let _d1 = guild_storage::get_member(env, guild_id, delegator)
+        .unwrap_or_else(|| panic!("delegator must be guild member"));
+
+    if splits.is_empty() || splits.len() > MAX_DELEGATION_SPLITS {
+        panic!("invalid number of delegates");
+    }
+
+    let now = env.ledger().timestamp();
+    if expires_at != 0 && expires_at <= now {
+        panic!("delegation expiry must be in the future");
+    }
+
+    let mut total: u32 = 0;
+    let mut seen: Vec<Address> = Vec::new(env);
+    for split in splits.iter() {
+        if split.delegate == *delegator {
+            panic!("cannot delegate to self");
+        }
+        if split.percentage == 0 || split.percentage > 100 {
+            panic!("invalid delegation percentage");
+        }
+        if seen.contains(&split.delegate) {
+            panic!("duplicate delegate");
+        }
+        seen.push_back(split.delegate.clone());
+        total += split.percentage;
+
+        let _d2 = guild_storage::get_member(env, guild_id, &split.delegate)
+            .unwrap_or_else(|| panic!("delegate must be guild member"));
+
+        if reaches(env, guild_id, &split.delegate, delegator) {
+            panic!("delegation cycle detected");
+        }
+    }
+    if total > 100 {
+        panic!("delegation percentages exceed 100");
+    }
+
+    // a new delegation replaces the delegator's previous one for the scope
+    let mut list: Vec<Delegation> = Vec::new(env);
+    for existing in get_delegations(env, guild_id, delegator).iter() {
+        if existing.scope != scope {
+            list.push_back(existing);
+        }
+    }
+    list.push_back(Delegation {
+        guild_id,
+        delegator: delegator.clone(),
+        scope,
+        splits,
+        expires_at,
+        created_at: now,
+    });
+    set_delegations(env, guild_id, delegator, &list);
+}
+
+/// Delegate all of a member's weight to one delegate for every proposal type
+/// without its own topic delegation, until revoked
+pub fn delegate_vote(env: &Env, guild_id: u64, delegator: Address, delegate: Address) -> bool {
+    delegator.require_auth();
+
+    let mut splits = Vec::new(env);
+    splits.push_back(DelegationSplit {
+        delegate: delegate.clone(),
+        percentage: 100,
+    });
+    apply_delegation(env, guild_id, &delegator, DelegationScope::All, splits, 0);
+
+    let event = VoteDelegatedEvent {
+        guild_id,
+        delegator,
+        delegate,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_VOTE_DELEGATED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+/// Delegate shares of a member's weight to up to `MAX_DELEGATION_SPLITS`
+/// delegates, for one proposal type or all of them, optionally until
+/// `expires_at`. A topic delegation takes precedence over an `All` one, and
+/// a delegator who votes directly on a proposal overrides both.
+pub fn delegate_vote_scoped(
+    env: &Env,
+    guild_id: u64,
+    delegator: Address,
+    scope: DelegationScope,
+    splits: Vec<DelegationSplit>,
+    expires_at: u64,
+) -> bool {
+    delegator.require_auth();
+
+    apply_delegation(
+        env,
+        guild_id,
+        &delegator,
+        scope.clone(),
+        splits.clone(),
+        expires_at,
+    );
+
+    let event = DelegationUpdatedEvent {
+        guild_id,
+        delegator,
+        scope,
+        splits,
+        expires_at,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_DELEGATION_UPDATED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+/// Revoke every delegation of a member in a guild
+pub fn undelegate_vote(env: &Env, guild_id: u64, delegator: Address) -> bool {
+    delegator.require_auth();
+
+    set_delegations(env, guild_id, &delegator, &Vec::new(env));
+
+    let event = VoteUndelegatedEvent {
+        guild_id,
+        delegator,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_VOTE_UNDELEGATED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+/// Revoke a member's delegation for one scope only
+pub fn undelegate_vote_scoped(
+    env: &Env,
+    guild_id: u64,
+    delegator: Address,
+    scope: DelegationScope,
+) -> bool {
+    delegator.require_auth();
+
+    let existing = get_delegations(env, guild_id, &delegator);
+    let mut list: Vec<Delegation> = Vec::new(env);
+    for delegation in existing.iter() {
+        if delegation.scope != scope {
+            list.push_back(delegation);
+        }
+    }
+    if list.len() == existing.len() {
+        panic!("delegation not found");
+    }
+    set_delegations(env, guild_id, &delegator, &list);
+
+    // an update with no splits marks the scope as revoked
+    let event = DelegationUpdatedEvent {
+        guild_id,
+        delegator,
+        scope,
+        splits: Vec::new(env),
+        expires_at: 0,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_DELEGATION_UPDATED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+/// Unexpired delegations made by `address`
+pub fn get_outbound_delegations(env: &Env, guild_id: u64, address: Address) -> Vec<Delegation> {
+    let now = env.ledger().timestamp();
+    let mut result = Vec::new(env);
+    for delegation in get_delegations(env, guild_id, &address).iter() {
+        if is_active(&delegation, now) {
+            result.push_back(delegation);
+        }
+    }
+    result
+}
+
+/// Unexpired delegations that give `address` a share of someone's weight
+pub fn get_inbound_delegations(env: &Env, guild_id: u64, address: Address) -> Vec<Delegation> {
+    let now = env.ledger().timestamp();
+    let mut result = Vec::new(env);
+    for list in get_guild_delegations(env, guild_id).values().iter() {
+        for delegation in list.iter() {
+            if !is_active(&delegation, now) {
+                continue;
+            }
+            if delegation.splits.iter().any(|s| s.delegate == address) {
+                result.push_back(delegation);
+            }
+        }
+    }
+    result
+}
+
+/// The delegation each member had in force for the snapshot's proposal type
+/// when the snapshot was taken
+pub(crate) fn delegations_at(
+    env: &Env,
+    snapshot: &VoteSnapshot,
+) -> Map<Address, Vec<DelegationSplit>> {
+    let topic = DelegationScope::Topic(snapshot.proposal_type.clone());
+
+    let mut result = Map::new(env);
+    for (delegator, count) in get_delegation_checkpoint_counts(env, snapshot.guild_id).iter() {
+        let list = get_delegations_at(
+            env,
+            snapshot.guild_id,
+            &delegator,
+            count,
+            snapshot.checkpoint,
+        );
+        let mut general: Option<Vec<DelegationSplit>> = None;
+        let mut specific: Option<Vec<DelegationSplit>> = None;
+        for delegation in list.iter() {
+            if !is_active(&delegation, snapshot.taken_at) {
+                continue;
+            }
+            if delegation.scope == topic {
+                specific = Some(delegation.splits);
+            } else if delegation.scope == DelegationScope::All {
+                general = Some(delegation.splits);
+            }
+        }
+        if let Some(splits) = specific.or(general) {
+            result.set(delegator, splits);
+        }
+    }
+    result
+}
+
+/// Let every member's weight flow along their delegations. Weight stops at
+/// members who voted directly (`voters`) or who have not delegated; shares a
+/// delegator did not hand out, including rounding remainders, stay with
+/// them. Chains longer than `MAX_DELEGATION_DEPTH` stop where they are.
+pub(crate) fn flow_voting_power(
+    env: &Env,
+    base_weight: &Map<Address, i128>,
+    delegations: &Map<Address, Vec<DelegationSplit>>,
+    voters: &Map<Address, bool>,
+) -> Map<Address, i128> {
+    let mut power: Map<Address, i128> = Map::new(env);
+    for addr in base_weight.keys().iter() {
+        power.set(addr, 0);
+    }
+
+    let mut pending = base_weight.clone();
+    for _ in 0..MAX_DELEGATION_DEPTH {
+        let mut next: Map<Address, i128> = Map::new(env);
+        for (addr, amount) in pending.iter() {
+            let splits = if voters.contains_key(addr.clone()) {
+                None
+            } else {
+                delegations.get(addr.clone())
+            };
+
+            let mut kept = amount;
+            if let Some(splits) = splits {
+                for split in splits.iter() {
+                    let share = amount * (split.percentage as i128) / 100;
+                    kept -= share;
+                    let queued = next.get(split.delegate.clone()).unwrap_or(0);
+                    next.set(split.delegate, queued + share);
+                }
+            }
+            let current = power.get(addr.clone()).unwrap_or(0);
+            power.set(addr, current + kept);
+        }
+
+        if next.is_empty() {
+            return power;
+        }
+        pending = next;
+    }
+
+    for (addr, amount) in pending.iter() {
+        let current = power.get(addr.clone()).unwrap_or(0);
+        power.set(addr, current + amount);
+    }
+    power
+}
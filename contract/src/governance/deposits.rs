@@ -0,0 +1,109 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::governance::storage::{get_deposit, store_deposit};
+use crate::governance::types::{
+    DepositRule, DepositStatus, ProposalDeposit, ProposalDepositSettledEvent,
+};
+use crate::guild::storage::resolve_token;
+use crate::ledger::{self, SubAccount};
+use crate::treasury::management::credit_from_sub_account;
+use crate::treasury::types::TransactionType;
+
+const EVENT_TOPIC_DEPOSIT_SETTLED: &str = "proposal_deposit_settled";
+
+pub(crate) fn validate_deposit_rule(env: &Env, guild_id: u64, rule: &DepositRule) {
+    if rule.amount < 0 {
+        panic!("invalid deposit amount");
+    }
+    if rule.amount == 0 {
+        return;
+    }
+
+    let treasury = crate::treasury::storage::get_treasury(env, rule.treasury_id)
+        .unwrap_or_else(|| panic!("treasury not found"));
+    if treasury.guild_id != guild_id {
+        panic!("deposit treasury must belong to guild");
+    }
+}
+
+/// Lock the guild's current deposit from the proposer of a new proposal
+pub(crate) fn lock_deposit(env: &Env, proposal_id: u64, proposer: &Address, rule: &DepositRule) {
+    if rule.amount == 0 {
+        return;
+    }
+
+    ledger::deposit(
+        env,
+        &SubAccount::ProposalDeposit(proposal_id),
+        &resolve_token(env, &rule.token),
+        proposer,
+        rule.amount,
+    );
+
+    store_deposit(
+        env,
+        &ProposalDeposit {
+            proposal_id,
+            depositor: proposer.clone(),
+            amount: rule.amount,
+            token: rule.token.clone(),
+            treasury_id: rule.treasury_id,
+            status: DepositStatus::Locked,
+            settled_at: None,
+        },
+    );
+}
+
+/// Return a locked deposit to its depositor, or forfeit it to the guild
+/// treasury named when it was locked. Does nothing for proposals without a
+/// locked deposit.
+pub(crate) fn settle_deposit(env: &Env, proposal_id: u64, refund: bool) {
+    let mut deposit = match get_deposit(env, proposal_id) {
+        Some(d) if d.status == DepositStatus::Locked => d,
+        _ => return,
+    };
+
+    let account = SubAccount::ProposalDeposit(proposal_id);
+    if refund {
+        ledger::withdraw(
+            env,
+            &account,
+            &resolve_token(env, &deposit.token),
+            &deposit.depositor,
+            deposit.amount,
+        );
+        deposit.status = DepositStatus::Refunded;
+    } else {
+        credit_from_sub_account(
+            env,
+            deposit.treasury_id,
+            &account,
+            &deposit.token,
+            deposit.amount,
+            TransactionType::Deposit,
+            "forfeited proposal deposit",
+            proposal_id,
+        );
+        deposit.status = DepositStatus::Forfeited;
+    }
+    deposit.settled_at = Some(env.ledger().timestamp());
+    store_deposit(env, &deposit);
+
+    let event = ProposalDepositSettledEvent {
+        proposal_id,
+        depositor: deposit.depositor,
+        amount: deposit.amount,
+        status: deposit.status,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_DEPOSIT_SETTLED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+}
+
+pub fn get_proposal_deposit(env: &Env, proposal_id: u64) -> ProposalDeposit {
+    get_deposit(env, proposal_id).unwrap_or_else(|| panic!("deposit not found"))
+}
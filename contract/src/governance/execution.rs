@@ -1,7 +1,8 @@
 use soroban_sdk::{Address, Env, Symbol};
 
+use crate::governance::deposits::validate_deposit_rule;
 use crate::governance::proposals::get_proposal as load_proposal;
-use crate::governance::storage::store_proposal;
+use crate::governance::storage::{get_config, set_config, store_proposal};
 use crate::governance::types::{
     ExecutionPayload, Proposal, ProposalExecutedEvent, ProposalStatus, ProposalType,
 };
@@ -17,34 +18,54 @@ pub fn execute_proposal(env: &Env, proposal_id: u64, executor: Address) -> bool
     if matches!(proposal.status, ProposalStatus::Active) && now >= proposal.voting_end {
         let _status = finalize_proposal(env, proposal_id);
         proposal = load_proposal(env, proposal_id);
-        if !matches!(proposal.status, ProposalStatus::Passed) {
+        if !matches!(
+            proposal.status,
+            ProposalStatus::Passed | ProposalStatus::Queued
+        ) {
             panic!("proposal not passed");
         }
     }
 
-    if !matches!(proposal.status, ProposalStatus::Passed) {
+    if !matches!(
+        proposal.status,
+        ProposalStatus::Passed | ProposalStatus::Queued
+    ) {
         panic!("only passed proposals can be executed");
     }
 
-    if let Some(passed_at) = proposal.passed_at {
-        if now > passed_at + EXECUTION_DEADLINE_SECONDS {
+    // the execution window opens once any queue delay has elapsed
+    let window_start = proposal.executable_at.or(proposal.passed_at);
+    if let Some(executable_at) = proposal.executable_at {
+        if now < executable_at {
+            panic!("proposal still queued");
+        }
+    }
+
+    if let Some(window_start) = window_start {
+        if now > window_start + EXECUTION_DEADLINE_SECONDS {
             proposal.status = ProposalStatus::Expired;
             store_proposal(env, &proposal);
             panic!("execution window expired");
         }
     }
 
-    let success = match (&proposal.proposal_type, &proposal.execution_payload) {
-        (ProposalType::TreasurySpend, ExecutionPayload::TreasurySpend) => {
-            // High-security action: Relies on the new multisig flow.
-            true
-        }
-        (ProposalType::RuleChange, ExecutionPayload::RuleChange) => {
-            // High-security action
-            true
-        }
-        (ProposalType::GeneralDecision, ExecutionPayload::GeneralDecision) => true,
-        _ => false,
+    // a multi-option proposal runs the action attached to its winning option
+    let success = match proposal.winning_option {
+        Some(index) => match proposal.options.get(index) {
+            Some(option) => run_payload(
+                env,
+                &proposal,
+                &option.payload.proposal_type(),
+                &option.payload,
+            ),
+            None => false,
+        },
+        None => run_payload(
+            env,
+            &proposal,
+            &proposal.proposal_type,
+            &proposal.execution_payload,
+        ),
     };
 
     let mut proposal_to_update: Proposal = proposal.clone();
@@ -68,3 +89,34 @@ pub fn execute_proposal(env: &Env, proposal_id: u64, executor: Address) -> bool
 
     success
 }
+
+fn run_payload(
+    env: &Env,
+    proposal: &Proposal,
+    proposal_type: &ProposalType,
+    payload: &ExecutionPayload,
+) -> bool {
+    match (proposal_type, payload) {
+        (ProposalType::TreasurySpend, ExecutionPayload::TreasurySpend) => {
+            // High-security action: Relies on the new multisig flow.
+            true
+        }
+        (ProposalType::RuleChange, ExecutionPayload::RuleChange) => {
+            // High-security action
+            true
+        }
+        (ProposalType::RuleChange, ExecutionPayload::DepositRuleChange(rule)) => {
+            validate_deposit_rule(env, proposal.guild_id, rule);
+            let mut cfg = get_config(env, proposal.guild_id);
+            cfg.proposal_deposit = rule.clone();
+            set_config(env, proposal.guild_id, &cfg);
+            true
+        }
+        (ProposalType::GeneralDecision, ExecutionPayload::GeneralDecision) => true,
+        (ProposalType::TreasuryVeto, ExecutionPayload::TreasuryVeto(tx_id)) => {
+            crate::treasury::governance_veto(env, *tx_id, proposal.guild_id, proposal.id);
+            true
+        }
+        _ => false,
+    }
+}
@@ -1,18 +1,58 @@
+pub mod checkpoints;
+pub mod commit;
+pub mod delegation;
+pub mod deposits;
 pub mod execution;
+pub mod optimistic;
+pub mod options;
 pub mod proposals;
+pub mod queue;
+pub mod signed;
 pub mod storage;
 pub mod types;
 pub mod voting;
 
 pub use types::{
-    ExecutionPayload, GovernanceConfig, Proposal, ProposalStatus, ProposalType, VoteDecision,
+    BallotType, Delegation, DelegationScope, DelegationSplit, DepositRule, ExecutionPayload,
+    GovernanceConfig, OptionBallot, Proposal, ProposalDeposit, ProposalOption, ProposalStatus,
+    ProposalType, ProposalVeto, SignedVote, Vote, VoteCommitment, VoteDecision, VoteSnapshot,
+    VotingMode,
 };
 
 pub use proposals::{
-    cancel_proposal, create_proposal, get_active_proposals, get_proposal, update_governance_config,
+    cancel_proposal, cancel_proposal_for_abuse, create_multi_option_proposal,
+    create_optimistic_proposal, create_private_proposal, create_proposal, get_active_proposals,
+    get_all_proposals, get_proposal, get_proposals_by_status, set_proposal_type_voting_mode,
+    update_governance_config,
 };
 
-pub use voting::{delegate_vote, finalize_proposal, undelegate_vote, vote};
+pub use voting::{
+    finalize_proposal, get_proposal_vote_snapshot, get_proposal_votes, vote, vote_quadratic,
+};
+
+pub use commit::{
+    commit_vote, get_proposal_vote_commitments, get_vote_commitment, reveal_vote, vote_commitment,
+};
+
+pub use delegation::{
+    delegate_vote, delegate_vote_scoped, get_inbound_delegations, get_outbound_delegations,
+    undelegate_vote, undelegate_vote_scoped,
+};
+
+pub use deposits::get_proposal_deposit;
+
+pub use optimistic::{get_proposal_objections, object_to_proposal};
+
+pub use options::{get_proposal_option_ballots, vote_options};
+
+pub use queue::{
+    get_governance_guardians, get_proposal_veto, get_queued_proposals, set_governance_guardians,
+    veto_proposal,
+};
+
+pub use signed::{
+    get_signed_vote_nonce, register_vote_key, signed_vote_payload, submit_signed_votes,
+};
 
 pub use execution::execute_proposal;
 
@@ -0,0 +1,111 @@
+use soroban_sdk::{Address, Env, Map, Symbol, Vec};
+
+use crate::governance::storage::{
+    get_config, get_objections, get_proposal as load_proposal, set_objections, store_proposal,
+};
+use crate::governance::types::{
+    ProposalEscalatedEvent, ProposalObjectedEvent, ProposalStatus, VoteSnapshot,
+};
+use crate::governance::voting::{
+    effective_voting_power, get_proposal_vote_snapshot, voting_power_of,
+};
+
+const EVENT_TOPIC_PROPOSAL_OBJECTED: &str = "proposal_objected";
+const EVENT_TOPIC_PROPOSAL_ESCALATED: &str = "proposal_escalated";
+
+/// Combined weight of the objectors. An objector keeps all of their own
+/// weight, and delegates who object also carry the weight of delegators who
+/// did not object themselves.
+fn objection_weight(env: &Env, snapshot: &VoteSnapshot, objections: &Map<Address, u64>) -> i128 {
+    let mut objectors: Map<Address, bool> = Map::new(env);
+    for objector in objections.keys().iter() {
+        objectors.set(objector, true);
+    }
+    let effective = effective_voting_power(env, snapshot, &objectors);
+
+    let mut total: i128 = 0;
+    for objector in objectors.keys().iter() {
+        total += effective.get(objector).unwrap_or(0);
+    }
+    total
+}
+
+/// Object to an optimistic proposal during its objection window. Once
+/// objections exceed the guild's objection threshold the proposal becomes a
+/// full vote lasting the guild's voting period. Returns whether this
+/// objection escalated the proposal.
+pub fn object_to_proposal(env: &Env, proposal_id: u64, objector: Address) -> bool {
+    objector.require_auth();
+
+    let mut proposal =
+        load_proposal(env, proposal_id).unwrap_or_else(|| panic!("proposal not found"));
+    if !proposal.optimistic {
+        panic!("proposal is not optimistic");
+    }
+    if !matches!(proposal.status, ProposalStatus::Active) {
+        panic!("proposal not active");
+    }
+
+    let now = env.ledger().timestamp();
+    if now > proposal.voting_end {
+        panic!("objection window closed");
+    }
+
+    // checks the objector could vote on the proposal
+    voting_power_of(env, proposal_id, &objector);
+
+    let mut objections = get_objections(env, proposal_id);
+    if objections.contains_key(objector.clone()) {
+        panic!("already objected");
+    }
+    objections.set(objector.clone(), now);
+    set_objections(env, proposal_id, &objections);
+
+    let snapshot = get_proposal_vote_snapshot(env, proposal_id);
+    proposal.objection_weight = objection_weight(env, &snapshot, &objections);
+
+    let event = ProposalObjectedEvent {
+        proposal_id,
+        objector,
+        objection_weight: proposal.objection_weight,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_PROPOSAL_OBJECTED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    let cfg = get_config(env, proposal.guild_id);
+    let threshold = snapshot.total_weight * (cfg.objection_threshold_percentage as i128) / 100;
+    if proposal.objection_weight <= threshold {
+        store_proposal(env, &proposal);
+        return false;
+    }
+
+    proposal.optimistic = false;
+    proposal.voting_start = now;
+    proposal.voting_end = now + (cfg.voting_period_days as u64) * 24 * 60 * 60;
+    store_proposal(env, &proposal);
+
+    let event = ProposalEscalatedEvent {
+        proposal_id,
+        objection_weight: proposal.objection_weight,
+        voting_end: proposal.voting_end,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_PROPOSAL_ESCALATED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+/// Members who objected to an optimistic proposal
+pub fn get_proposal_objections(env: &Env, proposal_id: u64) -> Vec<Address> {
+    get_objections(env, proposal_id).keys()
+}
@@ -0,0 +1,244 @@
+use soroban_sdk::{Address, Env, Map, Symbol, Vec};
+
+use crate::governance::storage::{get_option_ballots, store_option_ballot};
+use crate::governance::types::{
+    BallotType, GovernanceConfig, OptionBallot, OptionBallotCastEvent, Proposal, ProposalStatus,
+    VoteSnapshot,
+};
+use crate::governance::voting::{
+    effective_voting_power, load_votable_proposal, quorum_reached, voting_power_of,
+};
+
+const EVENT_TOPIC_OPTION_BALLOT_CAST: &str = "option_ballot_cast";
+
+/// Cast or replace a ballot on a multi-option proposal. `choices` holds the
+/// single chosen option, the approved options, or the options ranked from
+/// most to least preferred, depending on the proposal's ballot type.
+pub fn vote_options(env: &Env, proposal_id: u64, voter: Address, choices: Vec<u32>) -> bool {
+    voter.require_auth();
+
+    let proposal = load_votable_proposal(env, proposal_id);
+    match proposal.ballot_type {
+        BallotType::Binary => panic!("proposal is not a multi-option proposal"),
+        BallotType::SingleChoice => {
+            if choices.len() != 1 {
+                panic!("single-choice ballots take exactly one option");
+            }
+        }
+        BallotType::Approval | BallotType::RankedChoice => {
+            if choices.is_empty() {
+                panic!("ballot must choose at least one option");
+            }
+        }
+    }
+
+    let mut seen: Vec<u32> = Vec::new(env);
+    for choice in choices.iter() {
+        if choice >= proposal.options.len() {
+            panic!("invalid option");
+        }
+        if seen.contains(choice) {
+            panic!("duplicate option");
+        }
+        seen.push_back(choice);
+    }
+
+    let weight = voting_power_of(env, proposal_id, &voter);
+
+    let ballot = OptionBallot {
+        voter: voter.clone(),
+        proposal_id,
+        choices: choices.clone(),
+        weight,
+        timestamp: env.ledger().timestamp(),
+    };
+    store_option_ballot(env, &ballot);
+
+    let event = OptionBallotCastEvent {
+        proposal_id,
+        voter,
+        choices,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_OPTION_BALLOT_CAST),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+/// Tally a multi-option proposal whose voting period has ended. The
+/// proposal passes when ballots reach the guild's quorum and a single
+/// option wins; ties are rejected. Returns whether quorum was reached.
+pub(crate) fn tally_options(
+    env: &Env,
+    proposal: &mut Proposal,
+    cfg: &GovernanceConfig,
+    snapshot: &VoteSnapshot,
+) -> bool {
+    let stored = get_option_ballots(env, proposal.id);
+
+    // weigh ballots by power at the close, once direct votes by delegators
+    // have overridden their delegations
+    let mut voters: Map<Address, bool> = Map::new(env);
+    for voter in stored.keys().iter() {
+        voters.set(voter, true);
+    }
+    let effective = effective_voting_power(env, snapshot, &voters);
+
+    let mut ballots: Vec<OptionBallot> = Vec::new(env);
+    let mut participation: i128 = 0;
+    for mut ballot in stored.values().iter() {
+        ballot.weight = effective.get(ballot.voter.clone()).unwrap_or(0);
+        participation += ballot.weight;
+        ballots.push_back(ballot);
+    }
+
+    let (weights, winner) = match proposal.ballot_type {
+        BallotType::RankedChoice => instant_runoff(env, proposal, &ballots),
+        _ => plurality(env, proposal, &ballots),
+    };
+    proposal.option_weights = weights;
+
+    let quorum_reached = quorum_reached(cfg, snapshot, participation, ballots.len());
+    if !quorum_reached || winner.is_none() {
+        proposal.status = ProposalStatus::Rejected;
+        return quorum_reached;
+    }
+
+    proposal.winning_option = winner;
+    proposal.status = ProposalStatus::Passed;
+    if proposal.passed_at.is_none() {
+        proposal.passed_at = Some(env.ledger().timestamp());
+    }
+    true
+}
+
+fn zeroed(env: &Env, len: u32) -> Vec<i128> {
+    let mut tallies = Vec::new(env);
+    for _ in 0..len {
+        tallies.push_back(0);
+    }
+    tallies
+}
+
+/// The option holding strictly the most weight, if any
+fn leader(tallies: &Vec<i128>, eliminated: &Vec<bool>) -> Option<u32> {
+    let mut best: Option<u32> = None;
+    let mut best_weight: i128 = 0;
+    let mut tied = false;
+
+    for (i, weight) in tallies.iter().enumerate() {
+        if eliminated.get(i as u32).unwrap_or(false) || weight <= 0 {
+            continue;
+        }
+        if weight > best_weight {
+            best = Some(i as u32);
+            best_weight = weight;
+            tied = false;
+        } else if weight == best_weight {
+            tied = true;
+        }
+    }
+
+    if tied {
+        None
+    } else {
+        best
+    }
+}
+
+/// Single-choice and approval ballots: every chosen option receives the
+/// voter's full weight.
+fn plurality(
+    env: &Env,
+    proposal: &Proposal,
+    ballots: &Vec<OptionBallot>,
+) -> (Vec<i128>, Option<u32>) {
+    let mut tallies = zeroed(env, proposal.options.len());
+    for ballot in ballots.iter() {
+        for choice in ballot.choices.iter() {
+            let current = tallies.get(choice).unwrap_or(0);
+            tallies.set(choice, current + ballot.weight);
+        }
+    }
+
+    let none_eliminated = Vec::new(env);
+    let winner = leader(&tallies, &none_eliminated);
+    (tallies, winner)
+}
+
+/// Ranked ballots: each round counts every ballot for its highest-ranked
+/// remaining option. An option with a majority of the counted weight wins;
+/// otherwise all options tied for the fewest votes are eliminated and the
+/// ballots are recounted. Returns the tallies of the final round.
+fn instant_runoff(
+    env: &Env,
+    proposal: &Proposal,
+    ballots: &Vec<OptionBallot>,
+) -> (Vec<i128>, Option<u32>) {
+    let option_count = proposal.options.len();
+    let mut eliminated: Vec<bool> = Vec::new(env);
+    for _ in 0..option_count {
+        eliminated.push_back(false);
+    }
+
+    loop {
+        let mut tallies = zeroed(env, option_count);
+        let mut counted: i128 = 0;
+        for ballot in ballots.iter() {
+            for choice in ballot.choices.iter() {
+                if !eliminated.get(choice).unwrap_or(true) {
+                    let current = tallies.get(choice).unwrap_or(0);
+                    tallies.set(choice, current + ballot.weight);
+                    counted += ballot.weight;
+                    break;
+                }
+            }
+        }
+
+        if counted <= 0 {
+            return (tallies, None);
+        }
+
+        if let Some(top) = leader(&tallies, &eliminated) {
+            if tallies.get(top).unwrap_or(0) * 2 > counted {
+                return (tallies, Some(top));
+            }
+        }
+
+        let mut fewest: Option<i128> = None;
+        let mut most: i128 = 0;
+        for i in 0..option_count {
+            if eliminated.get(i).unwrap_or(true) {
+                continue;
+            }
+            let weight = tallies.get(i).unwrap_or(0);
+            fewest = Some(fewest.map_or(weight, |f| f.min(weight)));
+            most = most.max(weight);
+        }
+
+        let fewest = match fewest {
+            Some(f) => f,
+            None => return (tallies, None),
+        };
+        // every remaining option is tied, so nobody can be eliminated
+        if fewest == most {
+            return (tallies, None);
+        }
+
+        for i in 0..option_count {
+            if !eliminated.get(i).unwrap_or(true) && tallies.get(i).unwrap_or(0) == fewest {
+                eliminated.set(i, true);
+            }
+        }
+    }
+}
+
+/// Every ballot cast on a multi-option proposal
+pub fn get_proposal_option_ballots(env: &Env, proposal_id: u64) -> Vec<OptionBallot> {
+    get_option_ballots(env, proposal_id).values()
+}
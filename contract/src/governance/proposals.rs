@@ -1,18 +1,27 @@
 use soroban_sdk::{Address, Env, String, Symbol, Vec};
 
+use crate::governance::deposits::{lock_deposit, settle_deposit, validate_deposit_rule};
 use crate::governance::storage::{
     get_config, get_guild_proposals, get_next_proposal_id, get_proposal as load_proposal,
-    set_config, store_proposal,
+    get_type_voting_mode, set_config, set_type_voting_mode, store_proposal,
 };
 use crate::governance::types::{
-    ExecutionPayload, GovernanceConfig, GovernanceConfigUpdatedEvent, Proposal,
-    ProposalCreatedEvent, ProposalStatus, ProposalType,
+    BallotType, ExecutionPayload, GovernanceConfig, GovernanceConfigUpdatedEvent, Proposal,
+    ProposalCreatedEvent, ProposalOption, ProposalStatus, ProposalType, VotingMode,
+    VotingModeUpdatedEvent,
 };
+use crate::governance::voting::take_vote_snapshot;
 use crate::guild::storage as guild_storage;
-use crate::guild::types::Member;
+use crate::guild::types::{Member, Role};
 
 const EVENT_TOPIC_PROPOSAL_CREATED: &str = "proposal_created";
 const EVENT_TOPIC_CONFIG_UPDATED: &str = "gov_config_updated";
+const EVENT_TOPIC_VOTING_MODE_UPDATED: &str = "voting_mode_updated";
+
+pub const MAX_PROPOSAL_OPTIONS: u32 = 10;
+pub const MAX_EXECUTION_DELAY_SECONDS: u64 = 30 * 24 * 60 * 60;
+pub const MAX_REVEAL_PERIOD_SECONDS: u64 = 30 * 24 * 60 * 60;
+pub const MAX_OBJECTION_WINDOW_SECONDS: u64 = 30 * 24 * 60 * 60;
 
 fn validate_execution_payload(
     env: &Env,
@@ -26,7 +35,19 @@ fn validate_execution_payload(
         (ProposalType::AddMember, ExecutionPayload::AddMember) => {}
         (ProposalType::RemoveMember, ExecutionPayload::RemoveMember) => {}
         (ProposalType::RuleChange, ExecutionPayload::RuleChange) => {}
+        (ProposalType::RuleChange, ExecutionPayload::DepositRuleChange(rule)) => {
+            validate_deposit_rule(env, guild_id, rule);
+        }
         (ProposalType::GeneralDecision, ExecutionPayload::GeneralDecision) => {}
+        (ProposalType::TreasuryVeto, ExecutionPayload::TreasuryVeto(tx_id)) => {
+            let tx = crate::treasury::storage::get_transaction(env, *tx_id)
+                .unwrap_or_else(|| panic!("transaction not found"));
+            let treasury = crate::treasury::storage::get_treasury(env, tx.treasury_id)
+                .unwrap_or_else(|| panic!("treasury not found"));
+            if treasury.guild_id != guild_id {
+                panic!("transaction does not belong to guild treasury");
+            }
+        }
         _ => {
             panic!("execution payload does not match proposal type");
         }
@@ -56,6 +77,155 @@ pub fn create_proposal(
     title: String,
     description: String,
     execution_payload: ExecutionPayload,
+) -> u64 {
+    validate_execution_payload(env, guild_id, &proposal_type, &execution_payload);
+
+    open_proposal(
+        env,
+        guild_id,
+        proposer,
+        proposal_type,
+        title,
+        description,
+        execution_payload,
+        Vec::new(env),
+        BallotType::Binary,
+        ProposalFlavour::Standard,
+    )
+}
+
+/// Create a binary proposal voted by commit-reveal: members commit to a
+/// hash of their decision while voting is open and reveal it during the
+/// guild's reveal period, so nobody sees how others voted until voting
+/// closes.
+pub fn create_private_proposal(
+    env: &Env,
+    guild_id: u64,
+    proposer: Address,
+    proposal_type: ProposalType,
+    title: String,
+    description: String,
+    execution_payload: ExecutionPayload,
+) -> u64 {
+    validate_execution_payload(env, guild_id, &proposal_type, &execution_payload);
+
+    open_proposal(
+        env,
+        guild_id,
+        proposer,
+        proposal_type,
+        title,
+        description,
+        execution_payload,
+        Vec::new(env),
+        BallotType::Binary,
+        ProposalFlavour::CommitReveal,
+    )
+}
+
+/// Create a proposal choosing between 2 to `MAX_PROPOSAL_OPTIONS` labelled
+/// options, each carrying the action that runs if it wins. All actions must
+/// be of one proposal type, which sets the proposal's delegations and
+/// voting rules. Ballots are weighted linearly whatever the guild's voting
+/// mode.
+pub fn create_multi_option_proposal(
+    env: &Env,
+    guild_id: u64,
+    proposer: Address,
+    title: String,
+    description: String,
+    ballot_type: BallotType,
+    options: Vec<ProposalOption>,
+) -> u64 {
+    if ballot_type == BallotType::Binary {
+        panic!("multi-option proposals need a multi-option ballot type");
+    }
+    if options.len() < 2 || options.len() > MAX_PROPOSAL_OPTIONS {
+        panic!("invalid number of options");
+    }
+    let proposal_type = options.get(0).unwrap().payload.proposal_type();
+    for option in options.iter() {
+        if option.label.is_empty() || option.label.len() > 100 {
+            panic!("option label length invalid");
+        }
+        if option.payload.proposal_type() != proposal_type {
+            panic!("options must share one proposal type");
+        }
+        validate_execution_payload(env, guild_id, &proposal_type, &option.payload);
+    }
+
+    open_proposal(
+        env,
+        guild_id,
+        proposer,
+        proposal_type,
+        title,
+        description,
+        ExecutionPayload::GeneralDecision,
+        options,
+        ballot_type,
+        ProposalFlavour::Standard,
+    )
+}
+
+/// How a new proposal is decided
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ProposalFlavour {
+    /// Members vote in the open during the voting period
+    Standard,
+    /// Members commit sealed votes and reveal them after voting closes
+    CommitReveal,
+    /// Passes once its objection window ends unless members object
+    Optimistic,
+}
+
+/// Post a routine proposal that passes on its own once the guild's
+/// objection window ends. Members may object in the meantime; enough
+/// objection weight turns it into a full vote. Only owners and admins may
+/// post one.
+pub fn create_optimistic_proposal(
+    env: &Env,
+    guild_id: u64,
+    proposer: Address,
+    proposal_type: ProposalType,
+    title: String,
+    description: String,
+    execution_payload: ExecutionPayload,
+) -> u64 {
+    validate_execution_payload(env, guild_id, &proposal_type, &execution_payload);
+
+    let member = get_member(env, guild_id, &proposer)
+        .unwrap_or_else(|| panic!("proposer must be a guild member"));
+    if !matches!(member.role, Role::Owner | Role::Admin) {
+        panic!("only owners and admins can post optimistic proposals");
+    }
+
+    open_proposal(
+        env,
+        guild_id,
+        proposer,
+        proposal_type,
+        title,
+        description,
+        execution_payload,
+        Vec::new(env),
+        BallotType::Binary,
+        ProposalFlavour::Optimistic,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn open_proposal(
+    env: &Env,
+    guild_id: u64,
+    proposer: Address,
+    proposal_type: ProposalType,
+    title: String,
+    description: String,
+    execution_payload: ExecutionPayload,
+    options: Vec<ProposalOption>,
+    ballot_type: BallotType,
+    flavour: ProposalFlavour,
 ) -> u64 {
     proposer.require_auth();
 
@@ -71,7 +241,7 @@ pub fn create_proposal(
         panic!("insufficient reputation to create proposal");
     }
 
-    if title.len() == 0 || title.len() > 200 {
+    if title.is_empty() || title.len() > 200 {
         panic!("proposal title length invalid");
     }
 
@@ -79,11 +249,24 @@ pub fn create_proposal(
         panic!("proposal description too long");
     }
 
-    validate_execution_payload(env, guild_id, &proposal_type, &execution_payload);
+    let voting_mode = match ballot_type {
+        BallotType::Binary => {
+            get_type_voting_mode(env, guild_id, &proposal_type).unwrap_or(cfg.voting_mode.clone())
+        }
+        _ => VotingMode::Linear,
+    };
 
     let id = get_next_proposal_id(env);
     let now = env.ledger().timestamp();
     let voting_period_secs = (cfg.voting_period_days as u64) * 24 * 60 * 60;
+    let (voting_end, reveal_end) = match flavour {
+        ProposalFlavour::Standard => (now + voting_period_secs, None),
+        ProposalFlavour::CommitReveal => (
+            now + voting_period_secs,
+            Some(now + voting_period_secs + cfg.reveal_period_seconds),
+        ),
+        ProposalFlavour::Optimistic => (now + cfg.objection_window_seconds, None),
+    };
 
     let proposal = Proposal {
         id,
@@ -93,7 +276,7 @@ pub fn create_proposal(
         title,
         description,
         voting_start: now,
-        voting_end: now + voting_period_secs,
+        voting_end,
         status: ProposalStatus::Active,
         votes_for: 0,
         votes_against: 0,
@@ -101,9 +284,20 @@ pub fn create_proposal(
         execution_payload,
         passed_at: None,
         executed_at: None,
+        voting_mode,
+        ballot_type,
+        options,
+        option_weights: Vec::new(env),
+        winning_option: None,
+        executable_at: None,
+        reveal_end,
+        optimistic: flavour == ProposalFlavour::Optimistic,
+        objection_weight: 0,
     };
 
     store_proposal(env, &proposal);
+    take_vote_snapshot(env, &proposal);
+    lock_deposit(env, id, &proposer, &cfg.proposal_deposit);
 
     let event = ProposalCreatedEvent {
         proposal_id: id,
@@ -144,8 +338,47 @@ pub fn cancel_proposal(env: &Env, proposal_id: u64, canceller: Address) -> bool
         panic!("only proposer or guild owner can cancel");
     }
 
+    // a proposer withdrawing a live proposal forfeits it, so deposits cannot
+    // be dodged by cancelling before a losing vote ends
+    let self_cancel_active =
+        canceller == proposal.proposer && matches!(proposal.status, ProposalStatus::Active);
+    settle_deposit(env, proposal_id, !self_cancel_active);
+    mark_cancelled(env, &mut proposal);
+
+    true
+}
+
+/// Cancel a proposal as abusive. Only guild owners and admins may do this,
+/// and the proposer's deposit is forfeited to the guild treasury.
+pub fn cancel_proposal_for_abuse(env: &Env, proposal_id: u64, admin: Address) -> bool {
+    admin.require_auth();
+
+    let mut proposal =
+        load_proposal(env, proposal_id).unwrap_or_else(|| panic!("proposal not found"));
+
+    if !matches!(
+        proposal.status,
+        ProposalStatus::Active | ProposalStatus::Draft
+    ) {
+        panic!("only active or draft proposals can be cancelled");
+    }
+
+    let member = get_member(env, proposal.guild_id, &admin)
+        .unwrap_or_else(|| panic!("only guild admins can cancel for abuse"));
+    if !matches!(member.role, Role::Owner | Role::Admin) {
+        panic!("only guild admins can cancel for abuse");
+    }
+
+    settle_deposit(env, proposal_id, false);
+    mark_cancelled(env, &mut proposal);
+
+    true
+}
+
+fn mark_cancelled(env: &Env, proposal: &mut Proposal) {
+    let proposal_id = proposal.id;
     proposal.status = ProposalStatus::Cancelled;
-    store_proposal(env, &proposal);
+    store_proposal(env, proposal);
 
     let event = crate::governance::types::ProposalCancelledEvent { proposal_id };
     env.events().publish(
@@ -155,8 +388,6 @@ pub fn cancel_proposal(env: &Env, proposal_id: u64, canceller: Address) -> bool
         ),
         event,
     );
-
-    true
 }
 
 pub fn get_proposal(env: &Env, proposal_id: u64) -> Proposal {
@@ -174,6 +405,23 @@ pub fn get_active_proposals(env: &Env, guild_id: u64) -> Vec<Proposal> {
     active
 }
 
+/// Every proposal ever created in a guild, oldest first
+pub fn get_all_proposals(env: &Env, guild_id: u64) -> Vec<Proposal> {
+    get_guild_proposals(env, guild_id)
+}
+
+/// A guild's proposals currently in `status`, oldest first
+pub fn get_proposals_by_status(env: &Env, guild_id: u64, status: ProposalStatus) -> Vec<Proposal> {
+    let all = get_guild_proposals(env, guild_id);
+    let mut matching = Vec::new(env);
+    for p in all.iter() {
+        if p.status == status {
+            matching.push_back(p);
+        }
+    }
+    matching
+}
+
 pub fn update_governance_config(
     env: &Env,
     guild_id: u64,
@@ -195,6 +443,25 @@ pub fn update_governance_config(
     if config.approval_threshold == 0 || config.approval_threshold > 100 {
         panic!("invalid approval threshold");
     }
+    if config.execution_delay_seconds > MAX_EXECUTION_DELAY_SECONDS {
+        panic!("execution delay too long");
+    }
+    if config.reveal_period_seconds == 0 || config.reveal_period_seconds > MAX_REVEAL_PERIOD_SECONDS
+    {
+        panic!("invalid reveal period");
+    }
+    if config.objection_window_seconds == 0
+        || config.objection_window_seconds > MAX_OBJECTION_WINDOW_SECONDS
+    {
+        panic!("invalid objection window");
+    }
+    if config.objection_threshold_percentage == 0 || config.objection_threshold_percentage > 100 {
+        panic!("invalid objection threshold");
+    }
+    // the deposit rule only changes through a passed rule change proposal
+    if config.proposal_deposit != get_config(env, guild_id).proposal_deposit {
+        panic!("deposit rule is changed via its own proposal");
+    }
 
     set_config(env, guild_id, &config);
 
@@ -209,3 +476,38 @@ pub fn update_governance_config(
 
     true
 }
+
+/// Override the guild's voting mode for one proposal type. Only the guild
+/// owner may change it; proposals already created keep their mode.
+pub fn set_proposal_type_voting_mode(
+    env: &Env,
+    guild_id: u64,
+    caller: Address,
+    proposal_type: ProposalType,
+    voting_mode: VotingMode,
+) -> bool {
+    let guild =
+        guild_storage::get_guild(env, guild_id).unwrap_or_else(|| panic!("guild not found"));
+
+    if caller != guild.owner {
+        panic!("only guild owner can update governance config");
+    }
+    caller.require_auth();
+
+    set_type_voting_mode(env, guild_id, &proposal_type, &voting_mode);
+
+    let event = VotingModeUpdatedEvent {
+        guild_id,
+        proposal_type,
+        voting_mode,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_VOTING_MODE_UPDATED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
@@ -0,0 +1,162 @@
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::governance::storage::{
+    get_guardians, get_guild_proposals, get_proposal as load_proposal, get_veto, set_guardians,
+    store_proposal, store_veto,
+};
+use crate::governance::types::{
+    GovernanceConfig, GuardiansUpdatedEvent, Proposal, ProposalQueuedEvent, ProposalStatus,
+    ProposalVeto, ProposalVetoedEvent,
+};
+use crate::guild::storage as guild_storage;
+
+const EVENT_TOPIC_PROPOSAL_QUEUED: &str = "proposal_queued";
+const EVENT_TOPIC_PROPOSAL_VETOED: &str = "proposal_vetoed";
+const EVENT_TOPIC_GUARDIANS_UPDATED: &str = "guardians_updated";
+
+pub const MAX_GUARDIANS: u32 = 20;
+
+/// Record when a just-passed proposal becomes executable. With an execution
+/// delay configured the proposal is queued until then.
+pub(crate) fn queue_if_passed(env: &Env, proposal: &mut Proposal, cfg: &GovernanceConfig) {
+    if proposal.status != ProposalStatus::Passed {
+        return;
+    }
+
+    let executable_at = env.ledger().timestamp() + cfg.execution_delay_seconds;
+    proposal.executable_at = Some(executable_at);
+    if cfg.execution_delay_seconds == 0 {
+        return;
+    }
+
+    proposal.status = ProposalStatus::Queued;
+
+    let event = ProposalQueuedEvent {
+        proposal_id: proposal.id,
+        executable_at,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_PROPOSAL_QUEUED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+}
+
+/// Replace the guild's veto guardians. A guardian may be any address,
+/// including a multisig account contract that enforces its own threshold.
+pub fn set_governance_guardians(
+    env: &Env,
+    guild_id: u64,
+    caller: Address,
+    guardians: Vec<Address>,
+) -> bool {
+    let guild =
+        guild_storage::get_guild(env, guild_id).unwrap_or_else(|| panic!("guild not found"));
+
+    if caller != guild.owner {
+        panic!("only guild owner can update governance config");
+    }
+    caller.require_auth();
+
+    if guardians.len() > MAX_GUARDIANS {
+        panic!("too many guardians");
+    }
+    let mut unique: Vec<Address> = Vec::new(env);
+    for guardian in guardians.iter() {
+        if unique.contains(&guardian) {
+            panic!("duplicate guardian");
+        }
+        unique.push_back(guardian);
+    }
+
+    set_guardians(env, guild_id, &unique);
+
+    let event = GuardiansUpdatedEvent {
+        guild_id,
+        guardians: unique,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_GUARDIANS_UPDATED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+pub fn get_governance_guardians(env: &Env, guild_id: u64) -> Vec<Address> {
+    get_guardians(env, guild_id)
+}
+
+/// Veto a queued proposal before its execution delay runs out
+pub fn veto_proposal(env: &Env, proposal_id: u64, guardian: Address, reason: String) -> bool {
+    guardian.require_auth();
+
+    let mut proposal =
+        load_proposal(env, proposal_id).unwrap_or_else(|| panic!("proposal not found"));
+
+    if !get_guardians(env, proposal.guild_id).contains(&guardian) {
+        panic!("only guardians can veto proposals");
+    }
+    if proposal.status != ProposalStatus::Queued {
+        panic!("only queued proposals can be vetoed");
+    }
+
+    let now = env.ledger().timestamp();
+    if let Some(executable_at) = proposal.executable_at {
+        if now >= executable_at {
+            panic!("execution delay has ended");
+        }
+    }
+    if reason.is_empty() || reason.len() > 256 {
+        panic!("veto reason length invalid");
+    }
+
+    proposal.status = ProposalStatus::Vetoed;
+    store_proposal(env, &proposal);
+
+    store_veto(
+        env,
+        &ProposalVeto {
+            proposal_id,
+            guardian: guardian.clone(),
+            reason: reason.clone(),
+            vetoed_at: now,
+        },
+    );
+
+    let event = ProposalVetoedEvent {
+        proposal_id,
+        guardian,
+        reason,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_PROPOSAL_VETOED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+pub fn get_proposal_veto(env: &Env, proposal_id: u64) -> ProposalVeto {
+    get_veto(env, proposal_id).unwrap_or_else(|| panic!("veto not found"))
+}
+
+/// Proposals of a guild waiting out their execution delay; each carries its
+/// earliest execution time in `executable_at`
+pub fn get_queued_proposals(env: &Env, guild_id: u64) -> Vec<Proposal> {
+    let mut queued = Vec::new(env);
+    for p in get_guild_proposals(env, guild_id).iter() {
+        if p.status == ProposalStatus::Queued {
+            queued.push_back(p);
+        }
+    }
+    queued
+}
@@ -0,0 +1,133 @@
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::governance::storage::{get_vote_key, get_vote_nonce, set_vote_key, set_vote_nonce};
+use crate::governance::types::{
+    SignedVote, SignedVotesRelayedEvent, VoteDecision, VoteKeyRegisteredEvent,
+};
+use crate::governance::voting::cast_vote;
+
+const EVENT_TOPIC_VOTE_KEY_REGISTERED: &str = "vote_key_registered";
+const EVENT_TOPIC_SIGNED_VOTES_RELAYED: &str = "signed_votes_relayed";
+
+/// Prefix of every signed vote payload, so a vote signature can never be
+/// mistaken for a signature over anything else
+const SIGNED_VOTE_DOMAIN: &[u8] = b"stellar-guilds:vote:v1";
+
+/// XDR encoding of a Stellar account address up to its key: an address
+/// value holding an account whose public key is an ed25519 key
+const ACCOUNT_ADDRESS_XDR_PREFIX: [u8; 12] = [0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0];
+
+pub const MAX_SIGNED_VOTES_PER_BATCH: u32 = 50;
+
+/// The ed25519 public key of a Stellar account address; `None` for contracts
+fn account_key(env: &Env, address: &Address) -> Option<BytesN<32>> {
+    let xdr = address.clone().to_xdr(env);
+    if xdr.len() != 44 || xdr.slice(0..12) != Bytes::from_array(env, &ACCOUNT_ADDRESS_XDR_PREFIX) {
+        return None;
+    }
+    let mut key = [0u8; 32];
+    xdr.slice(12..44).copy_into_slice(&mut key);
+    Some(BytesN::from_array(env, &key))
+}
+
+/// The key that signs `voter`'s off-chain votes. Stellar accounts sign with
+/// their own account key, so they vote without any on-chain setup. Contract
+/// accounts have no key of their own and use the key they registered.
+fn vote_key_of(env: &Env, voter: &Address) -> BytesN<32> {
+    account_key(env, voter)
+        .or_else(|| get_vote_key(env, voter))
+        .unwrap_or_else(|| panic!("vote key not registered"))
+}
+
+/// Register the ed25519 key a contract account signs off-chain votes with.
+/// Registering again replaces the key. Stellar accounts always sign with their
+/// own account key and cannot register another.
+pub fn register_vote_key(env: &Env, voter: Address, public_key: BytesN<32>) -> bool {
+    voter.require_auth();
+
+    if account_key(env, &voter).is_some() {
+        panic!("accounts sign votes with their own key");
+    }
+
+    set_vote_key(env, &voter, &public_key);
+
+    let event = VoteKeyRegisteredEvent { voter, public_key };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_VOTE_KEY_REGISTERED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    true
+}
+
+/// The next nonce a voter must sign
+pub fn get_signed_vote_nonce(env: &Env, voter: &Address) -> u64 {
+    get_vote_nonce(env, voter)
+}
+
+/// The bytes a voter signs: the domain prefix, this contract's address, the
+/// voter's address, the proposal id, the decision and the voter's nonce
+pub fn signed_vote_payload(
+    env: &Env,
+    voter: &Address,
+    proposal_id: u64,
+    decision: &VoteDecision,
+    nonce: u64,
+) -> Bytes {
+    let mut payload = Bytes::from_slice(env, SIGNED_VOTE_DOMAIN);
+    payload.append(&env.current_contract_address().to_xdr(env));
+    payload.append(&voter.clone().to_xdr(env));
+    payload.extend_from_array(&proposal_id.to_be_bytes());
+    payload.push_back(decision.code());
+    payload.extend_from_array(&nonce.to_be_bytes());
+    payload
+}
+
+/// Record a batch of off-chain signed votes, each as if the voter had called
+/// `vote`. The whole batch fails if any signature, nonce or vote is invalid.
+pub fn submit_signed_votes(env: &Env, relayer: Address, votes: Vec<SignedVote>) -> u32 {
+    relayer.require_auth();
+
+    if votes.is_empty() || votes.len() > MAX_SIGNED_VOTES_PER_BATCH {
+        panic!("invalid signed vote batch size");
+    }
+
+    for signed in votes.iter() {
+        let public_key = vote_key_of(env, &signed.voter);
+
+        let nonce = get_vote_nonce(env, &signed.voter);
+        if signed.nonce != nonce {
+            panic!("invalid vote nonce");
+        }
+
+        let payload = signed_vote_payload(
+            env,
+            &signed.voter,
+            signed.proposal_id,
+            &signed.decision,
+            nonce,
+        );
+        env.crypto()
+            .ed25519_verify(&public_key, &payload, &signed.signature);
+
+        set_vote_nonce(env, &signed.voter, nonce + 1);
+        cast_vote(env, signed.proposal_id, signed.voter, signed.decision);
+    }
+
+    let event = SignedVotesRelayedEvent {
+        relayer,
+        count: votes.len(),
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, EVENT_TOPIC_SIGNED_VOTES_RELAYED),
+            Symbol::new(env, "v0"),
+        ),
+        event,
+    );
+
+    votes.len()
+}
@@ -1,6 +1,10 @@
-use soroban_sdk::{symbol_short, Address, Env, Map, Symbol, Vec};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Map, Symbol, Vec};
 
-use crate::governance::types::{GovernanceConfig, Proposal, Vote};
+use crate::governance::types::{
+    Delegation, DelegationCheckpoint, GovernanceConfig, OptionBallot, Proposal, ProposalDeposit,
+    ProposalType, ProposalVeto, Vote, VoteCommitment, VoteSnapshot, VotingMode, WeightCheckpoint,
+};
+use crate::guild::types::Role;
 
 const PROPOSALS_KEY: Symbol = symbol_short!("g_props");
 const PROPOSAL_COUNTER_KEY: Symbol = symbol_short!("g_pcnt");
@@ -8,9 +12,30 @@ const GUILD_PROPOSALS_KEY: Symbol = symbol_short!("g_pidx");
 
 const VOTES_KEY: Symbol = symbol_short!("g_votes");
 
+// one entry per proposal so large guilds don't grow a single shared map
+const VOTE_SNAPSHOT_KEY: Symbol = symbol_short!("g_vsnap");
+const OPTION_BALLOTS_KEY: Symbol = symbol_short!("g_ballots");
+const COMMITMENTS_KEY: Symbol = symbol_short!("g_commit");
+const OBJECTIONS_KEY: Symbol = symbol_short!("g_object");
+
 const DELEGATIONS_KEY: Symbol = symbol_short!("g_deleg");
 
+// checkpoints are stored one per entry and found by binary search, so vote
+// snapshots only need to remember a sequence number
+const CHECKPOINT_SEQ_KEY: Symbol = symbol_short!("g_cpseq");
+const WEIGHT_CHECKPOINTS_KEY: Symbol = symbol_short!("g_wcp");
+const WEIGHT_CHECKPOINT_COUNT_KEY: Symbol = symbol_short!("g_wcpn");
+const DELEGATION_CHECKPOINTS_KEY: Symbol = symbol_short!("g_dcp");
+const DELEGATION_CHECKPOINT_COUNT_KEY: Symbol = symbol_short!("g_dcpn");
+
+const VOTE_KEYS_KEY: Symbol = symbol_short!("g_vkey");
+const VOTE_NONCES_KEY: Symbol = symbol_short!("g_vnonce");
+
 const GOV_CONFIG_KEY: Symbol = symbol_short!("g_conf");
+const VOTING_MODES_KEY: Symbol = symbol_short!("g_vmode");
+const GUARDIANS_KEY: Symbol = symbol_short!("g_guard");
+const VETOES_KEY: Symbol = symbol_short!("g_veto");
+const DEPOSITS_KEY: Symbol = symbol_short!("g_depo");
 
 pub fn get_next_proposal_id(env: &Env) -> u64 {
     let current: u64 = env
@@ -123,42 +148,255 @@ pub fn get_all_votes(env: &Env, proposal_id: u64) -> Map<Address, Vote> {
     votes_map.get(proposal_id).unwrap_or_else(|| Map::new(env))
 }
 
-pub fn set_delegation(env: &Env, guild_id: u64, delegator: &Address, delegate: &Address) {
-    let mut delegations: Map<(u64, Address), Address> = env
+pub fn store_vote_snapshot(env: &Env, snapshot: &VoteSnapshot) {
+    env.storage()
+        .persistent()
+        .set(&(VOTE_SNAPSHOT_KEY, snapshot.proposal_id), snapshot);
+}
+
+pub fn get_vote_snapshot(env: &Env, proposal_id: u64) -> Option<VoteSnapshot> {
+    env.storage()
+        .persistent()
+        .get(&(VOTE_SNAPSHOT_KEY, proposal_id))
+}
+
+pub fn store_option_ballot(env: &Env, ballot: &OptionBallot) {
+    let key = (OPTION_BALLOTS_KEY, ballot.proposal_id);
+    let mut ballots: Map<Address, OptionBallot> = env
         .storage()
         .persistent()
-        .get(&DELEGATIONS_KEY)
+        .get(&key)
         .unwrap_or_else(|| Map::new(env));
 
-    delegations.set((guild_id, delegator.clone()), delegate.clone());
+    ballots.set(ballot.voter.clone(), ballot.clone());
+    env.storage().persistent().set(&key, &ballots);
+}
+
+pub fn get_option_ballots(env: &Env, proposal_id: u64) -> Map<Address, OptionBallot> {
     env.storage()
         .persistent()
-        .set(&DELEGATIONS_KEY, &delegations);
+        .get(&(OPTION_BALLOTS_KEY, proposal_id))
+        .unwrap_or_else(|| Map::new(env))
 }
 
-pub fn remove_delegation(env: &Env, guild_id: u64, delegator: &Address) {
-    let mut delegations: Map<(u64, Address), Address> = env
+pub fn store_vote_commitment(env: &Env, proposal_id: u64, commitment: &VoteCommitment) {
+    let key = (COMMITMENTS_KEY, proposal_id);
+    let mut commitments: Map<Address, VoteCommitment> = env
         .storage()
         .persistent()
-        .get(&DELEGATIONS_KEY)
+        .get(&key)
         .unwrap_or_else(|| Map::new(env));
 
-    if delegations.contains_key((guild_id, delegator.clone())) {
-        delegations.remove((guild_id, delegator.clone()));
-        env.storage()
-            .persistent()
-            .set(&DELEGATIONS_KEY, &delegations);
+    commitments.set(commitment.voter.clone(), commitment.clone());
+    env.storage().persistent().set(&key, &commitments);
+}
+
+pub fn get_vote_commitments(env: &Env, proposal_id: u64) -> Map<Address, VoteCommitment> {
+    env.storage()
+        .persistent()
+        .get(&(COMMITMENTS_KEY, proposal_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Members objecting to an optimistic proposal, with when they objected
+pub fn get_objections(env: &Env, proposal_id: u64) -> Map<Address, u64> {
+    env.storage()
+        .persistent()
+        .get(&(OBJECTIONS_KEY, proposal_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn set_objections(env: &Env, proposal_id: u64, objections: &Map<Address, u64>) {
+    env.storage()
+        .persistent()
+        .set(&(OBJECTIONS_KEY, proposal_id), objections);
+}
+
+/// Every delegator's delegations within a guild, one per scope
+pub fn get_guild_delegations(env: &Env, guild_id: u64) -> Map<Address, Vec<Delegation>> {
+    env.storage()
+        .persistent()
+        .get(&(DELEGATIONS_KEY, guild_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn get_delegations(env: &Env, guild_id: u64, delegator: &Address) -> Vec<Delegation> {
+    get_guild_delegations(env, guild_id)
+        .get(delegator.clone())
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_delegations(env: &Env, guild_id: u64, delegator: &Address, list: &Vec<Delegation>) {
+    let mut delegations = get_guild_delegations(env, guild_id);
+
+    if list.is_empty() {
+        delegations.remove(delegator.clone());
+    } else {
+        delegations.set(delegator.clone(), list.clone());
     }
+    env.storage()
+        .persistent()
+        .set(&(DELEGATIONS_KEY, guild_id), &delegations);
+
+    push_delegation_checkpoint(env, guild_id, delegator, list);
 }
 
-pub fn get_delegate(env: &Env, guild_id: u64, delegator: &Address) -> Option<Address> {
-    let delegations: Map<(u64, Address), Address> = env
-        .storage()
+/// Sequence number of the latest checkpoint of any kind
+pub fn get_checkpoint_seq(env: &Env) -> u64 {
+    env.storage()
         .persistent()
-        .get(&DELEGATIONS_KEY)
-        .unwrap_or_else(|| Map::new(env));
+        .get(&CHECKPOINT_SEQ_KEY)
+        .unwrap_or(0u64)
+}
 
-    delegations.get((guild_id, delegator.clone()))
+fn next_checkpoint_seq(env: &Env) -> u64 {
+    let next = get_checkpoint_seq(env) + 1;
+    env.storage().persistent().set(&CHECKPOINT_SEQ_KEY, &next);
+    next
+}
+
+/// Index of the last of `count` checkpoints recorded at or before `seq`
+fn latest_at(count: u32, seq: u64, seq_of: impl Fn(u32) -> u64) -> Option<u32> {
+    let (mut low, mut high) = (0u32, count);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if seq_of(mid) <= seq {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low.checked_sub(1)
+}
+
+pub fn push_weight_checkpoint(
+    env: &Env,
+    guild_id: u64,
+    address: &Address,
+    member: bool,
+    role: Role,
+    score: u64,
+    score_at: u64,
+) {
+    let count = get_weight_checkpoint_count(env, guild_id, address);
+    let checkpoint = WeightCheckpoint {
+        seq: next_checkpoint_seq(env),
+        member,
+        role,
+        score,
+        score_at,
+    };
+    let storage = env.storage().persistent();
+    storage.set(
+        &(WEIGHT_CHECKPOINTS_KEY, guild_id, address.clone(), count),
+        &checkpoint,
+    );
+    storage.set(
+        &(WEIGHT_CHECKPOINT_COUNT_KEY, guild_id, address.clone()),
+        &(count + 1),
+    );
+}
+
+pub fn get_weight_checkpoint_count(env: &Env, guild_id: u64, address: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(WEIGHT_CHECKPOINT_COUNT_KEY, guild_id, address.clone()))
+        .unwrap_or(0u32)
+}
+
+fn get_weight_checkpoint(
+    env: &Env,
+    guild_id: u64,
+    address: &Address,
+    index: u32,
+) -> WeightCheckpoint {
+    env.storage()
+        .persistent()
+        .get(&(WEIGHT_CHECKPOINTS_KEY, guild_id, address.clone(), index))
+        .unwrap_or_else(|| panic!("checkpoint not found"))
+}
+
+/// The member's last weight checkpoint at or before `seq`
+pub fn get_weight_checkpoint_at(
+    env: &Env,
+    guild_id: u64,
+    address: &Address,
+    seq: u64,
+) -> Option<WeightCheckpoint> {
+    let count = get_weight_checkpoint_count(env, guild_id, address);
+    let index = latest_at(count, seq, |i| {
+        get_weight_checkpoint(env, guild_id, address, i).seq
+    })?;
+    Some(get_weight_checkpoint(env, guild_id, address, index))
+}
+
+/// Checkpoint count of everyone who has ever delegated in the guild
+pub fn get_delegation_checkpoint_counts(env: &Env, guild_id: u64) -> Map<Address, u32> {
+    env.storage()
+        .persistent()
+        .get(&(DELEGATION_CHECKPOINT_COUNT_KEY, guild_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+fn push_delegation_checkpoint(
+    env: &Env,
+    guild_id: u64,
+    delegator: &Address,
+    list: &Vec<Delegation>,
+) {
+    let mut counts = get_delegation_checkpoint_counts(env, guild_id);
+    let count = counts.get(delegator.clone()).unwrap_or(0);
+    let checkpoint = DelegationCheckpoint {
+        seq: next_checkpoint_seq(env),
+        delegations: list.clone(),
+    };
+    counts.set(delegator.clone(), count + 1);
+
+    let storage = env.storage().persistent();
+    storage.set(
+        &(
+            DELEGATION_CHECKPOINTS_KEY,
+            guild_id,
+            delegator.clone(),
+            count,
+        ),
+        &checkpoint,
+    );
+    storage.set(&(DELEGATION_CHECKPOINT_COUNT_KEY, guild_id), &counts);
+}
+
+fn get_delegation_checkpoint(
+    env: &Env,
+    guild_id: u64,
+    delegator: &Address,
+    index: u32,
+) -> DelegationCheckpoint {
+    env.storage()
+        .persistent()
+        .get(&(
+            DELEGATION_CHECKPOINTS_KEY,
+            guild_id,
+            delegator.clone(),
+            index,
+        ))
+        .unwrap_or_else(|| panic!("checkpoint not found"))
+}
+
+/// The delegator's delegations as of the last of their `count` checkpoints
+/// at or before `seq`
+pub fn get_delegations_at(
+    env: &Env,
+    guild_id: u64,
+    delegator: &Address,
+    count: u32,
+    seq: u64,
+) -> Vec<Delegation> {
+    match latest_at(count, seq, |i| {
+        get_delegation_checkpoint(env, guild_id, delegator, i).seq
+    }) {
+        Some(index) => get_delegation_checkpoint(env, guild_id, delegator, index).delegations,
+        None => Vec::new(env),
+    }
 }
 
 pub fn get_config(env: &Env, guild_id: u64) -> GovernanceConfig {
@@ -183,3 +421,116 @@ pub fn set_config(env: &Env, guild_id: u64, config: &GovernanceConfig) {
     configs.set(guild_id, config.clone());
     env.storage().persistent().set(&GOV_CONFIG_KEY, &configs);
 }
+
+pub fn get_type_voting_mode(
+    env: &Env,
+    guild_id: u64,
+    proposal_type: &ProposalType,
+) -> Option<VotingMode> {
+    let modes: Map<(u64, ProposalType), VotingMode> = env
+        .storage()
+        .persistent()
+        .get(&VOTING_MODES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    modes.get((guild_id, proposal_type.clone()))
+}
+
+pub fn set_type_voting_mode(
+    env: &Env,
+    guild_id: u64,
+    proposal_type: &ProposalType,
+    mode: &VotingMode,
+) {
+    let mut modes: Map<(u64, ProposalType), VotingMode> = env
+        .storage()
+        .persistent()
+        .get(&VOTING_MODES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    modes.set((guild_id, proposal_type.clone()), mode.clone());
+    env.storage().persistent().set(&VOTING_MODES_KEY, &modes);
+}
+
+pub fn get_guardians(env: &Env, guild_id: u64) -> Vec<Address> {
+    let guardians: Map<u64, Vec<Address>> = env
+        .storage()
+        .persistent()
+        .get(&GUARDIANS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    guardians.get(guild_id).unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_guardians(env: &Env, guild_id: u64, list: &Vec<Address>) {
+    let mut guardians: Map<u64, Vec<Address>> = env
+        .storage()
+        .persistent()
+        .get(&GUARDIANS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    guardians.set(guild_id, list.clone());
+    env.storage().persistent().set(&GUARDIANS_KEY, &guardians);
+}
+
+pub fn store_veto(env: &Env, veto: &ProposalVeto) {
+    env.storage()
+        .persistent()
+        .set(&(VETOES_KEY, veto.proposal_id), veto);
+}
+
+pub fn get_veto(env: &Env, proposal_id: u64) -> Option<ProposalVeto> {
+    env.storage().persistent().get(&(VETOES_KEY, proposal_id))
+}
+
+pub fn store_deposit(env: &Env, deposit: &ProposalDeposit) {
+    env.storage()
+        .persistent()
+        .set(&(DEPOSITS_KEY, deposit.proposal_id), deposit);
+}
+
+pub fn get_deposit(env: &Env, proposal_id: u64) -> Option<ProposalDeposit> {
+    env.storage().persistent().get(&(DEPOSITS_KEY, proposal_id))
+}
+
+pub fn get_vote_key(env: &Env, voter: &Address) -> Option<BytesN<32>> {
+    let keys: Map<Address, BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&VOTE_KEYS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    keys.get(voter.clone())
+}
+
+pub fn set_vote_key(env: &Env, voter: &Address, public_key: &BytesN<32>) {
+    let mut keys: Map<Address, BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&VOTE_KEYS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    keys.set(voter.clone(), public_key.clone());
+    env.storage().persistent().set(&VOTE_KEYS_KEY, &keys);
+}
+
+pub fn get_vote_nonce(env: &Env, voter: &Address) -> u64 {
+    let nonces: Map<Address, u64> = env
+        .storage()
+        .persistent()
+        .get(&VOTE_NONCES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    nonces.get(voter.clone()).unwrap_or(0)
+}
+
+pub fn set_vote_nonce(env: &Env, voter: &Address, nonce: u64) {
+    let mut nonces: Map<Address, u64> = env
+        .storage()
+        .persistent()
+        .get(&VOTE_NONCES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    nonces.set(voter.clone(), nonce);
+    env.storage().persistent().set(&VOTE_NONCES_KEY, &nonces);
+}
@@ -1,11 +1,20 @@
 #[cfg(test)]
 mod tests {
-    use crate::governance::types::{ProposalStatus, ProposalType, VoteDecision};
+    use crate::governance::checkpoints::weight_at;
+    use crate::governance::types::{
+        BallotType, DelegationScope, DelegationSplit, DepositRule, DepositStatus, ExecutionPayload,
+        GovernanceConfig, ProposalOption, ProposalStatus, ProposalType, QuorumBasis, SignedVote,
+        VoteDecision, VoteSnapshot, VotingMode,
+    };
+    use crate::governance::voting::effective_voting_power;
     use crate::guild::types::Role;
+    use crate::ledger::SubAccount;
     use crate::StellarGuildsContract;
     use crate::StellarGuildsContractClient;
+    use ed25519_dalek::{Signer, SigningKey};
     use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
-    use soroban_sdk::{Address, Env, String};
+    use soroban_sdk::xdr::FromXdr;
+    use soroban_sdk::{token, vec, Address, Bytes, BytesN, Env, Map, String, Vec};
 
     fn setup_env() -> Env {
         let env = Env::default();
@@ -26,10 +35,27 @@ mod tests {
         });
     }
 
+    /// Voting power of `member` on a snapshot if no delegator votes directly
+    fn pooled_power(
+        env: &Env,
+        contract_id: &Address,
+        snapshot: &VoteSnapshot,
+        member: &Address,
+    ) -> i128 {
+        env.as_contract(contract_id, || {
+            effective_voting_power(env, snapshot, &Map::new(env))
+                .get(member.clone())
+                .unwrap_or_else(|| weight_at(env, snapshot, member).unwrap())
+        })
+    }
+
     fn register_and_init_contract(env: &Env) -> Address {
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+        client
+            .mock_all_auths()
+            .initialize(&Address::generate(env), &native.address());
         contract_id
     }
 
@@ -57,6 +83,17 @@ mod tests {
         (guild_id, admin, member, contributor)
     }
 
+    fn labelled_options(env: &Env, labels: &[&str]) -> Vec<ProposalOption> {
+        let mut options = Vec::new(env);
+        for label in labels {
+            options.push_back(ProposalOption {
+                label: String::from_str(env, label),
+                payload: ExecutionPayload::GeneralDecision,
+            });
+        }
+        options
+    }
+
     #[test]
     fn test_create_proposal_basic() {
         let env = setup_env();
@@ -148,6 +185,10 @@ mod tests {
         let (guild_id, admin, member, contributor) =
             setup_guild_with_members(&env, &client, &owner);
 
+        // Delegations are frozen into the vote snapshot at proposal creation
+        client.delegate_vote(&guild_id, &member, &admin);
+        client.delegate_vote(&guild_id, &contributor, &member);
+
         let proposal_id = client.create_proposal(
             &guild_id,
             &owner,
@@ -156,9 +197,6 @@ mod tests {
             &String::from_str(&env, "Delegation"),
         );
 
-        client.delegate_vote(&guild_id, &member, &admin);
-        client.delegate_vote(&guild_id, &contributor, &member);
-
         client.vote(&proposal_id, &admin, &VoteDecision::For);
 
         let proposal = client.get_proposal(&proposal_id);
@@ -211,4 +249,1547 @@ mod tests {
         // Should panic since it didn't pass quorum
         client.execute_proposal(&proposal_id, &owner);
     }
+
+    #[test]
+    fn test_vote_snapshot_ignores_changes_after_creation() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let proposal_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Snapshot"),
+            &String::from_str(&env, "Snapshot"),
+        );
+
+        let snapshot = client.get_proposal_vote_snapshot(&proposal_id);
+        assert_eq!(snapshot.total_weight, 18);
+        assert_eq!(snapshot.member_count, 4);
+        assert_eq!(pooled_power(&env, &contract_id, &snapshot, &contributor), 1);
+
+        // Promotions, delegations, new members and departures after creation
+        // do not count
+        client.update_role(&guild_id, &contributor, &Role::Admin, &owner);
+        client.delegate_vote(&guild_id, &member, &contributor);
+        let newcomer = Address::generate(&env);
+        client.add_member(&guild_id, &newcomer, &Role::Admin, &owner);
+        client.remove_member(&guild_id, &admin, &owner);
+
+        assert!(client
+            .try_vote(&proposal_id, &newcomer, &VoteDecision::For)
+            .is_err());
+        client.vote(&proposal_id, &contributor, &VoteDecision::For);
+        client.vote(&proposal_id, &admin, &VoteDecision::Against);
+
+        let proposal = client.get_proposal(&proposal_id);
+        set_ledger_timestamp(&env, proposal.voting_end + 1);
+
+        // 1 for, 5 against out of 18 snapshot weight
+        let status = client.finalize_proposal(&proposal_id);
+        assert_eq!(status, ProposalStatus::Rejected);
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.votes_for, 1);
+        assert_eq!(proposal.votes_against, 5);
+    }
+
+    #[test]
+    fn test_quadratic_voting_spends_voice_credits() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let linear_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Linear"),
+            &String::from_str(&env, "Linear"),
+        );
+        assert!(client
+            .try_vote_quadratic(&linear_id, &owner, &VoteDecision::For, &1u32)
+            .is_err());
+
+        client.set_proposal_type_voting_mode(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &VotingMode::Quadratic,
+        );
+        let proposal_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Quadratic"),
+            &String::from_str(&env, "Quadratic"),
+        );
+        assert_eq!(
+            client.get_proposal(&proposal_id).voting_mode,
+            VotingMode::Quadratic
+        );
+
+        // Credits equal weight: owner 10, admin 5, member 2, contributor 1
+        client.vote_quadratic(&proposal_id, &owner, &VoteDecision::For, &3u32);
+        client.vote(&proposal_id, &admin, &VoteDecision::Against); // all credits: 2 votes
+        client.vote_quadratic(&proposal_id, &member, &VoteDecision::Against, &1u32);
+        assert!(client
+            .try_vote_quadratic(&proposal_id, &contributor, &VoteDecision::For, &2u32)
+            .is_err());
+
+        let proposal = client.get_proposal(&proposal_id);
+        set_ledger_timestamp(&env, proposal.voting_end + 1);
+
+        let status = client.finalize_proposal(&proposal_id);
+        assert_eq!(status, ProposalStatus::Rejected);
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.votes_for, 3);
+        assert_eq!(proposal.votes_against, 3);
+    }
+
+    #[test]
+    fn test_quadratic_delegate_loses_credits_when_delegator_votes() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, _member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+        client.delegate_vote(&guild_id, &owner, &admin);
+        client.set_proposal_type_voting_mode(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &VotingMode::Quadratic,
+        );
+        let proposal_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Quadratic"),
+            &String::from_str(&env, "Quadratic"),
+        );
+
+        // The admin spends 9 of the 15 credits they hold with the owner's weight
+        client.vote_quadratic(&proposal_id, &admin, &VoteDecision::For, &3u32);
+        // The owner then votes and takes back their 10 credits
+        client.vote_quadratic(&proposal_id, &owner, &VoteDecision::Against, &3u32);
+
+        let proposal = client.get_proposal(&proposal_id);
+        set_ledger_timestamp(&env, proposal.voting_end + 1);
+
+        let status = client.finalize_proposal(&proposal_id);
+        assert_eq!(status, ProposalStatus::Rejected);
+        let proposal = client.get_proposal(&proposal_id);
+        // 5 credits left cover 2 votes
+        assert_eq!(proposal.votes_for, 2);
+        assert_eq!(proposal.votes_against, 3);
+    }
+
+    #[test]
+    fn test_conviction_voting_rewards_early_votes() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, _member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let mut config = GovernanceConfig::default();
+        config.voting_mode = VotingMode::Conviction;
+        client.update_governance_config(&guild_id, &owner, &config);
+
+        let proposal_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Conviction"),
+            &String::from_str(&env, "Conviction"),
+        );
+        let voting_end = client.get_proposal(&proposal_id).voting_end;
+
+        // Held for the whole 7-day period: 5 * 8 (capped)
+        client.vote(&proposal_id, &admin, &VoteDecision::For);
+
+        // Re-voting restarts conviction: held under a day, 10 * 1
+        client.vote(&proposal_id, &owner, &VoteDecision::Against);
+        set_ledger_timestamp(&env, voting_end - 3600);
+        client.vote(&proposal_id, &owner, &VoteDecision::Against);
+
+        set_ledger_timestamp(&env, voting_end + 1);
+        let status = client.finalize_proposal(&proposal_id);
+        assert_eq!(status, ProposalStatus::Passed);
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.votes_for, 40);
+        assert_eq!(proposal.votes_against, 10);
+    }
+
+    #[test]
+    fn test_single_choice_proposal_runs_winning_option() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let one_option = labelled_options(&env, &["Only"]);
+        assert!(client
+            .try_create_multi_option_proposal(
+                &guild_id,
+                &owner,
+                &String::from_str(&env, "Pick one"),
+                &String::from_str(&env, "Pick one"),
+                &BallotType::SingleChoice,
+                &one_option,
+            )
+            .is_err());
+
+        // options must all be of one proposal type
+        let mut mixed = labelled_options(&env, &["Signal"]);
+        mixed.push_back(ProposalOption {
+            label: String::from_str(&env, "Change rules"),
+            payload: ExecutionPayload::RuleChange,
+        });
+        assert!(client
+            .try_create_multi_option_proposal(
+                &guild_id,
+                &owner,
+                &String::from_str(&env, "Mixed"),
+                &String::from_str(&env, "Mixed"),
+                &BallotType::SingleChoice,
+                &mixed,
+            )
+            .is_err());
+
+        let mut rule_options = Vec::new(&env);
+        for label in ["Keep", "Change"] {
+            rule_options.push_back(ProposalOption {
+                label: String::from_str(&env, label),
+                payload: ExecutionPayload::RuleChange,
+            });
+        }
+        let rule_id = client.create_multi_option_proposal(
+            &guild_id,
+            &owner,
+            &String::from_str(&env, "Rules"),
+            &String::from_str(&env, "Rules"),
+            &BallotType::SingleChoice,
+            &rule_options,
+        );
+        assert_eq!(
+            client.get_proposal(&rule_id).proposal_type,
+            ProposalType::RuleChange
+        );
+
+        let proposal_id = client.create_multi_option_proposal(
+            &guild_id,
+            &owner,
+            &String::from_str(&env, "Venue"),
+            &String::from_str(&env, "Where to meet"),
+            &BallotType::SingleChoice,
+            &labelled_options(&env, &["Online", "Berlin", "Lisbon"]),
+        );
+
+        assert!(client
+            .try_vote(&proposal_id, &owner, &VoteDecision::For)
+            .is_err());
+        assert!(client
+            .try_vote_options(&proposal_id, &owner, &vec![&env, 0u32, 1u32])
+            .is_err());
+        assert!(client
+            .try_vote_options(&proposal_id, &owner, &vec![&env, 3u32])
+            .is_err());
+
+        client.vote_options(&proposal_id, &owner, &vec![&env, 0u32]);
+        client.vote_options(&proposal_id, &admin, &vec![&env, 1u32]);
+        client.vote_options(&proposal_id, &member, &vec![&env, 1u32]);
+        client.vote_options(&proposal_id, &contributor, &vec![&env, 2u32]);
+
+        let voting_end = client.get_proposal(&proposal_id).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+
+        let status = client.finalize_proposal(&proposal_id);
+        assert_eq!(status, ProposalStatus::Passed);
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.option_weights, vec![&env, 10i128, 7i128, 1i128]);
+        assert_eq!(proposal.winning_option, Some(0));
+
+        assert!(client.execute_proposal(&proposal_id, &owner));
+        assert_eq!(
+            client.get_proposal(&proposal_id).status,
+            ProposalStatus::Executed
+        );
+    }
+
+    #[test]
+    fn test_approval_proposal_counts_every_approved_option() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let proposal_id = client.create_multi_option_proposal(
+            &guild_id,
+            &owner,
+            &String::from_str(&env, "Tooling"),
+            &String::from_str(&env, "Which tools to fund"),
+            &BallotType::Approval,
+            &labelled_options(&env, &["Indexer", "Wallet", "Explorer"]),
+        );
+
+        assert!(client
+            .try_vote_options(&proposal_id, &owner, &vec![&env, 1u32, 1u32])
+            .is_err());
+
+        client.vote_options(&proposal_id, &owner, &vec![&env, 1u32]);
+        client.vote_options(&proposal_id, &admin, &vec![&env, 0u32, 1u32]);
+        client.vote_options(&proposal_id, &member, &vec![&env, 0u32, 2u32]);
+        client.vote_options(&proposal_id, &contributor, &vec![&env, 0u32]);
+        assert!(client.get_proposal_votes(&proposal_id).is_empty());
+        let ballots = client.get_proposal_option_ballots(&proposal_id);
+        assert_eq!(ballots.len(), 4);
+        let member_ballot = ballots.iter().find(|b| b.voter == member).unwrap();
+        assert_eq!(member_ballot.choices, vec![&env, 0u32, 2u32]);
+
+        let voting_end = client.get_proposal(&proposal_id).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+
+        let status = client.finalize_proposal(&proposal_id);
+        assert_eq!(status, ProposalStatus::Passed);
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.option_weights, vec![&env, 8i128, 15i128, 2i128]);
+        assert_eq!(proposal.winning_option, Some(1));
+    }
+
+    #[test]
+    fn test_ranked_choice_proposal_uses_instant_runoff() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+        let admin2 = Address::generate(&env);
+        let member2 = Address::generate(&env);
+        client.add_member(&guild_id, &admin2, &Role::Admin, &owner);
+        client.add_member(&guild_id, &member2, &Role::Member, &owner);
+
+        let proposal_id = client.create_multi_option_proposal(
+            &guild_id,
+            &owner,
+            &String::from_str(&env, "Logo"),
+            &String::from_str(&env, "Pick the new logo"),
+            &BallotType::RankedChoice,
+            &labelled_options(&env, &["Circle", "Square", "Star"]),
+        );
+
+        // First round: Circle 10, Square 8, Star 7 - no majority of 25
+        client.vote_options(&proposal_id, &owner, &vec![&env, 0u32]);
+        client.vote_options(&proposal_id, &admin, &vec![&env, 1u32, 2u32]);
+        client.vote_options(&proposal_id, &member, &vec![&env, 1u32, 2u32]);
+        client.vote_options(&proposal_id, &contributor, &vec![&env, 1u32, 0u32]);
+        client.vote_options(&proposal_id, &admin2, &vec![&env, 2u32, 1u32]);
+        client.vote_options(&proposal_id, &member2, &vec![&env, 2u32, 1u32]);
+
+        let voting_end = client.get_proposal(&proposal_id).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+
+        // Star is eliminated and its ballots move to Square
+        let status = client.finalize_proposal(&proposal_id);
+        assert_eq!(status, ProposalStatus::Passed);
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.option_weights, vec![&env, 10i128, 15i128, 0i128]);
+        assert_eq!(proposal.winning_option, Some(1));
+    }
+
+    fn create_passed_queued_proposal(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        guild_id: u64,
+        owner: &Address,
+    ) -> u64 {
+        let proposal_id = client.create_proposal(
+            &guild_id,
+            owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(env, "Queued"),
+            &String::from_str(env, "Queued"),
+        );
+        client.vote(&proposal_id, owner, &VoteDecision::For);
+
+        let voting_end = client.get_proposal(&proposal_id).voting_end;
+        set_ledger_timestamp(env, voting_end + 1);
+        assert_eq!(
+            client.finalize_proposal(&proposal_id),
+            ProposalStatus::Queued
+        );
+        proposal_id
+    }
+
+    #[test]
+    fn test_guardian_vetoes_queued_proposal() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+        let guardian = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, _member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let mut config = GovernanceConfig::default();
+        config.execution_delay_seconds = 2 * 86400;
+        client.update_governance_config(&guild_id, &owner, &config);
+        client.set_governance_guardians(&guild_id, &owner, &vec![&env, guardian.clone()]);
+
+        let proposal_id = create_passed_queued_proposal(&env, &client, guild_id, &owner);
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(
+            proposal.executable_at,
+            Some(env.ledger().timestamp() + 2 * 86400)
+        );
+
+        let queued = client.get_queued_proposals(&guild_id);
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued.get(0).unwrap().id, proposal_id);
+
+        assert!(client.try_execute_proposal(&proposal_id, &owner).is_err());
+
+        let reason = String::from_str(&env, "Conflicts with the treasury policy");
+        assert!(client
+            .try_veto_proposal(&proposal_id, &admin, &reason)
+            .is_err());
+        assert!(client.veto_proposal(&proposal_id, &guardian, &reason));
+
+        assert_eq!(
+            client.get_proposal(&proposal_id).status,
+            ProposalStatus::Vetoed
+        );
+        let veto = client.get_proposal_veto(&proposal_id);
+        assert_eq!(veto.guardian, guardian);
+        assert_eq!(veto.reason, reason);
+        assert_eq!(client.get_queued_proposals(&guild_id).len(), 0);
+
+        set_ledger_timestamp(&env, proposal.executable_at.unwrap() + 1);
+        assert!(client.try_execute_proposal(&proposal_id, &owner).is_err());
+    }
+
+    #[test]
+    fn test_queued_proposal_executes_after_delay() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+        let guardian = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, _admin, _member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let mut config = GovernanceConfig::default();
+        config.execution_delay_seconds = 86400;
+        client.update_governance_config(&guild_id, &owner, &config);
+        client.set_governance_guardians(&guild_id, &owner, &vec![&env, guardian.clone()]);
+
+        let proposal_id = create_passed_queued_proposal(&env, &client, guild_id, &owner);
+        let executable_at = client.get_proposal(&proposal_id).executable_at.unwrap();
+
+        set_ledger_timestamp(&env, executable_at);
+        assert!(client
+            .try_veto_proposal(&proposal_id, &guardian, &String::from_str(&env, "Too late"))
+            .is_err());
+
+        assert!(client.execute_proposal(&proposal_id, &owner));
+        assert_eq!(
+            client.get_proposal(&proposal_id).status,
+            ProposalStatus::Executed
+        );
+    }
+
+    /// Pass a rule change requiring `amount` as the proposal deposit, paid to a
+    /// new treasury. Leaves the ledger just after the rule change's voting
+    /// period.
+    fn setup_deposit_rule(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        guild_id: u64,
+        owner: &Address,
+        admin: &Address,
+        amount: i128,
+    ) -> u64 {
+        let treasury_id = client.initialize_treasury(&guild_id, &vec![env, owner.clone()], &1u32);
+
+        let rule = DepositRule {
+            amount,
+            token: None,
+            treasury_id,
+        };
+        let proposal_id = client.propose_deposit_rule_change(
+            &guild_id,
+            owner,
+            &rule,
+            &String::from_str(env, "Deposits"),
+            &String::from_str(env, "Require a deposit"),
+        );
+        client.vote(&proposal_id, owner, &VoteDecision::For);
+        client.vote(&proposal_id, admin, &VoteDecision::For);
+
+        let voting_end = client.get_proposal(&proposal_id).voting_end;
+        set_ledger_timestamp(env, voting_end + 1);
+        client.execute_proposal(&proposal_id, owner);
+        treasury_id
+    }
+
+    fn mint_native(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        to: &Address,
+        amount: i128,
+    ) {
+        let native = client.get_native_token();
+        token::StellarAssetClient::new(env, &native).mint(to, &amount);
+    }
+
+    #[test]
+    fn test_proposal_deposit_refunded_on_quorum_and_forfeited_without() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let native = token::TokenClient::new(&env, &client.get_native_token());
+
+        let (guild_id, admin, _member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+        let treasury_id = setup_deposit_rule(&env, &client, guild_id, &owner, &admin, 100);
+
+        mint_native(&env, &client, &owner, 100);
+        mint_native(&env, &client, &contributor, 100);
+
+        let backed_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Backed"),
+            &String::from_str(&env, "Backed"),
+        );
+        let ignored_id = client.create_proposal(
+            &guild_id,
+            &contributor,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Ignored"),
+            &String::from_str(&env, "Ignored"),
+        );
+        assert_eq!(native.balance(&owner), 0);
+        assert_eq!(
+            client.get_sub_account_balance(&SubAccount::ProposalDeposit(backed_id), &None),
+            100
+        );
+
+        // Against votes still count towards quorum
+        client.vote(&backed_id, &owner, &VoteDecision::Against);
+
+        let voting_end = client.get_proposal(&backed_id).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+
+        assert_eq!(
+            client.finalize_proposal(&backed_id),
+            ProposalStatus::Rejected
+        );
+        assert_eq!(
+            client.get_proposal_deposit(&backed_id).status,
+            DepositStatus::Refunded
+        );
+        assert_eq!(native.balance(&owner), 100);
+
+        assert_eq!(
+            client.finalize_proposal(&ignored_id),
+            ProposalStatus::Rejected
+        );
+        assert_eq!(
+            client.get_proposal_deposit(&ignored_id).status,
+            DepositStatus::Forfeited
+        );
+        assert_eq!(native.balance(&contributor), 0);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 100);
+    }
+
+    #[test]
+    fn test_abuse_cancellation_forfeits_deposit() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let native = token::TokenClient::new(&env, &client.get_native_token());
+
+        let (guild_id, admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+        let treasury_id = setup_deposit_rule(&env, &client, guild_id, &owner, &admin, 100);
+
+        mint_native(&env, &client, &contributor, 300);
+
+        // the guild owner closing a proposal returns the deposit
+        let closed_id = client.create_proposal(
+            &guild_id,
+            &contributor,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Closed"),
+            &String::from_str(&env, "Closed"),
+        );
+        client.cancel_proposal(&closed_id, &owner);
+        assert_eq!(native.balance(&contributor), 300);
+
+        // withdrawing one's own active proposal forfeits it
+        let withdrawn_id = client.create_proposal(
+            &guild_id,
+            &contributor,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Withdrawn"),
+            &String::from_str(&env, "Withdrawn"),
+        );
+        client.cancel_proposal(&withdrawn_id, &contributor);
+        assert_eq!(
+            client.get_proposal_deposit(&withdrawn_id).status,
+            DepositStatus::Forfeited
+        );
+        assert_eq!(native.balance(&contributor), 200);
+
+        let spam_id = client.create_proposal(
+            &guild_id,
+            &contributor,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Spam"),
+            &String::from_str(&env, "Spam"),
+        );
+        assert!(client
+            .try_cancel_proposal_for_abuse(&spam_id, &member)
+            .is_err());
+        assert!(client.cancel_proposal_for_abuse(&spam_id, &admin));
+
+        assert_eq!(
+            client.get_proposal(&spam_id).status,
+            ProposalStatus::Cancelled
+        );
+        assert_eq!(
+            client.get_proposal_deposit(&spam_id).status,
+            DepositStatus::Forfeited
+        );
+        assert_eq!(native.balance(&contributor), 100);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 200);
+    }
+
+    #[test]
+    fn test_rule_change_proposal_updates_deposit() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, _member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+        let treasury_id = setup_deposit_rule(&env, &client, guild_id, &owner, &admin, 100);
+
+        mint_native(&env, &client, &owner, 200);
+
+        let rule = DepositRule {
+            amount: 40,
+            token: None,
+            treasury_id,
+        };
+        let proposal_id = client.propose_deposit_rule_change(
+            &guild_id,
+            &owner,
+            &rule,
+            &String::from_str(&env, "Cheaper proposals"),
+            &String::from_str(&env, "Lower the deposit"),
+        );
+        client.vote(&proposal_id, &owner, &VoteDecision::For);
+        client.vote(&proposal_id, &admin, &VoteDecision::For);
+
+        let voting_end = client.get_proposal(&proposal_id).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+        assert!(client.execute_proposal(&proposal_id, &owner));
+
+        let next_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Next"),
+            &String::from_str(&env, "Next"),
+        );
+        assert_eq!(client.get_proposal_deposit(&next_id).amount, 40);
+
+        // a plain config update cannot drop the deposit
+        let result =
+            client.try_update_governance_config(&guild_id, &owner, &GovernanceConfig::default());
+        assert!(result.is_err());
+
+        // other settings still update while the stored rule is passed back
+        let mut config = GovernanceConfig::default();
+        config.quorum_percentage = 40;
+        config.proposal_deposit = rule;
+        assert!(client.update_governance_config(&guild_id, &owner, &config));
+        let after_update_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "After update"),
+            &String::from_str(&env, "After update"),
+        );
+        assert_eq!(client.get_proposal_deposit(&after_update_id).amount, 40);
+    }
+
+    fn signed_vote(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        key: &SigningKey,
+        voter: &Address,
+        proposal_id: u64,
+        decision: VoteDecision,
+        nonce: u64,
+    ) -> SignedVote {
+        let payload = client.get_signed_vote_payload(voter, &proposal_id, &decision, &nonce);
+        let mut message = [0u8; 256];
+        let len = payload.len() as usize;
+        payload.copy_into_slice(&mut message[..len]);
+        let signature = key.sign(&message[..len]).to_bytes();
+
+        SignedVote {
+            voter: voter.clone(),
+            proposal_id,
+            decision,
+            nonce,
+            signature: BytesN::from_array(env, &signature),
+        }
+    }
+
+    #[test]
+    fn test_signed_votes_are_relayed_once() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+        let relayer = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, _member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let owner_key = SigningKey::from_bytes(&[1u8; 32]);
+        let admin_key = SigningKey::from_bytes(&[2u8; 32]);
+        client.register_vote_key(
+            &owner,
+            &BytesN::from_array(&env, &owner_key.verifying_key().to_bytes()),
+        );
+        client.register_vote_key(
+            &admin,
+            &BytesN::from_array(&env, &admin_key.verifying_key().to_bytes()),
+        );
+
+        let proposal_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Gasless"),
+            &String::from_str(&env, "Gasless"),
+        );
+
+        let batch = vec![
+            &env,
+            signed_vote(
+                &env,
+                &client,
+                &owner_key,
+                &owner,
+                proposal_id,
+                VoteDecision::For,
+                0,
+            ),
+            signed_vote(
+                &env,
+                &client,
+                &admin_key,
+                &admin,
+                proposal_id,
+                VoteDecision::Against,
+                0,
+            ),
+        ];
+        assert_eq!(client.submit_signed_votes(&relayer, &batch), 2);
+        assert_eq!(client.get_signed_vote_nonce(&owner), 1);
+
+        // the same signatures cannot be replayed
+        assert!(client.try_submit_signed_votes(&relayer, &batch).is_err());
+
+        // a vote signed by the wrong key is refused
+        let forged = vec![
+            &env,
+            signed_vote(
+                &env,
+                &client,
+                &owner_key,
+                &admin,
+                proposal_id,
+                VoteDecision::For,
+                1,
+            ),
+        ];
+        assert!(client.try_submit_signed_votes(&relayer, &forged).is_err());
+
+        // a later signed vote replaces the earlier one
+        let change = vec![
+            &env,
+            signed_vote(
+                &env,
+                &client,
+                &admin_key,
+                &admin,
+                proposal_id,
+                VoteDecision::For,
+                1,
+            ),
+        ];
+        client.submit_signed_votes(&relayer, &change);
+
+        let voting_end = client.get_proposal(&proposal_id).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+        assert_eq!(
+            client.finalize_proposal(&proposal_id),
+            ProposalStatus::Passed
+        );
+        assert_eq!(client.get_proposal(&proposal_id).votes_for, 15);
+    }
+
+    /// The Stellar account address whose key is `key`
+    fn account_address(env: &Env, key: &SigningKey) -> Address {
+        let mut xdr = Bytes::from_array(env, &[0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0]);
+        xdr.extend_from_array(&key.verifying_key().to_bytes());
+        Address::from_xdr(env, &xdr).unwrap()
+    }
+
+    #[test]
+    fn test_signed_votes_are_bound_to_the_voter() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+        let relayer = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, _admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        // an account signs with its own key, without registering anything
+        let account_key = SigningKey::from_bytes(&[3u8; 32]);
+        let account = account_address(&env, &account_key);
+        client.add_member(&guild_id, &account, &Role::Member, &owner);
+        assert!(client
+            .try_register_vote_key(&account, &BytesN::from_array(&env, &[4u8; 32]),)
+            .is_err());
+
+        // contract accounts sharing a key still cannot use each other's votes
+        let shared_key = SigningKey::from_bytes(&[5u8; 32]);
+        let shared = BytesN::from_array(&env, &shared_key.verifying_key().to_bytes());
+        client.register_vote_key(&member, &shared);
+        client.register_vote_key(&contributor, &shared);
+
+        let proposal_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Bound"),
+            &String::from_str(&env, "Bound"),
+        );
+
+        let mut reused = signed_vote(
+            &env,
+            &client,
+            &shared_key,
+            &member,
+            proposal_id,
+            VoteDecision::For,
+            0,
+        );
+        reused.voter = contributor.clone();
+        assert!(client
+            .try_submit_signed_votes(&relayer, &vec![&env, reused])
+            .is_err());
+
+        let batch = vec![
+            &env,
+            signed_vote(
+                &env,
+                &client,
+                &account_key,
+                &account,
+                proposal_id,
+                VoteDecision::For,
+                0,
+            ),
+        ];
+        assert_eq!(client.submit_signed_votes(&relayer, &batch), 1);
+        assert_eq!(client.get_signed_vote_nonce(&account), 1);
+        assert_eq!(client.get_proposal_votes(&proposal_id).len(), 1);
+    }
+
+    #[test]
+    fn test_topic_scoped_partial_and_expiring_delegation() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        // General decisions go to the admin; everything else is split and lapses
+        client.delegate_vote_scoped(
+            &guild_id,
+            &owner,
+            &DelegationScope::Topic(ProposalType::GeneralDecision),
+            &vec![
+                &env,
+                DelegationSplit {
+                    delegate: admin.clone(),
+                    percentage: 100,
+                },
+            ],
+            &0u64,
+        );
+        client.delegate_vote_scoped(
+            &guild_id,
+            &owner,
+            &DelegationScope::All,
+            &vec![
+                &env,
+                DelegationSplit {
+                    delegate: member.clone(),
+                    percentage: 60,
+                },
+                DelegationSplit {
+                    delegate: contributor.clone(),
+                    percentage: 40,
+                },
+            ],
+            &1100u64,
+        );
+        assert!(client
+            .try_delegate_vote_scoped(
+                &guild_id,
+                &member,
+                &DelegationScope::All,
+                &vec![
+                    &env,
+                    DelegationSplit {
+                        delegate: owner.clone(),
+                        percentage: 50,
+                    },
+                ],
+                &0u64,
+            )
+            .is_err());
+
+        assert_eq!(client.get_outbound_delegations(&guild_id, &owner).len(), 2);
+        assert_eq!(client.get_inbound_delegations(&guild_id, &admin).len(), 1);
+        assert_eq!(
+            client
+                .get_inbound_delegations(&guild_id, &contributor)
+                .len(),
+            1
+        );
+
+        let decision_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Decision"),
+            &String::from_str(&env, "Decision"),
+        );
+        let snapshot = client.get_proposal_vote_snapshot(&decision_id);
+        assert_eq!(pooled_power(&env, &contract_id, &snapshot, &owner), 0);
+        assert_eq!(pooled_power(&env, &contract_id, &snapshot, &admin), 15);
+
+        let no_deposit = DepositRule {
+            amount: 0,
+            token: None,
+            treasury_id: 0,
+        };
+        let rule_id = client.propose_deposit_rule_change(
+            &guild_id,
+            &owner,
+            &no_deposit,
+            &String::from_str(&env, "Rule"),
+            &String::from_str(&env, "Rule"),
+        );
+        let snapshot = client.get_proposal_vote_snapshot(&rule_id);
+        assert_eq!(pooled_power(&env, &contract_id, &snapshot, &admin), 5);
+        assert_eq!(pooled_power(&env, &contract_id, &snapshot, &member), 8);
+        assert_eq!(pooled_power(&env, &contract_id, &snapshot, &contributor), 5);
+
+        // once the split delegation lapses the owner keeps their weight again
+        set_ledger_timestamp(&env, 1200);
+        let later_id = client.propose_deposit_rule_change(
+            &guild_id,
+            &owner,
+            &no_deposit,
+            &String::from_str(&env, "Later rule"),
+            &String::from_str(&env, "Later rule"),
+        );
+        let snapshot = client.get_proposal_vote_snapshot(&later_id);
+        assert_eq!(pooled_power(&env, &contract_id, &snapshot, &owner), 10);
+        assert_eq!(client.get_outbound_delegations(&guild_id, &owner).len(), 1);
+
+        client.undelegate_vote_scoped(
+            &guild_id,
+            &owner,
+            &DelegationScope::Topic(ProposalType::GeneralDecision),
+        );
+        assert_eq!(client.get_inbound_delegations(&guild_id, &admin).len(), 0);
+    }
+
+    #[test]
+    fn test_direct_vote_overrides_delegation() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        client.delegate_vote(&guild_id, &member, &admin);
+        client.delegate_vote(&guild_id, &contributor, &member);
+
+        let proposal_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Override"),
+            &String::from_str(&env, "Override"),
+        );
+
+        // the member votes themselves, taking back their own and the
+        // contributor's weight from the admin
+        client.vote(&proposal_id, &admin, &VoteDecision::For);
+        client.vote(&proposal_id, &member, &VoteDecision::Against);
+
+        let voting_end = client.get_proposal(&proposal_id).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+
+        assert_eq!(
+            client.finalize_proposal(&proposal_id),
+            ProposalStatus::Passed
+        );
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.votes_for, 5);
+        assert_eq!(proposal.votes_against, 3);
+    }
+
+    #[test]
+    fn test_commit_reveal_proposal_hides_votes_until_reveal() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let proposal_id = client.create_private_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Sealed"),
+            &String::from_str(&env, "Sealed"),
+        );
+        let proposal = client.get_proposal(&proposal_id);
+        let reveal_end = proposal.reveal_end.unwrap();
+        assert_eq!(reveal_end, proposal.voting_end + 2 * 86400);
+
+        let salt = BytesN::from_array(&env, &[7u8; 32]);
+        let commit = |voter: &Address, decision: VoteDecision| {
+            let commitment = client.compute_vote_commitment(voter, &decision, &salt);
+            client.commit_vote(&proposal_id, voter, &commitment);
+        };
+        commit(&owner, VoteDecision::For);
+        commit(&admin, VoteDecision::Against);
+        commit(&member, VoteDecision::Against);
+
+        assert!(client
+            .try_vote(&proposal_id, &admin, &VoteDecision::For)
+            .is_err());
+        assert!(client
+            .try_reveal_vote(&proposal_id, &owner, &VoteDecision::For, &salt)
+            .is_err());
+
+        set_ledger_timestamp(&env, proposal.voting_end + 1);
+        assert!(client.try_commit_vote(&proposal_id, &owner, &salt).is_err());
+
+        // a reveal must match the commitment
+        assert!(client
+            .try_reveal_vote(&proposal_id, &admin, &VoteDecision::For, &salt)
+            .is_err());
+        client.reveal_vote(&proposal_id, &owner, &VoteDecision::For, &salt);
+        client.reveal_vote(&proposal_id, &admin, &VoteDecision::Against, &salt);
+        assert!(
+            client
+                .get_vote_commitment(&proposal_id, &owner)
+                .unwrap()
+                .revealed
+        );
+        let commitments = client.get_proposal_vote_commitments(&proposal_id);
+        assert_eq!(commitments.len(), 3);
+        assert_eq!(commitments.iter().filter(|c| c.revealed).count(), 2);
+        assert_eq!(client.get_proposal_votes(&proposal_id).len(), 2);
+
+        assert!(client.try_finalize_proposal(&proposal_id).is_err());
+
+        set_ledger_timestamp(&env, reveal_end + 1);
+        assert!(client
+            .try_reveal_vote(&proposal_id, &member, &VoteDecision::Against, &salt)
+            .is_err());
+
+        // the member's unrevealed commitment is left out of the tallies
+        assert_eq!(
+            client.finalize_proposal(&proposal_id),
+            ProposalStatus::Passed
+        );
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.votes_for, 10);
+        assert_eq!(proposal.votes_against, 5);
+    }
+
+    #[test]
+    fn test_unrevealed_commitments_count_toward_quorum() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let proposal_id = client.create_private_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &String::from_str(&env, "Quorum"),
+            &String::from_str(&env, "Quorum"),
+        );
+
+        let salt = BytesN::from_array(&env, &[9u8; 32]);
+        for voter in [&admin, &member] {
+            let commitment = client.compute_vote_commitment(voter, &VoteDecision::For, &salt);
+            client.commit_vote(&proposal_id, voter, &commitment);
+        }
+
+        let proposal = client.get_proposal(&proposal_id);
+        set_ledger_timestamp(&env, proposal.voting_end + 1);
+        client.reveal_vote(&proposal_id, &member, &VoteDecision::For, &salt);
+
+        // the member's 2 of 18 alone misses the 30% quorum; the admin's
+        // sealed 5 makes it up
+        set_ledger_timestamp(&env, proposal.reveal_end.unwrap() + 1);
+        assert_eq!(
+            client.finalize_proposal(&proposal_id),
+            ProposalStatus::Passed
+        );
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.votes_for, 2);
+        assert_eq!(proposal.votes_against, 0);
+    }
+
+    #[test]
+    fn test_optimistic_proposal_passes_without_enough_objections() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let title = String::from_str(&env, "Routine");
+        assert!(client
+            .try_create_optimistic_proposal(
+                &guild_id,
+                &member,
+                &ProposalType::GeneralDecision,
+                &title,
+                &title,
+            )
+            .is_err());
+
+        let proposal_id = client.create_optimistic_proposal(
+            &guild_id,
+            &admin,
+            &ProposalType::GeneralDecision,
+            &title,
+            &title,
+        );
+        let proposal = client.get_proposal(&proposal_id);
+        assert!(proposal.optimistic);
+        assert_eq!(proposal.voting_end, 1000 + 3 * 86400);
+
+        assert!(client
+            .try_vote(&proposal_id, &owner, &VoteDecision::For)
+            .is_err());
+
+        // the contributor's 1 of 18 does not exceed the 10% threshold
+        assert_eq!(client.object_to_proposal(&proposal_id, &contributor), false);
+        assert!(client
+            .try_object_to_proposal(&proposal_id, &contributor)
+            .is_err());
+        assert_eq!(client.get_proposal(&proposal_id).objection_weight, 1);
+        assert_eq!(
+            client.get_proposal_objections(&proposal_id),
+            vec![&env, contributor.clone()]
+        );
+
+        assert!(client.try_execute_proposal(&proposal_id, &admin).is_err());
+
+        set_ledger_timestamp(&env, proposal.voting_end + 1);
+        assert!(client
+            .try_object_to_proposal(&proposal_id, &member)
+            .is_err());
+        assert!(client.execute_proposal(&proposal_id, &admin));
+        assert_eq!(
+            client.get_proposal(&proposal_id).status,
+            ProposalStatus::Executed
+        );
+    }
+
+    #[test]
+    fn test_objections_escalate_optimistic_proposal_to_full_vote() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let title = String::from_str(&env, "Contested");
+        let proposal_id = client.create_optimistic_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &title,
+            &title,
+        );
+
+        set_ledger_timestamp(&env, 2000);
+        assert_eq!(client.object_to_proposal(&proposal_id, &member), true);
+
+        let proposal = client.get_proposal(&proposal_id);
+        assert!(!proposal.optimistic);
+        assert_eq!(proposal.objection_weight, 2);
+        assert_eq!(proposal.voting_start, 2000);
+        assert_eq!(proposal.voting_end, 2000 + 7 * 86400);
+        assert!(client.try_object_to_proposal(&proposal_id, &admin).is_err());
+
+        // once escalated it is decided like any other proposal
+        client.vote(&proposal_id, &admin, &VoteDecision::Against);
+        client.vote(&proposal_id, &member, &VoteDecision::Against);
+
+        set_ledger_timestamp(&env, proposal.voting_end + 1);
+        assert_eq!(
+            client.finalize_proposal(&proposal_id),
+            ProposalStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn test_quorum_uses_exact_weight_or_headcount() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, admin, _member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let title = String::from_str(&env, "Quorum");
+        let propose = || {
+            client.create_proposal(
+                &guild_id,
+                &owner,
+                &ProposalType::GeneralDecision,
+                &title,
+                &title,
+            )
+        };
+
+        // 5 of 18 weight is short of a 30% quorum, which needs 5.4
+        let by_weight = propose();
+        client.vote(&by_weight, &admin, &VoteDecision::For);
+
+        let voting_end = client.get_proposal(&by_weight).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+        assert_eq!(
+            client.finalize_proposal(&by_weight),
+            ProposalStatus::Rejected
+        );
+
+        let mut config = GovernanceConfig::default();
+        config.quorum_basis = QuorumBasis::Headcount;
+        client.update_governance_config(&guild_id, &owner, &config);
+
+        // under headcount quorum the owner's heavy weight alone is one member
+        // of four, while two light members are enough
+        let lone_owner = propose();
+        client.vote(&lone_owner, &owner, &VoteDecision::For);
+        let two_members = propose();
+        client.vote(&two_members, &admin, &VoteDecision::For);
+        client.vote(&two_members, &contributor, &VoteDecision::For);
+
+        let voting_end = client.get_proposal(&two_members).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+
+        assert_eq!(
+            client.finalize_proposal(&lone_owner),
+            ProposalStatus::Rejected
+        );
+        assert_eq!(
+            client.finalize_proposal(&two_members),
+            ProposalStatus::Passed
+        );
+    }
+
+    #[test]
+    fn test_quorum_counts_snapshot_power_in_quadratic_and_conviction_modes() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, _admin, member, contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+        let title = String::from_str(&env, "Quorum");
+
+        // a single quadratic vote still brings the owner's 10 of 18 power
+        client.set_proposal_type_voting_mode(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &VotingMode::Quadratic,
+        );
+        let quadratic_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &title,
+            &title,
+        );
+        client.vote_quadratic(&quadratic_id, &owner, &VoteDecision::For, &1u32);
+
+        // conviction multiplies the tally, not the 3 of 18 power taking part
+        client.set_proposal_type_voting_mode(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &VotingMode::Conviction,
+        );
+        let conviction_id = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &title,
+            &title,
+        );
+        client.vote(&conviction_id, &member, &VoteDecision::For);
+        client.vote(&conviction_id, &contributor, &VoteDecision::For);
+
+        let voting_end = client.get_proposal(&conviction_id).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+
+        assert_eq!(
+            client.finalize_proposal(&quadratic_id),
+            ProposalStatus::Passed
+        );
+        assert_eq!(client.get_proposal(&quadratic_id).votes_for, 1);
+        assert_eq!(
+            client.finalize_proposal(&conviction_id),
+            ProposalStatus::Rejected
+        );
+        assert_eq!(client.get_proposal(&conviction_id).votes_for, 24);
+    }
+
+    #[test]
+    fn test_proposal_history_queries() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let (guild_id, _admin, member, _contributor) =
+            setup_guild_with_members(&env, &client, &owner);
+
+        let title = String::from_str(&env, "History");
+        let passed = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &title,
+            &title,
+        );
+        let cancelled = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &title,
+            &title,
+        );
+        let active = client.create_proposal(
+            &guild_id,
+            &owner,
+            &ProposalType::GeneralDecision,
+            &title,
+            &title,
+        );
+
+        client.vote(&passed, &owner, &VoteDecision::For);
+        client.vote(&passed, &member, &VoteDecision::Against);
+        client.cancel_proposal(&cancelled, &owner);
+
+        let voting_end = client.get_proposal(&passed).voting_end;
+        set_ledger_timestamp(&env, voting_end + 1);
+        client.finalize_proposal(&passed);
+
+        let all = client.get_all_proposals(&guild_id);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all.get(0).unwrap().id, passed);
+
+        let by_status = |status: ProposalStatus| {
+            let mut ids = Vec::new(&env);
+            for p in client.get_proposals_by_status(&guild_id, &status).iter() {
+                ids.push_back(p.id);
+            }
+            ids
+        };
+        assert_eq!(by_status(ProposalStatus::Passed), vec![&env, passed]);
+        assert_eq!(by_status(ProposalStatus::Cancelled), vec![&env, cancelled]);
+        assert_eq!(by_status(ProposalStatus::Active), vec![&env, active]);
+        assert_eq!(by_status(ProposalStatus::Rejected).len(), 0);
+
+        let votes = client.get_proposal_votes(&passed);
+        assert_eq!(votes.len(), 2);
+        for vote in votes.iter() {
+            assert_eq!(vote.proposal_id, passed);
+            if vote.voter == owner {
+                assert_eq!(vote.decision, VoteDecision::For);
+                assert_eq!(vote.weight, 10);
+            } else {
+                assert_eq!(vote.voter, member);
+                assert_eq!(vote.decision, VoteDecision::Against);
+                assert_eq!(vote.weight, 2);
+            }
+        }
+        assert_eq!(client.get_proposal_votes(&active).len(), 0);
+        assert!(client.try_get_proposal_votes(&99).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 use crate::guild::types::Role;
 
@@ -10,6 +10,7 @@ pub enum ProposalType {
     RemoveMember,
     RuleChange,
     GeneralDecision,
+    TreasuryVeto,
 }
 
 #[contracttype]
@@ -22,6 +23,10 @@ pub enum ProposalStatus {
     Executed,
     Cancelled,
     Expired,
+    /// Passed and waiting out the guild's execution delay
+    Queued,
+    /// Stopped by a guardian while queued
+    Vetoed,
 }
 
 #[contracttype]
@@ -32,22 +37,120 @@ pub enum VoteDecision {
     Abstain,
 }
 
+impl VoteDecision {
+    /// Byte identifying the decision in signed payloads and vote commitments
+    pub fn code(&self) -> u8 {
+        match self {
+            VoteDecision::For => 0,
+            VoteDecision::Against => 1,
+            VoteDecision::Abstain => 2,
+        }
+    }
+}
+
+/// How votes are turned into tallies
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VotingMode {
+    /// A vote counts with the voter's full weight
+    Linear,
+    /// The voter's weight is a budget of voice credits; `n` votes cost `n²`
+    Quadratic,
+    /// A vote's weight grows the longer it stays unchanged on the proposal
+    Conviction,
+}
+
+/// What a guild's quorum percentage is measured against
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QuorumBasis {
+    /// Participating voting weight against the snapshot's total weight, the
+    /// same reputation-boosted weights the tallies start from. Quadratic and
+    /// conviction modes transform only the tallies, not this weight.
+    Weight,
+    /// Members who took part against the members in the snapshot, whatever
+    /// their weight
+    Headcount,
+}
+
+/// How ballots are cast on a proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BallotType {
+    /// For / Against / Abstain
+    Binary,
+    /// One option per voter; the option with the most weight wins
+    SingleChoice,
+    /// Any number of options per voter, each receiving the voter's full weight
+    Approval,
+    /// Options ranked by preference, tallied by instant runoff
+    RankedChoice,
+}
+
+/// A labelled choice on a multi-option proposal, with the action that runs
+/// if it wins
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalOption {
+    pub label: String,
+    pub payload: ExecutionPayload,
+}
+
+/// Deposit a proposer locks when creating a proposal. It is refunded once
+/// the proposal reaches quorum and forfeited to `treasury_id` otherwise.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositRule {
+    /// Zero disables deposits
+    pub amount: i128,
+    /// `None` for the native token
+    pub token: Option<Address>,
+    /// Guild treasury receiving forfeited deposits
+    pub treasury_id: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GovernanceConfig {
     pub quorum_percentage: u32,
+    pub quorum_basis: QuorumBasis,
     pub approval_threshold: u32,
     pub voting_period_days: u32,
     pub min_proposer_reputation: u32,
+    /// Mode for proposal types without their own override
+    pub voting_mode: VotingMode,
+    /// Delay between a proposal passing and becoming executable, during
+    /// which guardians may veto it
+    pub execution_delay_seconds: u64,
+    pub proposal_deposit: DepositRule,
+    /// How long voters on a commit-reveal proposal have to reveal their
+    /// votes once voting closes
+    pub reveal_period_seconds: u64,
+    /// How long members may object to an optimistic proposal
+    pub objection_window_seconds: u64,
+    /// Share of the guild's total weight whose objections turn an
+    /// optimistic proposal into a full vote
+    pub objection_threshold_percentage: u32,
 }
 
 impl GovernanceConfig {
     pub fn default() -> Self {
         Self {
             quorum_percentage: 30,
+            quorum_basis: QuorumBasis::Weight,
             approval_threshold: 60,
             voting_period_days: 7,
             min_proposer_reputation: 0,
+            voting_mode: VotingMode::Linear,
+            execution_delay_seconds: 0,
+            reveal_period_seconds: 2 * 24 * 60 * 60,
+            objection_window_seconds: 3 * 24 * 60 * 60,
+            objection_threshold_percentage: 10,
+            proposal_deposit: DepositRule {
+                amount: 0,
+                token: None,
+                treasury_id: 0,
+            },
         }
     }
 }
@@ -67,6 +170,10 @@ pub enum ExecutionPayload {
     RuleChange,
     /// General decision (signalling only)
     GeneralDecision,
+    /// Veto a timelocked treasury transaction: (tx_id)
+    TreasuryVeto(u64),
+    /// Rule change replacing the guild's proposal deposit rule
+    DepositRuleChange(DepositRule),
 }
 
 /// Detailed payload data stored separately for complex operations
@@ -124,6 +231,26 @@ pub struct Proposal {
     pub execution_payload: ExecutionPayload,
     pub passed_at: Option<u64>,
     pub executed_at: Option<u64>,
+    /// Fixed when the proposal is created
+    pub voting_mode: VotingMode,
+    pub ballot_type: BallotType,
+    /// Choices of a multi-option proposal (empty for binary proposals)
+    pub options: Vec<ProposalOption>,
+    /// Final weight of each option once finalized; for ranked ballots the
+    /// tallies of the last instant-runoff round
+    pub option_weights: Vec<i128>,
+    /// Index of the winning option once a multi-option proposal passes
+    pub winning_option: Option<u32>,
+    /// Earliest time a passed proposal may be executed
+    pub executable_at: Option<u64>,
+    /// End of the reveal window of a commit-reveal proposal; `None` when
+    /// votes are cast in the open
+    pub reveal_end: Option<u64>,
+    /// Set while an optimistic proposal is in its objection window, which
+    /// runs until `voting_end`; cleared if objections force a full vote
+    pub optimistic: bool,
+    /// Weight of the members objecting to an optimistic proposal
+    pub objection_weight: i128,
 }
 
 #[contracttype]
@@ -132,10 +259,157 @@ pub struct Vote {
     pub voter: Address,
     pub proposal_id: u64,
     pub decision: VoteDecision,
+    /// Snapshot voting power, or the votes bought in quadratic mode
     pub weight: i128,
+    /// When the vote was last cast; conviction accrues from here
     pub timestamp: u64,
 }
 
+/// A sealed vote: the hash of the decision and a secret salt, opened once
+/// voting closes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommitment {
+    pub voter: Address,
+    pub commitment: BytesN<32>,
+    pub committed_at: u64,
+    pub revealed: bool,
+}
+
+/// Which proposals a delegation applies to
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DelegationScope {
+    /// Every proposal type without its own topic delegation
+    All,
+    /// Only proposals of this type
+    Topic(ProposalType),
+}
+
+/// Share of a delegator's weight given to one delegate
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegationSplit {
+    pub delegate: Address,
+    /// 1-100; the shares of one delegation add up to at most 100, and the
+    /// delegator keeps the rest
+    pub percentage: u32,
+}
+
+/// A member's delegation of their voting weight within a guild
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delegation {
+    pub guild_id: u64,
+    pub delegator: Address,
+    pub scope: DelegationScope,
+    pub splits: Vec<DelegationSplit>,
+    /// Time the delegation lapses; 0 for no expiry
+    pub expires_at: u64,
+    pub created_at: u64,
+}
+
+/// Voting power frozen when a proposal becomes active. Later role changes,
+/// new members, reputation gains and delegation changes do not affect it.
+///
+/// Only the checkpoint sequence is stored; each voter's weight and the
+/// delegations in force are read from the member and delegation checkpoints
+/// recorded up to it, so a snapshot stays the same size however large the
+/// guild grows.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteSnapshot {
+    pub proposal_id: u64,
+    pub guild_id: u64,
+    pub proposal_type: ProposalType,
+    pub taken_at: u64,
+    /// Last checkpoint sequence number in effect for this snapshot
+    pub checkpoint: u64,
+    /// Sum of all eligible members' weights; the quorum base
+    pub total_weight: i128,
+    /// Number of eligible members; the headcount quorum base
+    pub member_count: u32,
+}
+
+/// A member's role and reputation in a guild after a change to either
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WeightCheckpoint {
+    pub seq: u64,
+    /// `false` once the address has left the guild
+    pub member: bool,
+    /// Role while a member
+    pub role: Role,
+    /// Decayed reputation score, last decayed at `score_at`
+    pub score: u64,
+    pub score_at: u64,
+}
+
+/// A delegator's delegations in a guild after a change to them
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegationCheckpoint {
+    pub seq: u64,
+    pub delegations: Vec<Delegation>,
+}
+
+/// A voter's choices on a multi-option proposal: the chosen option, the
+/// approved options, or the options in order of preference
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptionBallot {
+    pub voter: Address,
+    pub proposal_id: u64,
+    pub choices: Vec<u32>,
+    pub weight: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DepositStatus {
+    Locked,
+    Refunded,
+    Forfeited,
+}
+
+/// Deposit locked by a proposal's creator
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalDeposit {
+    pub proposal_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub token: Option<Address>,
+    pub treasury_id: u64,
+    pub status: DepositStatus,
+    pub settled_at: Option<u64>,
+}
+
+/// A vote signed off-chain with the voter's registered ed25519 key and
+/// relayed on-chain by anyone
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedVote {
+    pub voter: Address,
+    pub proposal_id: u64,
+    pub decision: VoteDecision,
+    /// Must equal the voter's current vote nonce
+    pub nonce: u64,
+    /// Signature over the payload from `signed_vote_payload`
+    pub signature: BytesN<64>,
+}
+
+/// A guardian's veto of a queued proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalVeto {
+    pub proposal_id: u64,
+    pub guardian: Address,
+    pub reason: String,
+    pub vetoed_at: u64,
+}
+
 // Events
 
 #[contracttype]
@@ -155,6 +429,28 @@ pub struct VoteCastEvent {
     pub decision: VoteDecision,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteKeyRegisteredEvent {
+    pub voter: Address,
+    pub public_key: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedVotesRelayedEvent {
+    pub relayer: Address,
+    pub count: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptionBallotCastEvent {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub choices: Vec<u32>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VoteDelegatedEvent {
@@ -163,6 +459,16 @@ pub struct VoteDelegatedEvent {
     pub delegate: Address,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegationUpdatedEvent {
+    pub guild_id: u64,
+    pub delegator: Address,
+    pub scope: DelegationScope,
+    pub splits: Vec<DelegationSplit>,
+    pub expires_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VoteUndelegatedEvent {
@@ -199,6 +505,83 @@ pub struct GovernanceConfigUpdatedEvent {
     pub guild_id: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingModeUpdatedEvent {
+    pub guild_id: u64,
+    pub proposal_type: ProposalType,
+    pub voting_mode: VotingMode,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommittedEvent {
+    pub proposal_id: u64,
+    pub voter: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalObjectedEvent {
+    pub proposal_id: u64,
+    pub objector: Address,
+    pub objection_weight: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalEscalatedEvent {
+    pub proposal_id: u64,
+    pub objection_weight: i128,
+    pub voting_end: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalQueuedEvent {
+    pub proposal_id: u64,
+    pub executable_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalVetoedEvent {
+    pub proposal_id: u64,
+    pub guardian: Address,
+    pub reason: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardiansUpdatedEvent {
+    pub guild_id: u64,
+    pub guardians: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalDepositSettledEvent {
+    pub proposal_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub status: DepositStatus,
+}
+
+impl ExecutionPayload {
+    /// The proposal type whose action this payload carries
+    pub fn proposal_type(&self) -> ProposalType {
+        match self {
+            ExecutionPayload::TreasurySpend => ProposalType::TreasurySpend,
+            ExecutionPayload::AddMember => ProposalType::AddMember,
+            ExecutionPayload::RemoveMember => ProposalType::RemoveMember,
+            ExecutionPayload::RuleChange => ProposalType::RuleChange,
+            ExecutionPayload::GeneralDecision => ProposalType::GeneralDecision,
+            ExecutionPayload::TreasuryVeto(_) => ProposalType::TreasuryVeto,
+            ExecutionPayload::DepositRuleChange(_) => ProposalType::RuleChange,
+        }
+    }
+}
+
 pub fn role_weight(role: &Role) -> i128 {
     match role {
         Role::Owner => 10,
@@ -1,102 +1,321 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, Env, Map, Symbol, Vec};
 
+use crate::governance::checkpoints::weight_at;
+use crate::governance::delegation::{delegations_at, flow_voting_power};
+use crate::governance::deposits::settle_deposit;
+use crate::governance::options::tally_options;
+use crate::governance::queue::queue_if_passed;
 use crate::governance::storage::{
-    get_all_votes, get_config, get_delegate, get_proposal as load_proposal, remove_delegation,
-    set_delegation, store_proposal, store_vote,
+    get_all_votes, get_checkpoint_seq, get_config, get_proposal as load_proposal,
+    get_vote_commitments, get_vote_snapshot, store_proposal, store_vote, store_vote_snapshot,
 };
-use crate::governance::types::role_weight;
 use crate::governance::types::{
-    Proposal, ProposalFinalizedEvent, ProposalStatus, Vote, VoteCastEvent, VoteDecision,
+    BallotType, GovernanceConfig, Proposal, ProposalFinalizedEvent, ProposalStatus, QuorumBasis,
+    Vote, VoteCastEvent, VoteDecision, VoteSnapshot, VotingMode,
 };
 use crate::guild::storage as guild_storage;
-use crate::reputation::scoring::compute_governance_weight;
+use crate::reputation::scoring::{compute_governance_weight, integer_sqrt};
 
 const EVENT_TOPIC_VOTE_CAST: &str = "vote_cast";
-const EVENT_TOPIC_VOTE_DELEGATED: &str = "vote_delegated";
-const EVENT_TOPIC_VOTE_UNDELEGATED: &str = "vote_undelegated";
 const EVENT_TOPIC_PROPOSAL_FINALIZED: &str = "proposal_finalized";
 
-fn resolve_delegate(env: &Env, guild_id: u64, addr: &Address) -> Address {
-    let mut current = addr.clone();
-    // prevent infinite loops in case of unexpected cycles
-    for _ in 0..16 {
-        if let Some(next) = get_delegate(env, guild_id, &current) {
-            if next == current {
-                break;
-            }
-            current = next;
-        } else {
-            break;
+/// Freeze the proposal's voting power at the current checkpoint, recording
+/// the quorum bases from current roles and decayed reputation. Each voter's
+/// own weight and the delegations in force are looked up from the checkpoint
+/// when needed.
+fn build_vote_snapshot(env: &Env, proposal: &Proposal) -> VoteSnapshot {
+    let members = guild_storage::get_all_members(env, proposal.guild_id);
+
+    let mut total_weight: i128 = 0;
+    for member in members.iter() {
+        total_weight +=
+            compute_governance_weight(env, &member.address, proposal.guild_id, &member.role);
+    }
+
+    VoteSnapshot {
+        proposal_id: proposal.id,
+        guild_id: proposal.guild_id,
+        proposal_type: proposal.proposal_type.clone(),
+        taken_at: env.ledger().timestamp(),
+        checkpoint: get_checkpoint_seq(env),
+        total_weight,
+        member_count: members.len(),
+    }
+}
+
+/// Effective voting power of each voter on a proposal. A delegator who voted
+/// directly keeps all of their weight; delegates only receive weight from
+/// delegators who did not vote themselves. Only voters and members on a
+/// delegation path are weighed.
+pub(crate) fn effective_voting_power(
+    env: &Env,
+    snapshot: &VoteSnapshot,
+    voters: &Map<Address, bool>,
+) -> Map<Address, i128> {
+    let delegations = delegations_at(env, snapshot);
+
+    let mut involved = voters.keys();
+    for (delegator, splits) in delegations.iter() {
+        involved.push_back(delegator);
+        for split in splits.iter() {
+            involved.push_back(split.delegate);
+        }
+    }
+
+    let mut base_weight: Map<Address, i128> = Map::new(env);
+    for address in involved.iter() {
+        if let Some(weight) = weight_at(env, snapshot, &address) {
+            base_weight.set(address, weight);
         }
     }
-    current
+
+    flow_voting_power(env, &base_weight, &delegations, voters)
 }
 
-fn compute_total_weight_and_tallies(env: &Env, proposal: &Proposal) -> (i128, i128, i128, i128) {
-    // returns (total_votes_weight, for_weight, against_weight, abstain_weight)
+/// Freeze the voting power of a proposal's eligible voters. Called when the
+/// proposal becomes active.
+pub(crate) fn take_vote_snapshot(env: &Env, proposal: &Proposal) {
+    store_vote_snapshot(env, &build_vote_snapshot(env, proposal));
+}
+
+/// The frozen snapshot of a proposal; proposals created before snapshots
+/// existed fall back to current weights.
+pub fn get_proposal_vote_snapshot(env: &Env, proposal_id: u64) -> VoteSnapshot {
+    let proposal = load_proposal(env, proposal_id).unwrap_or_else(|| panic!("proposal not found"));
+    get_vote_snapshot(env, proposal_id).unwrap_or_else(|| build_vote_snapshot(env, &proposal))
+}
+
+/// Every vote recorded on a proposal. Sealed votes appear once revealed.
+pub fn get_proposal_votes(env: &Env, proposal_id: u64) -> Vec<Vote> {
+    let _proposal = load_proposal(env, proposal_id).unwrap_or_else(|| panic!("proposal not found"));
+    get_all_votes(env, proposal_id).values()
+}
+
+/// A conviction vote gains its base weight again for every full period it
+/// stays unchanged, up to `MAX_CONVICTION_MULTIPLIER` times the base.
+pub const CONVICTION_PERIOD_SECONDS: u64 = 24 * 60 * 60;
+pub const MAX_CONVICTION_MULTIPLIER: i128 = 8;
+
+fn conviction_weight(power: i128, held_seconds: u64) -> i128 {
+    let periods = (held_seconds / CONVICTION_PERIOD_SECONDS) as i128;
+    power * (1 + periods).min(MAX_CONVICTION_MULTIPLIER)
+}
+
+/// Whether participation meets the guild's quorum. `participation` is the
+/// snapshot voting power behind the ballots and `participants` the number of
+/// members who cast them. Compared without rounding, so a 30% quorum of 18
+/// weight needs 5.4, not 5.
+///
+/// Quadratic and conviction modes still count raw snapshot power here rather
+/// than their square-rooted or time-weighted tallies: quorum measures how much
+/// of the guild took part, which should not depend on how many credits a
+/// voter spends or how early they voted.
+pub(crate) fn quorum_reached(
+    cfg: &GovernanceConfig,
+    snapshot: &VoteSnapshot,
+    participation: i128,
+    participants: u32,
+) -> bool {
+    let pct = cfg.quorum_percentage as i128;
+    match cfg.quorum_basis {
+        QuorumBasis::Weight => participation * 100 >= snapshot.total_weight * pct,
+        QuorumBasis::Headcount => {
+            (participants as i128) * 100 >= (snapshot.member_count as i128) * pct
+        }
+    }
+}
+
+fn compute_total_weight_and_tallies(
+    env: &Env,
+    proposal: &Proposal,
+    snapshot: &VoteSnapshot,
+) -> (i128, u32, i128, i128, i128) {
+    // returns (total_votes_weight, participants, for_weight, against_weight,
+    // abstain_weight); the total and the participant count feed quorum, the
+    // tallies are weighted by the proposal's voting mode
     let votes_map = get_all_votes(env, proposal.id);
-    let members = guild_storage::get_all_members(env, proposal.guild_id);
+    let tally_time = env.ledger().timestamp().min(proposal.voting_end);
+
+    // voters who committed but never revealed still took part, so they count
+    // toward quorum and keep their weight from flowing to delegates
+    let mut voters: Map<Address, bool> = Map::new(env);
+    for voter in votes_map.keys().iter() {
+        voters.set(voter, true);
+    }
+    for voter in get_vote_commitments(env, proposal.id).keys().iter() {
+        voters.set(voter, true);
+    }
+    let effective = effective_voting_power(env, snapshot, &voters);
 
     let mut total_votes_weight: i128 = 0;
+    for voter in voters.keys().iter() {
+        total_votes_weight += effective.get(voter).unwrap_or(0);
+    }
+
     let mut for_weight: i128 = 0;
     let mut against_weight: i128 = 0;
     let mut abstain_weight: i128 = 0;
 
-    for member in members.iter() {
-        let rep = resolve_delegate(env, proposal.guild_id, &member.address);
-        let weight =
-            compute_governance_weight(env, &member.address, proposal.guild_id, &member.role);
-
-        let decision_opt = if rep == member.address {
-            votes_map.get(member.address.clone()).map(|v| v.decision)
-        } else {
-            votes_map.get(rep).map(|v| v.decision)
+    for (voter, vote) in votes_map.iter() {
+        let power = effective.get(voter).unwrap_or(0);
+        let weight = match proposal.voting_mode {
+            VotingMode::Linear => power,
+            // delegators voting after their delegate shrink the delegate's
+            // credits, so votes are capped at what the credits now cover
+            VotingMode::Quadratic => vote.weight.min(integer_sqrt(power.max(0) as u64) as i128),
+            VotingMode::Conviction => {
+                conviction_weight(power, tally_time.saturating_sub(vote.timestamp))
+            }
         };
 
-        if let Some(decision) = decision_opt {
-            total_votes_weight += weight;
-            match decision {
-                VoteDecision::For => for_weight += weight,
-                VoteDecision::Against => against_weight += weight,
-                VoteDecision::Abstain => abstain_weight += weight,
-            }
+        match vote.decision {
+            VoteDecision::For => for_weight += weight,
+            VoteDecision::Against => against_weight += weight,
+            VoteDecision::Abstain => abstain_weight += weight,
         }
     }
 
     (
         total_votes_weight,
+        voters.len(),
         for_weight,
         against_weight,
         abstain_weight,
     )
 }
 
+/// Cast or change a vote. In quadratic mode this spends all of the voter's
+/// voice credits; in conviction mode re-voting restarts the vote's conviction.
 pub fn vote(env: &Env, proposal_id: u64, voter: Address, decision: VoteDecision) -> bool {
     voter.require_auth();
 
+    cast_vote(env, proposal_id, voter, decision);
+    true
+}
+
+/// Record `voter`'s vote once their authorization has been checked, either
+/// through `require_auth` or a verified vote signature
+pub(crate) fn cast_vote(env: &Env, proposal_id: u64, voter: Address, decision: VoteDecision) {
+    let proposal = load_votable_proposal(env, proposal_id);
+    if proposal.ballot_type != BallotType::Binary {
+        panic!("multi-option proposals take option ballots");
+    }
+    if proposal.reveal_end.is_some() {
+        panic!("proposal uses commit-reveal voting");
+    }
+
+    record_binary_vote(env, &proposal, voter, decision, env.ledger().timestamp());
+}
+
+/// Weigh a binary vote by the proposal's voting mode and record it as cast
+/// at `timestamp`
+pub(crate) fn record_binary_vote(
+    env: &Env,
+    proposal: &Proposal,
+    voter: Address,
+    decision: VoteDecision,
+    timestamp: u64,
+) {
+    let power = voting_power_of(env, proposal.id, &voter);
+    let weight = match proposal.voting_mode {
+        VotingMode::Quadratic => integer_sqrt(power.max(0) as u64) as i128,
+        VotingMode::Linear | VotingMode::Conviction => power,
+    };
+
+    record_vote(env, proposal.id, voter, decision, weight, timestamp);
+}
+
+/// Cast `votes` quadratic votes on a quadratic-mode proposal, costing
+/// `votes²` of the voter's voice credits (their snapshot voting power).
+/// Re-voting replaces the earlier vote and refunds its credits. Votes are
+/// capped again at tally by the credits left once delegators who voted
+/// themselves take back their weight.
+pub fn vote_quadratic(
+    env: &Env,
+    proposal_id: u64,
+    voter: Address,
+    decision: VoteDecision,
+    votes: u32,
+) -> bool {
+    voter.require_auth();
+
+    let proposal = load_votable_proposal(env, proposal_id);
+    if proposal.voting_mode != VotingMode::Quadratic {
+        panic!("proposal does not use quadratic voting");
+    }
+    if proposal.reveal_end.is_some() {
+        panic!("proposal uses commit-reveal voting");
+    }
+    if votes == 0 {
+        panic!("votes must be positive");
+    }
+
+    let credits = voting_power_of(env, proposal_id, &voter);
+    let cost = (votes as i128) * (votes as i128);
+    if cost > credits {
+        panic!("insufficient voice credits");
+    }
+
+    record_vote(
+        env,
+        proposal_id,
+        voter,
+        decision,
+        votes as i128,
+        env.ledger().timestamp(),
+    );
+    true
+}
+
+pub(crate) fn load_votable_proposal(env: &Env, proposal_id: u64) -> Proposal {
     let proposal = load_proposal(env, proposal_id).unwrap_or_else(|| panic!("proposal not found"));
 
     if !matches!(proposal.status, ProposalStatus::Active) {
         panic!("proposal not active");
     }
+    if proposal.optimistic {
+        panic!("optimistic proposals take objections, not votes");
+    }
 
     let now = env.ledger().timestamp();
     if now < proposal.voting_start || now > proposal.voting_end {
         panic!("voting period closed");
     }
 
-    // must be guild member
-    let member = guild_storage::get_member(env, proposal.guild_id, &voter)
-        .unwrap_or_else(|| panic!("voter must be guild member"));
+    proposal
+}
+
+/// The voter's power if they vote directly, overriding any delegation of
+/// their own
+pub(crate) fn voting_power_of(env: &Env, proposal_id: u64, voter: &Address) -> i128 {
+    let snapshot = get_proposal_vote_snapshot(env, proposal_id);
 
-    let weight = compute_governance_weight(env, &voter, proposal.guild_id, &member.role);
+    // only members at the time the proposal became active may vote
+    if weight_at(env, &snapshot, voter).is_none() {
+        panic!("voter must be guild member");
+    }
 
+    let mut voters: Map<Address, bool> = Map::new(env);
+    voters.set(voter.clone(), true);
+    effective_voting_power(env, &snapshot, &voters)
+        .get(voter.clone())
+        .unwrap_or(0)
+}
+
+fn record_vote(
+    env: &Env,
+    proposal_id: u64,
+    voter: Address,
+    decision: VoteDecision,
+    weight: i128,
+    timestamp: u64,
+) {
     let vote = Vote {
         voter: voter.clone(),
         proposal_id,
         decision: decision.clone(),
         weight,
-        timestamp: now,
+        timestamp,
     };
 
     store_vote(env, &vote);
@@ -113,75 +332,6 @@ pub fn vote(env: &Env, proposal_id: u64, voter: Address, decision: VoteDecision)
         ),
         event,
     );
-
-    true
-}
-
-pub fn delegate_vote(env: &Env, guild_id: u64, delegator: Address, delegate: Address) -> bool {
-    delegator.require_auth();
-
-    if delegator == delegate {
-        panic!("cannot delegate to self");
-    }
-
-    // both must be guild members
-    let _d1 = guild_storage::get_member(env, guild_id, &delegator)
-        .unwrap_or_else(|| panic!("delegator must be guild member"));
-    let _d2 = guild_storage::get_member(env, guild_id, &delegate)
-        .unwrap_or_else(|| panic!("delegate must be guild member"));
-
-    // check for cycles: walk starting from delegate
-    let mut current = delegate.clone();
-    for _ in 0..16 {
-        if current == delegator {
-            panic!("delegation cycle detected");
-        }
-        if let Some(next) = get_delegate(env, guild_id, &current) {
-            if next == current {
-                break;
-            }
-            current = next;
-        } else {
-            break;
-        }
-    }
-
-    set_delegation(env, guild_id, &delegator, &delegate);
-
-    let event = crate::governance::types::VoteDelegatedEvent {
-        guild_id,
-        delegator,
-        delegate,
-    };
-    env.events().publish(
-        (
-            Symbol::new(env, EVENT_TOPIC_VOTE_DELEGATED),
-            Symbol::new(env, "v0"),
-        ),
-        event,
-    );
-
-    true
-}
-
-pub fn undelegate_vote(env: &Env, guild_id: u64, delegator: Address) -> bool {
-    delegator.require_auth();
-
-    remove_delegation(env, guild_id, &delegator);
-
-    let event = crate::governance::types::VoteUndelegatedEvent {
-        guild_id,
-        delegator,
-    };
-    env.events().publish(
-        (
-            Symbol::new(env, EVENT_TOPIC_VOTE_UNDELEGATED),
-            Symbol::new(env, "v0"),
-        ),
-        event,
-    );
-
-    true
 }
 
 pub fn finalize_proposal(env: &Env, proposal_id: u64) -> ProposalStatus {
@@ -196,25 +346,43 @@ pub fn finalize_proposal(env: &Env, proposal_id: u64) -> ProposalStatus {
     if now < proposal.voting_end {
         panic!("voting period not finished");
     }
+    if let Some(reveal_end) = proposal.reveal_end {
+        if now <= reveal_end {
+            panic!("reveal period not finished");
+        }
+    }
 
     let cfg = get_config(env, proposal.guild_id);
 
-    let members = guild_storage::get_all_members(env, proposal.guild_id);
-    let mut total_possible_weight: i128 = 0;
-    for member in members.iter() {
-        total_possible_weight += role_weight(&member.role);
+    // an optimistic proposal nobody forced to a vote passes when its
+    // objection window ends
+    if proposal.optimistic {
+        proposal.status = ProposalStatus::Passed;
+        proposal.passed_at = Some(now);
+        settle_deposit(env, proposal_id, true);
+        return publish_finalized(env, &mut proposal, &cfg);
     }
 
-    let quorum_threshold: i128 = (total_possible_weight * (cfg.quorum_percentage as i128)) / 100;
+    let snapshot = get_proposal_vote_snapshot(env, proposal_id);
+
+    if proposal.ballot_type != BallotType::Binary {
+        let quorum_reached = tally_options(env, &mut proposal, &cfg, &snapshot);
+        settle_deposit(env, proposal_id, quorum_reached);
+        return publish_finalized(env, &mut proposal, &cfg);
+    }
 
-    let (total_votes_weight, for_weight, against_weight, abstain_weight) =
-        compute_total_weight_and_tallies(env, &proposal);
+    let (total_votes_weight, participants, for_weight, against_weight, abstain_weight) =
+        compute_total_weight_and_tallies(env, &proposal, &snapshot);
 
     proposal.votes_for = for_weight;
     proposal.votes_against = against_weight;
     proposal.votes_abstain = abstain_weight;
 
-    if total_votes_weight < quorum_threshold {
+    // the proposer's deposit comes back once the proposal draws quorum
+    let quorum_reached = quorum_reached(&cfg, &snapshot, total_votes_weight, participants);
+    settle_deposit(env, proposal_id, quorum_reached);
+
+    if !quorum_reached {
         proposal.status = ProposalStatus::Rejected;
     } else {
         let counted = for_weight + against_weight;
@@ -233,10 +401,15 @@ pub fn finalize_proposal(env: &Env, proposal_id: u64) -> ProposalStatus {
         }
     }
 
-    store_proposal(env, &proposal);
+    publish_finalized(env, &mut proposal, &cfg)
+}
+
+fn publish_finalized(env: &Env, proposal: &mut Proposal, cfg: &GovernanceConfig) -> ProposalStatus {
+    queue_if_passed(env, proposal, cfg);
+    store_proposal(env, proposal);
 
     let event = ProposalFinalizedEvent {
-        proposal_id,
+        proposal_id: proposal.id,
         status: proposal.status.clone(),
         votes_for: proposal.votes_for,
         votes_against: proposal.votes_against,
@@ -250,5 +423,5 @@ pub fn finalize_proposal(env: &Env, proposal_id: u64) -> ProposalStatus {
         event,
     );
 
-    proposal.status
+    proposal.status.clone()
 }
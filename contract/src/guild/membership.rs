@@ -2,6 +2,7 @@ use crate::events::emit::emit_event;
 use crate::events::topics::{
     ACT_CREATED, ACT_MEMBER_ADDED, ACT_MEMBER_REMOVED, ACT_ROLE_UPDATED, MOD_GUILD,
 };
+use crate::governance::checkpoints::record_weight_checkpoint;
 use crate::guild::storage;
 use crate::guild::types::{
     Guild, GuildCreatedEvent, Member, MemberAddedEvent, MemberRemovedEvent, Role, RoleUpdatedEvent,
@@ -62,6 +63,7 @@ pub fn create_guild(
         joined_at: timestamp,
     };
     storage::store_member(env, guild_id, &owner_member);
+    record_weight_checkpoint(env, guild_id, &owner);
 
     emit_event(
         env,
@@ -139,6 +141,7 @@ pub fn add_member(
         joined_at: timestamp,
     };
     storage::store_member(env, guild_id, &member);
+    record_weight_checkpoint(env, guild_id, &address);
 
     let mut updated_guild = guild;
     updated_guild.member_count += 1;
@@ -229,6 +232,7 @@ pub fn remove_member(
     }
 
     storage::remove_member(env, guild_id, &address);
+    record_weight_checkpoint(env, guild_id, &address);
 
     let mut updated_guild = guild;
     updated_guild.member_count = updated_guild.member_count.saturating_sub(1);
@@ -314,6 +318,7 @@ pub fn update_role(
         joined_at: member.joined_at,
     };
     storage::store_member(env, guild_id, &updated_member);
+    record_weight_checkpoint(env, guild_id, &address);
 
     emit_event(
         env,
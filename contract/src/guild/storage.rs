@@ -5,6 +5,8 @@ use soroban_sdk::{symbol_short, Address, Env, Map, Symbol, Vec};
 const GUILDS_KEY: Symbol = symbol_short!("guilds");
 const MEMBERS_KEY: Symbol = symbol_short!("members");
 const GUILD_COUNTER_KEY: Symbol = symbol_short!("guild_cnt");
+const NATIVE_TOKEN_KEY: Symbol = symbol_short!("native_tk");
+const ADMIN_KEY: Symbol = symbol_short!("admin");
 
 /// Initialize storage for guilds and members
 /// This should be called during contract initialization
@@ -22,6 +24,46 @@ pub fn initialize(env: &Env) {
     env.storage().persistent().set(&GUILD_COUNTER_KEY, &0u64);
 }
 
+/// Whether the contract has been initialized
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().persistent().has(&ADMIN_KEY)
+}
+
+/// Record the contract admin chosen at initialization
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().persistent().set(&ADMIN_KEY, admin);
+}
+
+/// Get the contract admin chosen at initialization
+pub fn get_admin(env: &Env) -> Address {
+    env.storage()
+        .persistent()
+        .get(&ADMIN_KEY)
+        .expect("contract not initialized")
+}
+
+/// Record the Stellar Asset Contract address of native XLM.
+/// Money flows use it whenever a token of `None` (meaning XLM) is given.
+pub fn set_native_token(env: &Env, token: &Address) {
+    env.storage().persistent().set(&NATIVE_TOKEN_KEY, token);
+}
+
+/// Get the Stellar Asset Contract address of native XLM
+pub fn get_native_token(env: &Env) -> Address {
+    env.storage()
+        .persistent()
+        .get(&NATIVE_TOKEN_KEY)
+        .expect("native token not configured")
+}
+
+/// Resolve an optional token to a contract address, `None` meaning native XLM
+pub fn resolve_token(env: &Env, token: &Option<Address>) -> Address {
+    match token {
+        Some(addr) => addr.clone(),
+        None => get_native_token(env),
+    }
+}
+
 /// Get the next guild ID and increment the counter
 pub fn get_next_guild_id(env: &Env) -> u64 {
     let counter: u64 = env
@@ -0,0 +1,99 @@
+//! Internal ledger of contract-held funds.
+//!
+//! Bounty escrow, payment pools, subscription revenue, payment streams,
+//! vesting grants and every treasury all hold tokens at the contract address.
+//! The ledger splits that single balance into [`SubAccount`]s: every token
+//! transfer into or out of the contract goes through [`deposit`] or
+//! [`withdraw`], and funds moving between modules use [`transfer_internal`]. A
+//! sub-account can never be debited below zero, so an accounting bug in one
+//! module cannot spend another module's funds.
+//!
+//! [`reconcile`] compares the sum of all sub-accounts against the real token
+//! balance of the contract.
+
+pub mod storage;
+pub mod types;
+
+use soroban_sdk::{token::Client as TokenClient, Address, Env, Vec};
+
+use storage::{get_balance, get_token_accounts, set_balance};
+pub use types::{ReconciliationReport, SubAccount, SubAccountBalance};
+
+/// Current balance of `account` in `token`.
+pub fn balance(env: &Env, account: &SubAccount, token: &Address) -> i128 {
+    get_balance(env, account, token)
+}
+
+/// Increase the balance of a sub-account.
+pub fn credit(env: &Env, account: &SubAccount, token: &Address, amount: i128) {
+    if amount < 0 {
+        panic!("ledger amount must be non-negative");
+    }
+    let current = get_balance(env, account, token);
+    set_balance(env, account, token, current + amount);
+}
+
+/// Decrease the balance of a sub-account; panics if it would go negative.
+pub fn debit(env: &Env, account: &SubAccount, token: &Address, amount: i128) {
+    if amount < 0 {
+        panic!("ledger amount must be non-negative");
+    }
+    let current = get_balance(env, account, token);
+    if current < amount {
+        panic!("insufficient sub-account balance");
+    }
+    set_balance(env, account, token, current - amount);
+}
+
+/// Pull `amount` of `token` from `from` into the contract, crediting `account`.
+pub fn deposit(env: &Env, account: &SubAccount, token: &Address, from: &Address, amount: i128) {
+    let client = TokenClient::new(env, token);
+    client.transfer(from, &env.current_contract_address(), &amount);
+    credit(env, account, token, amount);
+}
+
+/// Debit `account` and pay `amount` of `token` out of the contract to `to`.
+pub fn withdraw(env: &Env, account: &SubAccount, token: &Address, to: &Address, amount: i128) {
+    debit(env, account, token, amount);
+    let client = TokenClient::new(env, token);
+    client.transfer(&env.current_contract_address(), to, &amount);
+}
+
+/// Move funds between two sub-accounts; no tokens leave the contract.
+pub fn transfer_internal(
+    env: &Env,
+    from: &SubAccount,
+    to: &SubAccount,
+    token: &Address,
+    amount: i128,
+) {
+    debit(env, from, token, amount);
+    credit(env, to, token, amount);
+}
+
+/// Compare the sum of all sub-accounts in `token` with the contract's real
+/// balance of that token.
+pub fn reconcile(env: &Env, token: &Address) -> ReconciliationReport {
+    let mut accounts = Vec::new(env);
+    let mut ledger_total = 0i128;
+    for account in get_token_accounts(env, token).iter() {
+        let balance = get_balance(env, &account, token);
+        ledger_total += balance;
+        accounts.push_back(SubAccountBalance { account, balance });
+    }
+
+    let actual_balance = TokenClient::new(env, token).balance(&env.current_contract_address());
+    let surplus = actual_balance - ledger_total;
+
+    ReconciliationReport {
+        token: token.clone(),
+        actual_balance,
+        ledger_total,
+        surplus,
+        balanced: surplus == 0,
+        accounts,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+use soroban_sdk::{symbol_short, Address, Env, Map, Symbol, Vec};
+
+use super::types::SubAccount;
+
+// ── Storage Keys ─────────────────────────────────────────────────────────────
+
+/// Balance map: (sub-account, token) → balance
+const BALANCES_KEY: Symbol = symbol_short!("ldg_bal");
+
+/// Token index: token → Vec<SubAccount> that have ever held it
+const TOKEN_IDX_KEY: Symbol = symbol_short!("ldg_tidx");
+
+type BalanceKey = (SubAccount, Address);
+
+pub fn get_balance(env: &Env, account: &SubAccount, token: &Address) -> i128 {
+    let balances: Map<BalanceKey, i128> = env
+        .storage()
+        .persistent()
+        .get(&BALANCES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    balances
+        .get((account.clone(), token.clone()))
+        .unwrap_or(0i128)
+}
+
+pub fn set_balance(env: &Env, account: &SubAccount, token: &Address, balance: i128) {
+    let mut balances: Map<BalanceKey, i128> = env
+        .storage()
+        .persistent()
+        .get(&BALANCES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+    balances.set((account.clone(), token.clone()), balance);
+    env.storage().persistent().set(&BALANCES_KEY, &balances);
+
+    let mut index: Map<Address, Vec<SubAccount>> = env
+        .storage()
+        .persistent()
+        .get(&TOKEN_IDX_KEY)
+        .unwrap_or_else(|| Map::new(env));
+    let mut accounts = index.get(token.clone()).unwrap_or_else(|| Vec::new(env));
+    if !accounts.contains(account) {
+        accounts.push_back(account.clone());
+        index.set(token.clone(), accounts);
+        env.storage().persistent().set(&TOKEN_IDX_KEY, &index);
+    }
+}
+
+pub fn get_token_accounts(env: &Env, token: &Address) -> Vec<SubAccount> {
+    let index: Map<Address, Vec<SubAccount>> = env
+        .storage()
+        .persistent()
+        .get(&TOKEN_IDX_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    index.get(token.clone()).unwrap_or_else(|| Vec::new(env))
+}
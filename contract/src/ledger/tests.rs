@@ -0,0 +1,256 @@
+//! Internal Ledger Tests
+//!
+//! Coverage for sub-account segregation and reconciliation of the contract's
+//! real token balance against the sum of its sub-accounts.
+
+use super::*;
+use crate::payment::DistributionRule;
+use crate::StellarGuildsContract;
+use crate::StellarGuildsContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+use soroban_sdk::{token, Address, Env, String};
+
+// ============ Test Helpers ============
+
+fn setup_env() -> Env {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    env
+}
+
+fn set_ledger_timestamp(env: &Env, timestamp: u64) {
+    env.ledger().set(LedgerInfo {
+        timestamp,
+        protocol_version: 20,
+        sequence_number: 0,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 100,
+        min_persistent_entry_ttl: 100,
+        max_entry_ttl: 1000000,
+    });
+}
+
+fn register_and_init_contract(env: &Env) -> Address {
+    let contract_id = env.register_contract(None, StellarGuildsContract);
+    let client = StellarGuildsContractClient::new(env, &contract_id);
+    let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client
+        .mock_all_auths()
+        .initialize(&Address::generate(env), &native.address());
+    contract_id
+}
+
+fn create_mock_token(env: &Env, admin: &Address) -> Address {
+    env.register_stellar_asset_contract_v2(admin.clone())
+        .address()
+}
+
+fn mint_tokens(env: &Env, token: &Address, to: &Address, amount: i128) {
+    token::StellarAssetClient::new(env, token).mint(to, &amount);
+}
+
+/// Deposit into a treasury, fund a bounty and fund a payment pool, all in `token`.
+///
+/// Returns the treasury, bounty and pool ids and the treasury's second signer.
+fn setup_funded_modules(
+    env: &Env,
+    client: &StellarGuildsContractClient<'_>,
+    owner: &Address,
+    token: &Address,
+) -> (u64, u64, u64, Address) {
+    let guild_id = client.create_guild(
+        &String::from_str(env, "Guild"),
+        &String::from_str(env, "Ledger test guild"),
+        owner,
+    );
+
+    let signer = Address::generate(env);
+    let mut signers = soroban_sdk::Vec::new(env);
+    signers.push_back(owner.clone());
+    signers.push_back(signer.clone());
+    let treasury_id = client.initialize_treasury(&guild_id, &signers, &2u32);
+    mint_tokens(env, token, owner, 1000);
+    client.deposit_treasury(&treasury_id, owner, &1000i128, &Some(token.clone()));
+
+    let bounty_id = client.create_bounty(
+        &guild_id,
+        owner,
+        &String::from_str(env, "Task"),
+        &String::from_str(env, "Description"),
+        &300i128,
+        token,
+        &10_000u64,
+    );
+    mint_tokens(env, token, owner, 300);
+    client.fund_bounty(&bounty_id, owner, &300i128);
+
+    let pool_id = client.create_payment_pool(
+        &200i128,
+        &Some(token.clone()),
+        &DistributionRule::EqualSplit,
+        owner,
+    );
+    mint_tokens(env, token, owner, 200);
+    client.fund_payment_pool(&pool_id, owner, &200i128);
+
+    (treasury_id, bounty_id, pool_id, signer)
+}
+
+// ============ Reconciliation Tests ============
+
+#[test]
+fn test_reconcile_sums_every_sub_account() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let (treasury_id, bounty_id, pool_id, _signer) =
+        setup_funded_modules(&env, &client, &owner, &token);
+    let token_opt = Some(token.clone());
+
+    assert_eq!(
+        client.get_sub_account_balance(&SubAccount::Treasury(treasury_id), &token_opt),
+        1000
+    );
+    assert_eq!(
+        client.get_sub_account_balance(&SubAccount::Bounty(bounty_id), &token_opt),
+        300
+    );
+    assert_eq!(
+        client.get_sub_account_balance(&SubAccount::Pool(pool_id), &token_opt),
+        200
+    );
+
+    let report = client.reconcile(&token_opt);
+    assert_eq!(report.actual_balance, 1500);
+    assert_eq!(report.ledger_total, 1500);
+    assert_eq!(report.surplus, 0);
+    assert!(report.balanced);
+    assert_eq!(report.accounts.len(), 3);
+}
+
+#[test]
+fn test_reconcile_stays_balanced_after_payouts() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let (treasury_id, bounty_id, pool_id, signer) =
+        setup_funded_modules(&env, &client, &owner, &token);
+
+    let tx_id = client.propose_withdrawal(
+        &treasury_id,
+        &owner,
+        &recipient,
+        &400i128,
+        &Some(token.clone()),
+        &String::from_str(&env, "payout"),
+    );
+    client.approve_transaction(&tx_id, &signer);
+    client.execute_transaction(&tx_id, &owner);
+
+    client.cancel_bounty(&bounty_id, &owner);
+
+    client.add_recipient(&pool_id, &recipient, &1u32, &owner);
+    client.execute_distribution(&pool_id, &owner);
+
+    let report = client.reconcile(&Some(token.clone()));
+    assert_eq!(report.actual_balance, 600);
+    assert_eq!(report.ledger_total, 600);
+    assert!(report.balanced);
+}
+
+#[test]
+fn test_reconcile_reports_untracked_surplus() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    setup_funded_modules(&env, &client, &owner, &token);
+
+    // Tokens sent straight to the contract belong to no sub-account
+    mint_tokens(&env, &token, &contract_id, 50);
+
+    let report = client.reconcile(&Some(token.clone()));
+    assert_eq!(report.actual_balance, 1550);
+    assert_eq!(report.ledger_total, 1500);
+    assert_eq!(report.surplus, 50);
+    assert!(!report.balanced);
+}
+
+#[test]
+fn test_reconcile_native_xlm() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+    let native = client.get_native_token();
+
+    let guild_id = client.create_guild(
+        &String::from_str(&env, "Guild"),
+        &String::from_str(&env, "Ledger test guild"),
+        &owner,
+    );
+    let mut signers = soroban_sdk::Vec::new(&env);
+    signers.push_back(owner.clone());
+    let treasury_id = client.initialize_treasury(&guild_id, &signers, &1u32);
+    mint_tokens(&env, &native, &owner, 700);
+    client.deposit_treasury(&treasury_id, &owner, &700i128, &None);
+
+    assert_eq!(
+        client.get_sub_account_balance(&SubAccount::Treasury(treasury_id), &None),
+        700
+    );
+    let report = client.reconcile(&None);
+    assert_eq!(report.token, native);
+    assert_eq!(report.ledger_total, 700);
+    assert!(report.balanced);
+}
+
+// ============ Segregation Tests ============
+
+#[test]
+#[should_panic(expected = "insufficient sub-account balance")]
+fn test_sub_account_cannot_spend_other_funds() {
+    let env = setup_env();
+    let owner = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+
+    set_ledger_timestamp(&env, 1000);
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let (_treasury_id, bounty_id, _pool_id, _signer) =
+        setup_funded_modules(&env, &client, &owner, &token);
+
+    // The contract holds 1500 tokens, but the bounty only owns 300 of them
+    env.as_contract(&contract_id, || {
+        withdraw(&env, &SubAccount::Bounty(bounty_id), &token, &owner, 301);
+    });
+}
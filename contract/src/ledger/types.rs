@@ -0,0 +1,50 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// An internal sub-account of the contract's token holdings.
+///
+/// Every token held at the contract address belongs to exactly one
+/// sub-account, so one module can never spend funds owned by another.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SubAccount {
+    /// Funds held by a guild treasury
+    Treasury(u64),
+    /// Escrowed rewards and locked claim bonds of a bounty
+    Bounty(u64),
+    /// Funds awaiting distribution in a payment pool
+    Pool(u64),
+    /// Subscription revenue collected for a guild
+    SubscriptionRevenue(u64),
+    /// Treasury funds reserved for a payment stream
+    Stream(u64),
+    /// Tokens locked in a vesting grant
+    Vesting(u64),
+    /// Anti-spam deposit locked by a governance proposal
+    ProposalDeposit(u64),
+}
+
+/// Balance of a single sub-account in one token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubAccountBalance {
+    pub account: SubAccount,
+    pub balance: i128,
+}
+
+/// Result of reconciling the internal ledger against the real token balance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReconciliationReport {
+    pub token: Address,
+    /// Balance reported by the token contract for the contract address
+    pub actual_balance: i128,
+    /// Sum of all sub-account balances in this token
+    pub ledger_total: i128,
+    /// `actual_balance - ledger_total`; positive when tokens were sent to the
+    /// contract outside of any tracked flow
+    pub surplus: i128,
+    /// Whether the sub-accounts account for the real balance exactly
+    pub balanced: bool,
+    /// Every sub-account that has ever held this token
+    pub accounts: Vec<SubAccountBalance>,
+}
@@ -1,6 +1,6 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Vec};
 
 mod events;
 mod guild;
@@ -12,9 +12,20 @@ use guild::storage;
 use guild::types::{Member, Role};
 
 mod bounty;
+use bounty::recurring::{
+    create_template as bounty_create_template, deactivate_template as bounty_deactivate_template,
+    get_guild_templates_list as bounty_get_guild_templates,
+    get_template_bounties as bounty_get_template_bounties,
+    get_template_data as bounty_get_template, run_schedule as bounty_run_schedule,
+    schedule_template as bounty_schedule_template,
+    spawn_from_template as bounty_spawn_from_template,
+};
+use bounty::types::{BountyReview, BountyTemplate, ClaimBond, ClaimantShare};
 use bounty::{
-    approve_completion, cancel_bounty, claim_bounty, create_bounty, expire_bounty, fund_bounty,
-    get_bounty_data, get_guild_bounties_list, release_escrow, submit_work, Bounty,
+    add_bounty_reward, approve_completion, cancel_bounty, claim_bounty, create_bounty,
+    expire_bounty, fund_bounty, fund_bounty_token, get_bounty_data, get_bounty_reviews,
+    get_guild_bounties_list, release_claim, release_escrow, review_bounty, set_bounty_reviewers,
+    set_bounty_splits, submit_work, Bounty,
 };
 
 mod treasury;
@@ -23,7 +34,35 @@ use treasury::{
     emergency_pause as core_emergency_pause, execute_transaction as core_execute_transaction,
     get_balance as core_get_balance, get_transaction_history as core_get_transaction_history,
     grant_allowance as core_grant_allowance, initialize_treasury as core_initialize_treasury,
-    propose_withdrawal as core_propose_withdrawal, set_budget as core_set_budget, Transaction,
+    propose_add_signer as core_propose_add_signer,
+    propose_batch_withdrawal as core_propose_batch_withdrawal,
+    propose_high_value_threshold as core_propose_high_value_threshold,
+    propose_internal_transfer as core_propose_internal_transfer,
+    propose_remove_signer as core_propose_remove_signer,
+    propose_rotate_signer as core_propose_rotate_signer,
+    propose_threshold_change as core_propose_threshold_change,
+    propose_timelock_change as core_propose_timelock_change,
+    propose_withdrawal as core_propose_withdrawal, reject_transaction as core_reject_transaction,
+    set_budget as core_set_budget, set_timelock as core_set_timelock,
+    veto_transaction as core_veto_transaction, Transaction, Treasury, WithdrawalLeg,
+};
+use treasury::{
+    cancel_stream as core_cancel_stream, get_stream_withdrawable as core_get_stream_withdrawable,
+    pause_stream as core_pause_stream, propose_stream as core_propose_stream,
+    resume_stream as core_resume_stream, withdraw_from_stream as core_withdraw_from_stream,
+    Stream,
+};
+use treasury::{
+    approve_expense_claim as core_approve_expense_claim,
+    get_expense_claims_by_status as core_get_expense_claims_by_status,
+    get_member_expense_claims as core_get_member_expense_claims,
+    reject_expense_claim as core_reject_expense_claim,
+    set_expense_approvers as core_set_expense_approvers,
+    submit_expense_claim as core_submit_expense_claim, ExpenseClaim, ExpenseStatus,
+};
+use treasury::{
+    cancel_payroll as core_cancel_payroll, propose_payroll as core_propose_payroll,
+    run_payroll as core_run_payroll, PayrollEntry, PayrollOrder,
 };
 
 mod analytics;
@@ -43,12 +82,41 @@ use reputation::{
 
 mod governance;
 use governance::{
-    cancel_proposal as gov_cancel_proposal, create_proposal as gov_create_proposal,
-    delegate_vote as gov_delegate_vote, execute_proposal as gov_execute_proposal,
+    cancel_proposal as gov_cancel_proposal,
+    cancel_proposal_for_abuse as gov_cancel_proposal_for_abuse, commit_vote as gov_commit_vote,
+    create_multi_option_proposal as gov_create_multi_option_proposal,
+    create_optimistic_proposal as gov_create_optimistic_proposal,
+    create_private_proposal as gov_create_private_proposal, create_proposal as gov_create_proposal,
+    delegate_vote as gov_delegate_vote, delegate_vote_scoped as gov_delegate_vote_scoped,
+    execute_proposal as gov_execute_proposal,
     finalize_proposal as gov_finalize_proposal, get_active_proposals as gov_get_active_proposals,
-    get_proposal as gov_get_proposal, undelegate_vote as gov_undelegate_vote,
-    update_governance_config as gov_update_governance_config, vote as gov_vote, ExecutionPayload,
-    GovernanceConfig, Proposal, ProposalStatus, ProposalType, VoteDecision,
+    get_all_proposals as gov_get_all_proposals,
+    get_governance_guardians as gov_get_governance_guardians,
+    get_inbound_delegations as gov_get_inbound_delegations,
+    get_outbound_delegations as gov_get_outbound_delegations, get_proposal as gov_get_proposal,
+    get_proposal_objections as gov_get_proposal_objections,
+    get_proposal_option_ballots as gov_get_proposal_option_ballots,
+    get_proposal_vote_commitments as gov_get_proposal_vote_commitments,
+    get_proposal_votes as gov_get_proposal_votes,
+    get_proposals_by_status as gov_get_proposals_by_status,
+    object_to_proposal as gov_object_to_proposal,
+    get_proposal_deposit as gov_get_proposal_deposit,
+    get_proposal_veto as gov_get_proposal_veto,
+    get_proposal_vote_snapshot as gov_get_proposal_vote_snapshot,
+    get_queued_proposals as gov_get_queued_proposals,
+    get_signed_vote_nonce as gov_get_signed_vote_nonce,
+    get_vote_commitment as gov_get_vote_commitment, register_vote_key as gov_register_vote_key,
+    reveal_vote as gov_reveal_vote,
+    set_governance_guardians as gov_set_governance_guardians,
+    signed_vote_payload as gov_signed_vote_payload, submit_signed_votes as gov_submit_signed_votes,
+    undelegate_vote as gov_undelegate_vote, undelegate_vote_scoped as gov_undelegate_vote_scoped,
+    set_proposal_type_voting_mode as gov_set_proposal_type_voting_mode,
+    update_governance_config as gov_update_governance_config, veto_proposal as gov_veto_proposal,
+    vote as gov_vote, vote_commitment as gov_vote_commitment,
+    vote_options as gov_vote_options, vote_quadratic as gov_vote_quadratic, BallotType,
+    Delegation, DelegationScope, DelegationSplit, DepositRule, ExecutionPayload, GovernanceConfig,
+    OptionBallot, Proposal, ProposalDeposit, ProposalOption, ProposalStatus, ProposalType,
+    ProposalVeto, SignedVote, Vote, VoteCommitment, VoteDecision, VoteSnapshot, VotingMode,
 };
 
 mod milestone;
@@ -65,7 +133,8 @@ mod payment;
 use payment::{
     add_recipient as pay_add_recipient, batch_distribute as pay_batch_distribute,
     cancel_distribution as pay_cancel_distribution, create_payment_pool as pay_create_payment_pool,
-    execute_distribution as pay_execute_distribution, get_pool_status as pay_get_pool_status,
+    execute_distribution as pay_execute_distribution, fund_pool as pay_fund_pool,
+    get_pool_status as pay_get_pool_status,
     get_recipient_amount as pay_get_recipient_amount,
     validate_distribution as pay_validate_distribution, DistributionRule, DistributionStatus,
 };
@@ -79,16 +148,30 @@ use subscription::{
     pause_subscription as sub_pause_subscription,
     process_due_subscriptions as sub_process_due_subscriptions,
     process_payment as sub_process_payment, resume_subscription as sub_resume_subscription,
-    retry_payment as sub_retry_payment, subscribe as sub_subscribe, BillingCycle, MembershipTier,
+    retry_payment as sub_retry_payment, subscribe as sub_subscribe,
+    sweep_revenue as sub_sweep_revenue, BillingCycle, MembershipTier,
     ProrationResult, Subscription, SubscriptionChange, SubscriptionError, SubscriptionPlan,
     SubscriptionStatus,
 };
 
+mod ledger;
+use ledger::{ReconciliationReport, SubAccount};
+
+mod vesting;
+use vesting::{
+    claim_vested as core_claim_vested, create_grant as core_create_grant,
+    get_vesting_summary as core_get_vesting_summary,
+    propose_treasury_grant as core_propose_treasury_grant, revoke_grant as core_revoke_grant,
+    VestingGrant, VestingSchedule, VestingSummary,
+};
+
 mod dispute;
 use dispute::{
     calculate_vote_weight as dispute_calculate_vote_weight, cast_vote as dispute_cast_vote,
-    create_dispute as dispute_create_dispute, execute_resolution as dispute_execute_resolution,
-    resolve_dispute as dispute_resolve_dispute, submit_evidence as dispute_submit_evidence,
+    commit_vote as dispute_commit_vote, create_dispute as dispute_create_dispute,
+    create_private_dispute as dispute_create_private_dispute,
+    execute_resolution as dispute_execute_resolution, resolve_dispute as dispute_resolve_dispute,
+    reveal_vote as dispute_reveal_vote, submit_evidence as dispute_submit_evidence,
     tally_votes as dispute_tally_votes,
 };
 
@@ -173,12 +256,34 @@ pub struct StellarGuildsContract;
 
 #[contractimpl]
 impl StellarGuildsContract {
-    pub fn initialize(env: Env) -> bool {
+    /// Initialize contract storage. Can only be called once.
+    ///
+    /// # Arguments
+    /// * `admin` - Contract admin, must authorize the call
+    /// * `native_token` - Stellar Asset Contract address of native XLM, used for
+    ///   every money flow whose token is `None`
+    pub fn initialize(env: Env, admin: Address, native_token: Address) -> bool {
+        admin.require_auth();
+        if storage::is_initialized(&env) {
+            panic!("contract already initialized");
+        }
         storage::initialize(&env);
+        storage::set_admin(&env, &admin);
+        storage::set_native_token(&env, &native_token);
         subscription::storage::initialize_subscription_storage(&env);
         true
     }
 
+    /// Get the Stellar Asset Contract address used for native XLM
+    pub fn get_native_token(env: Env) -> Address {
+        storage::get_native_token(&env)
+    }
+
+    /// Get the contract admin set at initialization
+    pub fn get_admin(env: Env) -> Address {
+        storage::get_admin(&env)
+    }
+
     /// Get contract version
     pub fn version(_env: Env) -> String {
         String::from_str(&_env, "0.1.0")
@@ -348,6 +453,33 @@ impl StellarGuildsContract {
         }
     }
 
+    /// Deposit funds into a payment pool
+    ///
+    /// # Returns
+    /// The total amount the pool now holds
+    pub fn fund_payment_pool(env: Env, pool_id: u64, funder: Address, amount: i128) -> i128 {
+        match pay_fund_pool(&env, pool_id, funder, amount) {
+            Ok(total) => total,
+            Err(e) => {
+                let msg = match e as u32 {
+                    1 => "PoolNotFound",
+                    2 => "PoolNotPending",
+                    3 => "Unauthorized",
+                    4 => "InvalidShare",
+                    5 => "DuplicateRecipient",
+                    6 => "SharesNot100Percent",
+                    7 => "NoRecipients",
+                    8 => "InsufficientBalance",
+                    9 => "TransferFailed",
+                    10 => "ArithmeticOverflow",
+                    11 => "InvalidAmount",
+                    _ => "Unknown error",
+                };
+                panic!("{}", msg);
+            }
+        }
+    }
+
     pub fn add_recipient(
         env: Env,
         pool_id: u64,
@@ -543,6 +675,35 @@ impl StellarGuildsContract {
         )
     }
 
+    /// Create a dispute whose jurors vote by commit-reveal
+    ///
+    /// # Arguments
+    /// * `reference_id` - Bounty or milestone ID
+    /// * `plaintiff` - Address opening the dispute
+    /// * `defendant` - Address responding to the dispute
+    /// * `reason` - Dispute reason
+    /// * `evidence_url` - Initial evidence URL
+    ///
+    /// # Returns
+    /// The ID of the newly created dispute
+    pub fn create_private_dispute(
+        env: Env,
+        reference_id: u64,
+        plaintiff: Address,
+        defendant: Address,
+        reason: String,
+        evidence_url: String,
+    ) -> u64 {
+        dispute_create_private_dispute(
+            &env,
+            reference_id,
+            plaintiff,
+            defendant,
+            reason,
+            evidence_url,
+        )
+    }
+
     /// Submit evidence for an active dispute
     pub fn submit_evidence(
         env: Env,
@@ -563,6 +724,37 @@ impl StellarGuildsContract {
         dispute_cast_vote(&env, dispute_id, voter, decision)
     }
 
+    /// Commit a sealed vote on a commit-reveal dispute
+    pub fn commit_dispute_vote(
+        env: Env,
+        dispute_id: u64,
+        voter: Address,
+        commitment: BytesN<32>,
+    ) -> bool {
+        dispute_commit_vote(&env, dispute_id, voter, commitment)
+    }
+
+    /// Reveal a sealed dispute vote after the voting deadline
+    pub fn reveal_dispute_vote(
+        env: Env,
+        dispute_id: u64,
+        voter: Address,
+        decision: dispute::types::VoteDecision,
+        salt: BytesN<32>,
+    ) -> bool {
+        dispute_reveal_vote(&env, dispute_id, voter, decision, salt)
+    }
+
+    /// Compute the commitment a juror submits for a sealed dispute vote
+    pub fn compute_dispute_vote_commitment(
+        env: Env,
+        voter: Address,
+        decision: dispute::types::VoteDecision,
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        gov_vote_commitment(&env, &voter, decision.code(), &salt)
+    }
+
     /// Calculate voting weight for a guild member
     pub fn calculate_dispute_vote_weight(env: Env, guild_id: u64, voter: Address) -> u32 {
         dispute_calculate_vote_weight(&env, guild_id, voter)
@@ -592,7 +784,8 @@ impl StellarGuildsContract {
     ///
     /// # Arguments
     /// * `guild_id` - The ID of the guild
-    /// * `signers` - Vector of signer addresses (first is owner)
+    /// * `signers` - Vector of signer addresses (first is owner, who must be a
+    ///   guild owner or admin)
     /// * `approval_threshold` - Number of approvals required for transactions
     ///
     /// # Returns
@@ -658,6 +851,65 @@ impl StellarGuildsContract {
         )
     }
 
+    /// Propose a transfer between two treasuries of the same guild
+    ///
+    /// Needs the full approval threshold of the sending treasury. Balances
+    /// move without a token round-trip and the transfer is recorded in both
+    /// histories.
+    ///
+    /// # Arguments
+    /// * `from_treasury_id` - The ID of the sending treasury
+    /// * `to_treasury_id` - The ID of the receiving treasury
+    /// * `proposer` - Signer of the sending treasury
+    /// * `amount` - Amount to transfer
+    /// * `token` - Token address (None for XLM)
+    /// * `reason` - Reason for the transfer
+    ///
+    /// # Returns
+    /// The ID of the proposed transaction
+    pub fn propose_internal_transfer(
+        env: Env,
+        from_treasury_id: u64,
+        to_treasury_id: u64,
+        proposer: Address,
+        amount: i128,
+        token: Option<Address>,
+        reason: String,
+    ) -> u64 {
+        core_propose_internal_transfer(
+            &env,
+            from_treasury_id,
+            to_treasury_id,
+            proposer,
+            amount,
+            token,
+            reason,
+        )
+    }
+
+    /// Propose a batch withdrawal paying every leg in one transaction
+    ///
+    /// The batch is approved once and executed atomically: if any leg cannot
+    /// be paid, none are.
+    ///
+    /// # Arguments
+    /// * `treasury_id` - The ID of the treasury
+    /// * `proposer` - Address proposing the batch
+    /// * `legs` - Recipient, token and amount of each payment
+    /// * `reason` - Reason for the batch
+    ///
+    /// # Returns
+    /// The ID of the proposed transaction
+    pub fn propose_batch_withdrawal(
+        env: Env,
+        treasury_id: u64,
+        proposer: Address,
+        legs: Vec<WithdrawalLeg>,
+        reason: String,
+    ) -> u64 {
+        core_propose_batch_withdrawal(&env, treasury_id, proposer, legs, reason)
+    }
+
     /// Approve a proposed transaction
     ///
     /// # Arguments
@@ -682,109 +934,607 @@ impl StellarGuildsContract {
         core_execute_transaction(&env, tx_id, executor)
     }
 
-    /// Set a budget for a treasury category
+    /// Raise the timelock applied to high-value treasury transactions.
+    /// Reductions go through `propose_timelock_change`.
     ///
     /// # Arguments
     /// * `treasury_id` - The ID of the treasury
-    /// * `category` - Budget category name
-    /// * `amount` - Budget amount
-    /// * `period_seconds` - Budget period in seconds
-    /// * `caller` - Address making the request (must be signer)
+    /// * `caller` - Address making the request (must be owner)
+    /// * `timelock_seconds` - Delay between approval and execution
     ///
     /// # Returns
-    /// `true` if budget was set successfully
-    pub fn set_budget(
+    /// `true` if the timelock was updated
+    pub fn set_treasury_timelock(
         env: Env,
         treasury_id: u64,
-        category: String,
-        amount: i128,
-        period_seconds: u64,
         caller: Address,
+        timelock_seconds: u64,
     ) -> bool {
-        core_set_budget(&env, treasury_id, caller, category, amount, period_seconds)
+        core_set_timelock(&env, treasury_id, caller, timelock_seconds)
     }
 
-    /// Get treasury balance for a token
+    /// Veto an approved transaction during its timelock window
     ///
     /// # Arguments
-    /// * `treasury_id` - The ID of the treasury
-    /// * `token` - Token address (None for XLM)
+    /// * `tx_id` - The ID of the timelocked transaction
+    /// * `signer` - Address vetoing the transaction (must be signer)
     ///
     /// # Returns
-    /// The balance amount
-    pub fn get_treasury_balance(env: Env, treasury_id: u64, token: Option<Address>) -> i128 {
-        core_get_balance(&env, treasury_id, token)
+    /// `true` if the transaction was vetoed
+    pub fn veto_transaction(env: Env, tx_id: u64, signer: Address) -> bool {
+        core_veto_transaction(&env, tx_id, signer)
     }
 
-    /// Get transaction history for a treasury
+    /// Reject a pending transaction
+    ///
+    /// The transaction is closed as `Rejected` once the required approvals can
+    /// no longer be reached.
     ///
     /// # Arguments
-    /// * `treasury_id` - The ID of the treasury
-    /// * `limit` - Maximum number of transactions to return
+    /// * `tx_id` - The ID of the transaction to reject
+    /// * `signer` - Address rejecting the transaction (must be signer)
     ///
     /// # Returns
-    /// Vector of transactions
-    pub fn get_transaction_history(env: Env, treasury_id: u64, limit: u32) -> Vec<Transaction> {
-        core_get_transaction_history(&env, treasury_id, limit)
+    /// `true` if the rejection was recorded
+    pub fn reject_transaction(env: Env, tx_id: u64, signer: Address) -> bool {
+        core_reject_transaction(&env, tx_id, signer)
     }
 
-    /// Grant an allowance to an admin
+    /// Propose adding a treasury signer (requires threshold approval)
+    ///
+    /// # Returns
+    /// The ID of the configuration transaction
+    pub fn propose_add_signer(
+        env: Env,
+        treasury_id: u64,
+        proposer: Address,
+        new_signer: Address,
+    ) -> u64 {
+        core_propose_add_signer(&env, treasury_id, proposer, new_signer)
+    }
+
+    /// Propose removing a treasury signer (requires threshold approval)
+    ///
+    /// # Returns
+    /// The ID of the configuration transaction
+    pub fn propose_remove_signer(
+        env: Env,
+        treasury_id: u64,
+        proposer: Address,
+        signer: Address,
+    ) -> u64 {
+        core_propose_remove_signer(&env, treasury_id, proposer, signer)
+    }
+
+    /// Propose replacing one treasury signer with another (requires threshold approval)
+    ///
+    /// # Returns
+    /// The ID of the configuration transaction
+    pub fn propose_rotate_signer(
+        env: Env,
+        treasury_id: u64,
+        proposer: Address,
+        old_signer: Address,
+        new_signer: Address,
+    ) -> u64 {
+        core_propose_rotate_signer(&env, treasury_id, proposer, old_signer, new_signer)
+    }
+
+    /// Propose a new approval threshold (requires threshold approval)
+    ///
+    /// # Returns
+    /// The ID of the configuration transaction
+    pub fn propose_threshold_change(
+        env: Env,
+        treasury_id: u64,
+        proposer: Address,
+        new_threshold: u32,
+    ) -> u64 {
+        core_propose_threshold_change(&env, treasury_id, proposer, new_threshold)
+    }
+
+    /// Propose a new high-value timelock (requires threshold approval)
+    ///
+    /// # Arguments
+    /// * `timelock_seconds` - Delay between approval and execution (0 disables)
+    ///
+    /// # Returns
+    /// The ID of the configuration transaction
+    pub fn propose_timelock_change(
+        env: Env,
+        treasury_id: u64,
+        proposer: Address,
+        timelock_seconds: u64,
+    ) -> u64 {
+        core_propose_timelock_change(&env, treasury_id, proposer, timelock_seconds)
+    }
+
+    /// Propose a new high-value threshold for a token (requires threshold approval)
     ///
     /// # Arguments
-    /// * `treasury_id` - The ID of the treasury
-    /// * `admin` - Address to grant allowance to
-    /// * `amount` - Allowance amount per period
     /// * `token` - Token address (None for XLM)
-    /// * `period_seconds` - Allowance period in seconds
-    /// * `owner` - Treasury owner making the request
+    /// * `threshold` - Amount at or above which outflows need full approval
     ///
     /// # Returns
-    /// `true` if allowance was granted successfully
-    pub fn grant_allowance(
+    /// The ID of the configuration transaction
+    pub fn propose_high_value_threshold(
         env: Env,
         treasury_id: u64,
-        admin: Address,
-        amount: i128,
+        proposer: Address,
         token: Option<Address>,
-        period_seconds: u64,
-        owner: Address,
-    ) -> bool {
-        core_grant_allowance(
+        threshold: i128,
+    ) -> u64 {
+        core_propose_high_value_threshold(&env, treasury_id, proposer, token, threshold)
+    }
+
+    /// Get a treasury's configuration and balances
+    pub fn get_treasury(env: Env, treasury_id: u64) -> Treasury {
+        treasury::storage::get_treasury(&env, treasury_id).expect("treasury not found")
+    }
+
+    /// Propose a treasury-funded payment stream
+    ///
+    /// Creates a `StreamFunding` transaction for `rate_per_second * (end_time - start_time)`.
+    /// The stream starts accruing once that transaction is approved and executed.
+    ///
+    /// # Arguments
+    /// * `treasury_id` - The ID of the treasury
+    /// * `proposer` - Address proposing the stream (must be signer)
+    /// * `recipient` - Address receiving the stream
+    /// * `token` - Token address (None for native XLM)
+    /// * `rate_per_second` - Amount accrued per second
+    /// * `start_time` - Timestamp accrual starts from
+    /// * `end_time` - Timestamp accrual ends at
+    /// * `cliff_time` - Timestamp before which nothing can be withdrawn
+    ///
+    /// # Returns
+    /// The ID of the new stream
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_stream(
+        env: Env,
+        treasury_id: u64,
+        proposer: Address,
+        recipient: Address,
+        token: Option<Address>,
+        rate_per_second: i128,
+        start_time: u64,
+        end_time: u64,
+        cliff_time: u64,
+    ) -> u64 {
+        core_propose_stream(
             &env,
             treasury_id,
-            owner,
-            admin,
-            amount,
+            proposer,
+            recipient,
             token,
-            period_seconds,
+            rate_per_second,
+            start_time,
+            end_time,
+            cliff_time,
         )
     }
 
-    /// Emergency pause treasury operations
+    /// Withdraw the amount a stream has accrued so far
     ///
     /// # Arguments
-    /// * `treasury_id` - The ID of the treasury
-    /// * `signer` - Address making the request (must be signer)
-    /// * `paused` - Whether to pause or unpause
+    /// * `stream_id` - The ID of the stream
+    /// * `recipient` - The stream's recipient
     ///
     /// # Returns
-    /// `true` if pause state was changed successfully
-    pub fn emergency_pause(env: Env, treasury_id: u64, signer: Address, paused: bool) -> bool {
-        core_emergency_pause(&env, treasury_id, signer, paused)
+    /// The amount paid out
+    pub fn withdraw_from_stream(env: Env, stream_id: u64, recipient: Address) -> i128 {
+        core_withdraw_from_stream(&env, stream_id, recipient)
     }
 
-    // ============ Token Allowance Functions ============
+    /// Pause accrual of a stream (any treasury signer)
+    pub fn pause_stream(env: Env, stream_id: u64, signer: Address) -> bool {
+        core_pause_stream(&env, stream_id, signer)
+    }
 
-    /// Approve a token allowance from owner to spender.
+    /// Resume a paused stream (any treasury signer)
+    pub fn resume_stream(env: Env, stream_id: u64, signer: Address) -> bool {
+        core_resume_stream(&env, stream_id, signer)
+    }
+
+    /// Cancel a stream (any treasury signer)
     ///
-    /// Creates or replaces an allowance with optional expiration and
-    /// per-operation granularity.
+    /// Accrued but unclaimed funds are paid to the recipient and the
+    /// unstreamed remainder returns to the treasury.
+    pub fn cancel_stream(env: Env, stream_id: u64, signer: Address) -> bool {
+        core_cancel_stream(&env, stream_id, signer)
+    }
+
+    /// Get a stream by ID
+    pub fn get_stream(env: Env, stream_id: u64) -> Stream {
+        treasury::storage::get_stream(&env, stream_id).expect("stream not found")
+    }
+
+    /// Get the amount a stream's recipient can withdraw right now
+    pub fn get_stream_withdrawable(env: Env, stream_id: u64) -> i128 {
+        core_get_stream_withdrawable(&env, stream_id)
+    }
+
+    /// Get all streams of a treasury
+    pub fn get_treasury_streams(env: Env, treasury_id: u64) -> Vec<Stream> {
+        treasury::storage::get_treasury_streams(&env, treasury_id)
+    }
+
+    /// Propose a recurring payroll order
+    ///
+    /// The order is approved once through a `PayrollOrder` transaction that
+    /// needs the full approval threshold; installments are then paid by the
+    /// `run_payroll` keeper under the "payroll" budget category.
     ///
     /// # Arguments
-    /// * `owner` - Address granting the allowance (requires auth)
-    /// * `spender` - Address permitted to spend
-    /// * `token` - Token address (None for XLM)
-    /// * `amount` - Maximum spendable amount
+    /// * `treasury_id` - The ID of the treasury
+    /// * `proposer` - Address proposing the order (must be signer)
+    /// * `entries` - Recipients and their per-installment amounts
+    /// * `token` - Token address (None for native XLM)
+    /// * `interval_seconds` - Time between installments
+    /// * `first_payment_at` - Due time of the first installment
+    /// * `end_time` - No installment is due after this timestamp
+    ///
+    /// # Returns
+    /// The ID of the payroll order
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_payroll(
+        env: Env,
+        treasury_id: u64,
+        proposer: Address,
+        entries: Vec<PayrollEntry>,
+        token: Option<Address>,
+        interval_seconds: u64,
+        first_payment_at: u64,
+        end_time: u64,
+    ) -> u64 {
+        core_propose_payroll(
+            &env,
+            treasury_id,
+            proposer,
+            entries,
+            token,
+            interval_seconds,
+            first_payment_at,
+            end_time,
+        )
+    }
+
+    /// Keeper entrypoint: retry failed payouts and pay the next due installment
+    ///
+    /// # Arguments
+    /// * `order_id` - The ID of the payroll order
+    ///
+    /// # Returns
+    /// The number of payouts made
+    pub fn run_payroll(env: Env, order_id: u64) -> u32 {
+        core_run_payroll(&env, order_id)
+    }
+
+    /// Cancel a payroll order (any treasury signer)
+    pub fn cancel_payroll(env: Env, order_id: u64, signer: Address) -> bool {
+        core_cancel_payroll(&env, order_id, signer)
+    }
+
+    /// Get a payroll order by ID
+    pub fn get_payroll_order(env: Env, order_id: u64) -> PayrollOrder {
+        treasury::storage::get_payroll(&env, order_id).expect("payroll order not found")
+    }
+
+    /// Get all payroll orders of a treasury
+    pub fn get_treasury_payroll_orders(env: Env, treasury_id: u64) -> Vec<PayrollOrder> {
+        treasury::storage::get_treasury_payrolls(&env, treasury_id)
+    }
+
+    /// Set the addresses that may review expense claims besides the signers
+    /// (treasury owner only)
+    pub fn set_expense_approvers(
+        env: Env,
+        treasury_id: u64,
+        caller: Address,
+        approvers: Vec<Address>,
+    ) -> bool {
+        core_set_expense_approvers(&env, treasury_id, caller, approvers)
+    }
+
+    /// Submit an expense reimbursement claim against a treasury
+    ///
+    /// # Arguments
+    /// * `treasury_id` - The ID of the treasury
+    /// * `claimant` - Guild member asking to be reimbursed
+    /// * `amount` - Amount to reimburse
+    /// * `token` - Token address (None for XLM)
+    /// * `category` - Budget category the payout is charged to
+    /// * `receipt_hash` - Hash of the off-chain receipt
+    /// * `description` - What the expense was for
+    ///
+    /// # Returns
+    /// The ID of the claim
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_expense_claim(
+        env: Env,
+        treasury_id: u64,
+        claimant: Address,
+        amount: i128,
+        token: Option<Address>,
+        category: String,
+        receipt_hash: BytesN<32>,
+        description: String,
+    ) -> u64 {
+        core_submit_expense_claim(
+            &env,
+            treasury_id,
+            claimant,
+            amount,
+            token,
+            category,
+            receipt_hash,
+            description,
+        )
+    }
+
+    /// Approve an expense claim and pay it from the treasury
+    ///
+    /// # Returns
+    /// The ID of the withdrawal transaction paying the claim
+    pub fn approve_expense_claim(env: Env, claim_id: u64, approver: Address) -> u64 {
+        core_approve_expense_claim(&env, claim_id, approver)
+    }
+
+    /// Reject an expense claim
+    pub fn reject_expense_claim(env: Env, claim_id: u64, approver: Address) -> bool {
+        core_reject_expense_claim(&env, claim_id, approver)
+    }
+
+    /// Get an expense claim by ID
+    pub fn get_expense_claim(env: Env, claim_id: u64) -> ExpenseClaim {
+        treasury::storage::get_expense(&env, claim_id).expect("expense claim not found")
+    }
+
+    /// Get all expense claims submitted by a member
+    pub fn get_member_expense_claims(env: Env, member: Address) -> Vec<ExpenseClaim> {
+        core_get_member_expense_claims(&env, member)
+    }
+
+    /// Get the expense claims of a treasury with the given status
+    pub fn get_expense_claims_by_status(
+        env: Env,
+        treasury_id: u64,
+        status: ExpenseStatus,
+    ) -> Vec<ExpenseClaim> {
+        core_get_expense_claims_by_status(&env, treasury_id, status)
+    }
+
+    /// Set a budget for a treasury category
+    ///
+    /// # Arguments
+    /// * `treasury_id` - The ID of the treasury
+    /// * `category` - Budget category name
+    /// * `amount` - Budget amount
+    /// * `period_seconds` - Budget period in seconds
+    /// * `caller` - Address making the request (must be signer)
+    ///
+    /// # Returns
+    /// `true` if budget was set successfully
+    pub fn set_budget(
+        env: Env,
+        treasury_id: u64,
+        category: String,
+        amount: i128,
+        period_seconds: u64,
+        caller: Address,
+    ) -> bool {
+        core_set_budget(&env, treasury_id, caller, category, amount, period_seconds)
+    }
+
+    /// Get treasury balance for a token
+    ///
+    /// # Arguments
+    /// * `treasury_id` - The ID of the treasury
+    /// * `token` - Token address (None for XLM)
+    ///
+    /// # Returns
+    /// The balance amount
+    pub fn get_treasury_balance(env: Env, treasury_id: u64, token: Option<Address>) -> i128 {
+        core_get_balance(&env, treasury_id, token)
+    }
+
+    /// Get transaction history for a treasury
+    ///
+    /// # Arguments
+    /// * `treasury_id` - The ID of the treasury
+    /// * `limit` - Maximum number of transactions to return
+    ///
+    /// # Returns
+    /// Vector of transactions
+    pub fn get_transaction_history(env: Env, treasury_id: u64, limit: u32) -> Vec<Transaction> {
+        core_get_transaction_history(&env, treasury_id, limit)
+    }
+
+    /// Grant an allowance to an admin
+    ///
+    /// # Arguments
+    /// * `treasury_id` - The ID of the treasury
+    /// * `admin` - Address to grant allowance to
+    /// * `amount` - Allowance amount per period
+    /// * `token` - Token address (None for XLM)
+    /// * `period_seconds` - Allowance period in seconds
+    /// * `owner` - Treasury owner making the request
+    ///
+    /// # Returns
+    /// `true` if allowance was granted successfully
+    pub fn grant_allowance(
+        env: Env,
+        treasury_id: u64,
+        admin: Address,
+        amount: i128,
+        token: Option<Address>,
+        period_seconds: u64,
+        owner: Address,
+    ) -> bool {
+        core_grant_allowance(
+            &env,
+            treasury_id,
+            owner,
+            admin,
+            amount,
+            token,
+            period_seconds,
+        )
+    }
+
+    /// Emergency pause treasury operations
+    ///
+    /// # Arguments
+    /// * `treasury_id` - The ID of the treasury
+    /// * `signer` - Address making the request (must be signer)
+    /// * `paused` - Whether to pause or unpause
+    ///
+    /// # Returns
+    /// `true` if pause state was changed successfully
+    pub fn emergency_pause(env: Env, treasury_id: u64, signer: Address, paused: bool) -> bool {
+        core_emergency_pause(&env, treasury_id, signer, paused)
+    }
+
+    // ============ Vesting Functions ============
+
+    /// Lock tokens from a funder in a vesting grant
+    ///
+    /// # Arguments
+    /// * `funder` - Address providing the tokens
+    /// * `beneficiary` - Address the tokens vest to
+    /// * `token` - Token address (None for native XLM)
+    /// * `total_amount` - Amount locked in the grant
+    /// * `schedule` - Cliff plus linear vesting schedule
+    /// * `revocable` - Whether the funder may revoke unvested tokens
+    ///
+    /// # Returns
+    /// The ID of the new grant
+    pub fn create_vesting_grant(
+        env: Env,
+        funder: Address,
+        beneficiary: Address,
+        token: Option<Address>,
+        total_amount: i128,
+        schedule: VestingSchedule,
+        revocable: bool,
+    ) -> u64 {
+        core_create_grant(
+            &env,
+            funder,
+            beneficiary,
+            token,
+            total_amount,
+            schedule,
+            revocable,
+        )
+    }
+
+    /// Propose a vesting grant funded by a treasury
+    ///
+    /// The grant starts vesting once its `VestingFunding` transaction is
+    /// approved and executed.
+    ///
+    /// # Arguments
+    /// * `treasury_id` - The ID of the treasury
+    /// * `proposer` - Address proposing the grant (must be signer)
+    /// * `beneficiary` - Address the tokens vest to
+    /// * `token` - Token address (None for native XLM)
+    /// * `total_amount` - Amount locked in the grant
+    /// * `schedule` - Cliff plus linear vesting schedule
+    /// * `revocable` - Whether signers may revoke unvested tokens
+    ///
+    /// # Returns
+    /// The ID of the new grant
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_treasury_vesting_grant(
+        env: Env,
+        treasury_id: u64,
+        proposer: Address,
+        beneficiary: Address,
+        token: Option<Address>,
+        total_amount: i128,
+        schedule: VestingSchedule,
+        revocable: bool,
+    ) -> u64 {
+        core_propose_treasury_grant(
+            &env,
+            treasury_id,
+            proposer,
+            beneficiary,
+            token,
+            total_amount,
+            schedule,
+            revocable,
+        )
+    }
+
+    /// Claim all vested but unclaimed tokens of a grant
+    ///
+    /// # Returns
+    /// The amount released to the beneficiary
+    pub fn claim_vested(env: Env, grant_id: u64, beneficiary: Address) -> i128 {
+        core_claim_vested(&env, grant_id, beneficiary)
+    }
+
+    /// Revoke a grant, returning unvested tokens to its treasury or funder
+    ///
+    /// # Returns
+    /// The amount returned
+    pub fn revoke_vesting_grant(env: Env, grant_id: u64, caller: Address) -> i128 {
+        core_revoke_grant(&env, grant_id, caller)
+    }
+
+    /// Get a vesting grant by ID
+    pub fn get_vesting_grant(env: Env, grant_id: u64) -> VestingGrant {
+        vesting::storage::get_grant(&env, grant_id).expect("grant not found")
+    }
+
+    /// Get the vested, claimed and remaining amounts of a grant
+    pub fn get_vesting_summary(env: Env, grant_id: u64) -> VestingSummary {
+        core_get_vesting_summary(&env, grant_id)
+    }
+
+    /// Get all grants of a beneficiary
+    pub fn get_beneficiary_grants(env: Env, beneficiary: Address) -> Vec<VestingGrant> {
+        vesting::storage::get_beneficiary_grants(&env, &beneficiary)
+    }
+
+    // ============ Ledger Functions ============
+
+    /// Get the balance of an internal sub-account in a token
+    ///
+    /// # Arguments
+    /// * `account` - The sub-account (treasury, bounty, pool, stream, vesting grant, ...)
+    /// * `token` - Token address (None for native XLM)
+    pub fn get_sub_account_balance(env: Env, account: SubAccount, token: Option<Address>) -> i128 {
+        let token = storage::resolve_token(&env, &token);
+        ledger::balance(&env, &account, &token)
+    }
+
+    /// Reconcile the internal ledger against the contract's real token balance
+    ///
+    /// # Arguments
+    /// * `token` - Token address (None for native XLM)
+    ///
+    /// # Returns
+    /// Every sub-account holding the token, their sum and the real balance
+    pub fn reconcile(env: Env, token: Option<Address>) -> ReconciliationReport {
+        let token = storage::resolve_token(&env, &token);
+        ledger::reconcile(&env, &token)
+    }
+
+    // ============ Token Allowance Functions ============
+
+    /// Approve a token allowance from owner to spender.
+    ///
+    /// Creates or replaces an allowance with optional expiration and
+    /// per-operation granularity.
+    ///
+    /// # Arguments
+    /// * `owner` - Address granting the allowance (requires auth)
+    /// * `spender` - Address permitted to spend
+    /// * `token` - Token address (None for XLM)
+    /// * `amount` - Maximum spendable amount
     /// * `expires_at` - Ledger timestamp expiry (0 = no expiry)
     /// * `operation` - Operation type filter
     pub fn approve_token_allowance(
@@ -1071,6 +1821,7 @@ impl StellarGuildsContract {
     ///
     /// # Returns
     /// The ID of the newly created project
+    #[allow(clippy::too_many_arguments)]
     pub fn create_project(
         env: Env,
         guild_id: u64,
@@ -1274,27 +2025,286 @@ impl StellarGuildsContract {
         )
     }
 
-    /// Get a proposal by ID
-    ///
-    /// # Arguments
-    /// * `proposal_id` - The ID of the proposal
-    ///
-    /// # Returns
-    /// The Proposal struct
-    pub fn get_proposal(env: Env, proposal_id: u64) -> Proposal {
-        gov_get_proposal(&env, proposal_id)
-    }
-
-    /// Get all active proposals for a guild
+    /// Create a proposal voted by commit-reveal, keeping votes sealed until
+    /// voting closes
     ///
     /// # Arguments
     /// * `guild_id` - The ID of the guild
+    /// * `proposer` - Address of the proposer
+    /// * `proposal_type` - Type of the proposal
+    /// * `title` - Proposal title
+    /// * `description` - Detailed description
     ///
     /// # Returns
-    /// Vector of active proposals
-    pub fn get_active_proposals(env: Env, guild_id: u64) -> Vec<Proposal> {
-        gov_get_active_proposals(&env, guild_id)
-    }
+    /// The ID of the newly created proposal
+    pub fn create_private_proposal(
+        env: Env,
+        guild_id: u64,
+        proposer: Address,
+        proposal_type: ProposalType,
+        title: String,
+        description: String,
+    ) -> u64 {
+        gov_create_private_proposal(
+            &env,
+            guild_id,
+            proposer,
+            proposal_type,
+            title,
+            description,
+            ExecutionPayload::GeneralDecision,
+        )
+    }
+
+    /// Post an optimistic proposal that passes when its objection window
+    /// ends unless enough members object
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `proposer` - Address of the proposer; must be an owner or admin
+    /// * `proposal_type` - Type of the proposal
+    /// * `title` - Proposal title
+    /// * `description` - Detailed description
+    ///
+    /// # Returns
+    /// The ID of the newly created proposal
+    pub fn create_optimistic_proposal(
+        env: Env,
+        guild_id: u64,
+        proposer: Address,
+        proposal_type: ProposalType,
+        title: String,
+        description: String,
+    ) -> u64 {
+        gov_create_optimistic_proposal(
+            &env,
+            guild_id,
+            proposer,
+            proposal_type,
+            title,
+            description,
+            ExecutionPayload::GeneralDecision,
+        )
+    }
+
+    /// Object to an optimistic proposal during its objection window
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    /// * `objector` - Address of the objecting member
+    ///
+    /// # Returns
+    /// `true` if the objections turned the proposal into a full vote
+    pub fn object_to_proposal(env: Env, proposal_id: u64, objector: Address) -> bool {
+        gov_object_to_proposal(&env, proposal_id, objector)
+    }
+
+    /// Get the members who objected to an optimistic proposal
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// The objectors' addresses
+    pub fn get_proposal_objections(env: Env, proposal_id: u64) -> Vec<Address> {
+        gov_get_proposal_objections(&env, proposal_id)
+    }
+
+    /// Create a proposal that vetoes a timelocked treasury transaction when executed
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild owning the treasury
+    /// * `proposer` - Address creating the proposal
+    /// * `tx_id` - The treasury transaction to veto
+    /// * `title` - Proposal title
+    /// * `description` - Proposal description
+    ///
+    /// # Returns
+    /// The ID of the created proposal
+    pub fn propose_treasury_veto(
+        env: Env,
+        guild_id: u64,
+        proposer: Address,
+        tx_id: u64,
+        title: String,
+        description: String,
+    ) -> u64 {
+        gov_create_proposal(
+            &env,
+            guild_id,
+            proposer,
+            ProposalType::TreasuryVeto,
+            title,
+            description,
+            ExecutionPayload::TreasuryVeto(tx_id),
+        )
+    }
+
+    /// Create a proposal choosing between several labelled options
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `proposer` - Address of the proposer
+    /// * `title` - Proposal title
+    /// * `description` - Detailed description
+    /// * `ballot_type` - Single choice, approval or ranked choice
+    /// * `options` - 2 to 10 options, each with the action run if it wins
+    ///
+    /// # Returns
+    /// The ID of the newly created proposal
+    pub fn create_multi_option_proposal(
+        env: Env,
+        guild_id: u64,
+        proposer: Address,
+        title: String,
+        description: String,
+        ballot_type: BallotType,
+        options: Vec<ProposalOption>,
+    ) -> u64 {
+        gov_create_multi_option_proposal(
+            &env,
+            guild_id,
+            proposer,
+            title,
+            description,
+            ballot_type,
+            options,
+        )
+    }
+
+    /// Create a rule change proposal replacing the guild's proposal deposit
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `proposer` - Address creating the proposal
+    /// * `rule` - New deposit amount, token and forfeit treasury
+    /// * `title` - Proposal title
+    /// * `description` - Proposal description
+    ///
+    /// # Returns
+    /// The ID of the created proposal
+    pub fn propose_deposit_rule_change(
+        env: Env,
+        guild_id: u64,
+        proposer: Address,
+        rule: DepositRule,
+        title: String,
+        description: String,
+    ) -> u64 {
+        gov_create_proposal(
+            &env,
+            guild_id,
+            proposer,
+            ProposalType::RuleChange,
+            title,
+            description,
+            ExecutionPayload::DepositRuleChange(rule),
+        )
+    }
+
+    /// Get the deposit locked by a proposal's creator
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// The deposit and whether it is locked, refunded or forfeited
+    pub fn get_proposal_deposit(env: Env, proposal_id: u64) -> ProposalDeposit {
+        gov_get_proposal_deposit(&env, proposal_id)
+    }
+
+    /// Get a proposal by ID
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// The Proposal struct
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Proposal {
+        gov_get_proposal(&env, proposal_id)
+    }
+
+    /// Get the voting power frozen when a proposal became active
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// The snapshot used for tallying and quorum
+    pub fn get_proposal_vote_snapshot(env: Env, proposal_id: u64) -> VoteSnapshot {
+        gov_get_proposal_vote_snapshot(&env, proposal_id)
+    }
+
+    /// Get all active proposals for a guild
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    ///
+    /// # Returns
+    /// Vector of active proposals
+    pub fn get_active_proposals(env: Env, guild_id: u64) -> Vec<Proposal> {
+        gov_get_active_proposals(&env, guild_id)
+    }
+
+    /// Get every proposal ever created in a guild
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    ///
+    /// # Returns
+    /// Vector of proposals, oldest first
+    pub fn get_all_proposals(env: Env, guild_id: u64) -> Vec<Proposal> {
+        gov_get_all_proposals(&env, guild_id)
+    }
+
+    /// Get a guild's proposals with a given status
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `status` - The status to filter by
+    ///
+    /// # Returns
+    /// Vector of matching proposals, oldest first
+    pub fn get_proposals_by_status(
+        env: Env,
+        guild_id: u64,
+        status: ProposalStatus,
+    ) -> Vec<Proposal> {
+        gov_get_proposals_by_status(&env, guild_id, status)
+    }
+
+    /// Get the per-voter vote records of a proposal
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// Every recorded vote with its decision, weight and time
+    pub fn get_proposal_votes(env: Env, proposal_id: u64) -> Vec<Vote> {
+        gov_get_proposal_votes(&env, proposal_id)
+    }
+
+    /// Get the per-voter ballots of a multi-option proposal
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// Every ballot with its choices, weight and time
+    pub fn get_proposal_option_ballots(env: Env, proposal_id: u64) -> Vec<OptionBallot> {
+        gov_get_proposal_option_ballots(&env, proposal_id)
+    }
+
+    /// Get the vote commitments of a commit-reveal proposal
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// Every commitment, including ones not yet revealed
+    pub fn get_proposal_vote_commitments(env: Env, proposal_id: u64) -> Vec<VoteCommitment> {
+        gov_get_proposal_vote_commitments(&env, proposal_id)
+    }
 
     /// Cast a vote on a proposal
     ///
@@ -1305,112 +2315,529 @@ impl StellarGuildsContract {
     ///
     /// # Returns
     /// `true` if successful
-    pub fn vote(env: Env, proposal_id: u64, voter: Address, decision: VoteDecision) -> bool {
-        gov_vote(&env, proposal_id, voter, decision)
+    pub fn vote(env: Env, proposal_id: u64, voter: Address, decision: VoteDecision) -> bool {
+        gov_vote(&env, proposal_id, voter, decision)
+    }
+
+    /// Cast quadratic votes on a proposal using quadratic voting
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    /// * `voter` - Address of the voter
+    /// * `decision` - Vote decision (For, Against, Abstain)
+    /// * `votes` - Number of votes; costs `votes²` voice credits
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn vote_quadratic(
+        env: Env,
+        proposal_id: u64,
+        voter: Address,
+        decision: VoteDecision,
+        votes: u32,
+    ) -> bool {
+        gov_vote_quadratic(&env, proposal_id, voter, decision, votes)
+    }
+
+    /// Cast a ballot on a multi-option proposal
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    /// * `voter` - Address of the voter
+    /// * `choices` - Option indexes: the chosen option, the approved options,
+    ///   or all ranked options from most to least preferred
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn vote_options(env: Env, proposal_id: u64, voter: Address, choices: Vec<u32>) -> bool {
+        gov_vote_options(&env, proposal_id, voter, choices)
+    }
+
+    /// Commit to a sealed vote on a commit-reveal proposal
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    /// * `voter` - Address of the voter
+    /// * `commitment` - Hash from `compute_vote_commitment`
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn commit_vote(
+        env: Env,
+        proposal_id: u64,
+        voter: Address,
+        commitment: BytesN<32>,
+    ) -> bool {
+        gov_commit_vote(&env, proposal_id, voter, commitment)
+    }
+
+    /// Reveal a committed vote during the proposal's reveal period
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    /// * `voter` - Address of the voter
+    /// * `decision` - The committed decision
+    /// * `salt` - The secret salt used in the commitment
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn reveal_vote(
+        env: Env,
+        proposal_id: u64,
+        voter: Address,
+        decision: VoteDecision,
+        salt: BytesN<32>,
+    ) -> bool {
+        gov_reveal_vote(&env, proposal_id, voter, decision, salt)
+    }
+
+    /// Compute the commitment for a sealed proposal vote
+    ///
+    /// # Arguments
+    /// * `voter` - Address of the voter
+    /// * `decision` - Vote decision (For, Against, Abstain)
+    /// * `salt` - A secret 32-byte salt, kept until reveal
+    ///
+    /// # Returns
+    /// The hash to pass to `commit_vote`
+    pub fn compute_vote_commitment(
+        env: Env,
+        voter: Address,
+        decision: VoteDecision,
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        gov_vote_commitment(&env, &voter, decision.code(), &salt)
+    }
+
+    /// Get a voter's commitment on a commit-reveal proposal
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    /// * `voter` - Address of the voter
+    ///
+    /// # Returns
+    /// The commitment, if the voter has committed
+    pub fn get_vote_commitment(
+        env: Env,
+        proposal_id: u64,
+        voter: Address,
+    ) -> Option<VoteCommitment> {
+        gov_get_vote_commitment(&env, proposal_id, voter)
+    }
+
+    /// Register the ed25519 key a contract account signs off-chain votes with;
+    /// Stellar accounts sign with their own account key instead
+    ///
+    /// # Arguments
+    /// * `voter` - Address of the voter (a contract account)
+    /// * `public_key` - The voter's ed25519 public key
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn register_vote_key(env: Env, voter: Address, public_key: BytesN<32>) -> bool {
+        gov_register_vote_key(&env, voter, public_key)
+    }
+
+    /// Get the nonce a voter's next signed vote must use
+    ///
+    /// # Arguments
+    /// * `voter` - Address of the voter
+    ///
+    /// # Returns
+    /// The voter's current vote nonce
+    pub fn get_signed_vote_nonce(env: Env, voter: Address) -> u64 {
+        gov_get_signed_vote_nonce(&env, &voter)
+    }
+
+    /// Get the payload a voter signs off-chain for a vote
+    ///
+    /// # Arguments
+    /// * `voter` - Address of the voter
+    /// * `proposal_id` - The ID of the proposal
+    /// * `decision` - Vote decision (For, Against, Abstain)
+    /// * `nonce` - The voter's current vote nonce
+    ///
+    /// # Returns
+    /// The bytes to sign with the voter's account key or registered key
+    pub fn get_signed_vote_payload(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        decision: VoteDecision,
+        nonce: u64,
+    ) -> Bytes {
+        gov_signed_vote_payload(&env, &voter, proposal_id, &decision, nonce)
+    }
+
+    /// Relay a batch of votes signed off-chain
+    ///
+    /// # Arguments
+    /// * `relayer` - Address submitting the batch and paying its fees
+    /// * `votes` - Signed votes, each recorded as if cast with `vote`
+    ///
+    /// # Returns
+    /// The number of votes recorded
+    pub fn submit_signed_votes(env: Env, relayer: Address, votes: Vec<SignedVote>) -> u32 {
+        gov_submit_signed_votes(&env, relayer, votes)
+    }
+
+    /// Delegate voting power to another member
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `delegator` - Address delegating their vote
+    /// * `delegate` - Address receiving the delegation
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn delegate_vote(env: Env, guild_id: u64, delegator: Address, delegate: Address) -> bool {
+        gov_delegate_vote(&env, guild_id, delegator, delegate)
+    }
+
+    /// Remove vote delegation
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `delegator` - Address removing their delegation
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn undelegate_vote(env: Env, guild_id: u64, delegator: Address) -> bool {
+        gov_undelegate_vote(&env, guild_id, delegator)
+    }
+
+    /// Delegate shares of voting weight for one proposal type or all of them
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `delegator` - Address delegating their vote
+    /// * `scope` - `All` or a single proposal type
+    /// * `splits` - Delegates and their percentage of the weight
+    /// * `expires_at` - When the delegation lapses (0 for never)
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn delegate_vote_scoped(
+        env: Env,
+        guild_id: u64,
+        delegator: Address,
+        scope: DelegationScope,
+        splits: Vec<DelegationSplit>,
+        expires_at: u64,
+    ) -> bool {
+        gov_delegate_vote_scoped(&env, guild_id, delegator, scope, splits, expires_at)
+    }
+
+    /// Revoke a delegation for one scope
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `delegator` - Address revoking their delegation
+    /// * `scope` - The scope to revoke
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn undelegate_vote_scoped(
+        env: Env,
+        guild_id: u64,
+        delegator: Address,
+        scope: DelegationScope,
+    ) -> bool {
+        gov_undelegate_vote_scoped(&env, guild_id, delegator, scope)
+    }
+
+    /// Get the unexpired delegations an address has made
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `address` - The delegator
+    ///
+    /// # Returns
+    /// Vector of delegations, one per scope
+    pub fn get_outbound_delegations(env: Env, guild_id: u64, address: Address) -> Vec<Delegation> {
+        gov_get_outbound_delegations(&env, guild_id, address)
+    }
+
+    /// Get the unexpired delegations that include an address as a delegate
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `address` - The delegate
+    ///
+    /// # Returns
+    /// Vector of delegations made to the address
+    pub fn get_inbound_delegations(env: Env, guild_id: u64, address: Address) -> Vec<Delegation> {
+        gov_get_inbound_delegations(&env, guild_id, address)
+    }
+
+    /// Finalize a proposal after voting period ends
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// The final status of the proposal
+    pub fn finalize_proposal(env: Env, proposal_id: u64) -> ProposalStatus {
+        gov_finalize_proposal(&env, proposal_id)
+    }
+
+    /// Execute a passed proposal
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal to execute
+    /// * `executor` - Address executing the proposal
+    ///
+    /// # Returns
+    /// `true` if execution was successful
+    pub fn execute_proposal(env: Env, proposal_id: u64, executor: Address) -> bool {
+        gov_execute_proposal(&env, proposal_id, executor)
+    }
+
+    /// Cancel a proposal. The deposit is refunded unless the proposer
+    /// withdraws their own active proposal, which forfeits it.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    /// * `caller` - Address making the request (must be proposer or admin)
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn cancel_proposal(env: Env, proposal_id: u64, caller: Address) -> bool {
+        gov_cancel_proposal(&env, proposal_id, caller)
+    }
+
+    /// Update governance configuration
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `caller` - Address making the request (must be owner)
+    /// * `config` - New governance configuration; its `proposal_deposit` must
+    ///   match the current rule, as deposits only change through a rule change
+    ///   proposal
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn update_governance_config(
+        env: Env,
+        guild_id: u64,
+        caller: Address,
+        config: GovernanceConfig,
+    ) -> bool {
+        gov_update_governance_config(&env, guild_id, caller, config)
+    }
+
+    /// Cancel an abusive proposal, forfeiting its deposit to the guild treasury
+    ///
+    /// # Arguments
+    /// * `proposal_id` - The ID of the proposal
+    /// * `admin` - Address making the request (must be owner or admin)
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn cancel_proposal_for_abuse(env: Env, proposal_id: u64, admin: Address) -> bool {
+        gov_cancel_proposal_for_abuse(&env, proposal_id, admin)
+    }
+
+    /// Override the guild's voting mode for one proposal type
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `caller` - Address making the request (must be owner)
+    /// * `proposal_type` - Proposal type the mode applies to
+    /// * `voting_mode` - Linear, Quadratic or Conviction
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn set_proposal_type_voting_mode(
+        env: Env,
+        guild_id: u64,
+        caller: Address,
+        proposal_type: ProposalType,
+        voting_mode: VotingMode,
+    ) -> bool {
+        gov_set_proposal_type_voting_mode(&env, guild_id, caller, proposal_type, voting_mode)
+    }
+
+    /// Set the guardians allowed to veto queued proposals
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `caller` - Address making the request (must be owner)
+    /// * `guardians` - Guardian addresses, e.g. a multisig account contract
+    ///
+    /// # Returns
+    /// `true` if successful
+    pub fn set_governance_guardians(
+        env: Env,
+        guild_id: u64,
+        caller: Address,
+        guardians: Vec<Address>,
+    ) -> bool {
+        gov_set_governance_guardians(&env, guild_id, caller, guardians)
     }
 
-    /// Delegate voting power to another member
+    /// Get the guardians of a guild's governance
     ///
     /// # Arguments
     /// * `guild_id` - The ID of the guild
-    /// * `delegator` - Address delegating their vote
-    /// * `delegate` - Address receiving the delegation
     ///
     /// # Returns
-    /// `true` if successful
-    pub fn delegate_vote(env: Env, guild_id: u64, delegator: Address, delegate: Address) -> bool {
-        gov_delegate_vote(&env, guild_id, delegator, delegate)
+    /// Vector of guardian addresses
+    pub fn get_governance_guardians(env: Env, guild_id: u64) -> Vec<Address> {
+        gov_get_governance_guardians(&env, guild_id)
     }
 
-    /// Remove vote delegation
+    /// Veto a queued proposal during its execution delay
     ///
     /// # Arguments
-    /// * `guild_id` - The ID of the guild
-    /// * `delegator` - Address removing their delegation
+    /// * `proposal_id` - The ID of the proposal
+    /// * `guardian` - Guardian vetoing the proposal
+    /// * `reason` - Why the proposal is vetoed
     ///
     /// # Returns
     /// `true` if successful
-    pub fn undelegate_vote(env: Env, guild_id: u64, delegator: Address) -> bool {
-        gov_undelegate_vote(&env, guild_id, delegator)
+    pub fn veto_proposal(env: Env, proposal_id: u64, guardian: Address, reason: String) -> bool {
+        gov_veto_proposal(&env, proposal_id, guardian, reason)
     }
 
-    /// Finalize a proposal after voting period ends
+    /// Get the veto recorded against a proposal
     ///
     /// # Arguments
     /// * `proposal_id` - The ID of the proposal
     ///
     /// # Returns
-    /// The final status of the proposal
-    pub fn finalize_proposal(env: Env, proposal_id: u64) -> ProposalStatus {
-        gov_finalize_proposal(&env, proposal_id)
+    /// The guardian, reason and time of the veto
+    pub fn get_proposal_veto(env: Env, proposal_id: u64) -> ProposalVeto {
+        gov_get_proposal_veto(&env, proposal_id)
     }
 
-    /// Execute a passed proposal
+    /// Get a guild's proposals waiting out their execution delay
     ///
     /// # Arguments
-    /// * `proposal_id` - The ID of the proposal to execute
-    /// * `executor` - Address executing the proposal
+    /// * `guild_id` - The ID of the guild
     ///
     /// # Returns
-    /// `true` if execution was successful
-    pub fn execute_proposal(env: Env, proposal_id: u64, executor: Address) -> bool {
-        gov_execute_proposal(&env, proposal_id, executor)
+    /// Vector of queued proposals with their `executable_at` times
+    pub fn get_queued_proposals(env: Env, guild_id: u64) -> Vec<Proposal> {
+        gov_get_queued_proposals(&env, guild_id)
     }
 
-    /// Cancel a proposal
+    // ============ Bounty Escrow Functions ============
+
+    /// Create a new bounty
     ///
     /// # Arguments
-    /// * `proposal_id` - The ID of the proposal
-    /// * `caller` - Address making the request (must be proposer or admin)
+    /// * `guild_id` - The ID of the guild creating the bounty
+    /// * `creator` - Address of the bounty creator (must be guild admin/owner)
+    /// * `title` - Short title for the bounty
+    /// * `description` - Detailed description of the task
+    /// * `reward_amount` - Amount of tokens as reward
+    /// * `token` - Address of the token contract
+    /// * `expiry` - Absolute timestamp when the bounty expires
     ///
     /// # Returns
-    /// `true` if successful
-    pub fn cancel_proposal(env: Env, proposal_id: u64, caller: Address) -> bool {
-        gov_cancel_proposal(&env, proposal_id, caller)
+    /// The ID of the newly created bounty
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_bounty(
+        env: Env,
+        guild_id: u64,
+        creator: Address,
+        title: String,
+        description: String,
+        reward_amount: i128,
+        token: Address,
+        expiry: u64,
+    ) -> u64 {
+        create_bounty(
+            &env,
+            guild_id,
+            creator,
+            title,
+            description,
+            reward_amount,
+            token,
+            expiry,
+            None,
+            None,
+        )
     }
 
-    /// Update governance configuration
+    /// Create a bounty that requires claimers to lock a claim bond
+    ///
+    /// The bond is locked in the bounty's token when the bounty is claimed. It is
+    /// returned on approval or a voluntary release within the grace window, and
+    /// slashed to the funders on abandonment or a lost dispute.
     ///
     /// # Arguments
-    /// * `guild_id` - The ID of the guild
-    /// * `caller` - Address making the request (must be owner)
-    /// * `config` - New governance configuration
+    /// * `guild_id` - The ID of the guild creating the bounty
+    /// * `creator` - Address of the bounty creator (must be guild admin/owner)
+    /// * `title` - Short title for the bounty
+    /// * `description` - Detailed description of the task
+    /// * `reward_amount` - Amount of tokens as reward
+    /// * `token` - Address of the token contract
+    /// * `expiry` - Absolute timestamp when the bounty expires
+    /// * `bond` - Claim bond amount and grace period
     ///
     /// # Returns
-    /// `true` if successful
-    pub fn update_governance_config(
+    /// The ID of the newly created bounty
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_bonded_bounty(
         env: Env,
         guild_id: u64,
-        caller: Address,
-        config: GovernanceConfig,
-    ) -> bool {
-        gov_update_governance_config(&env, guild_id, caller, config)
+        creator: Address,
+        title: String,
+        description: String,
+        reward_amount: i128,
+        token: Address,
+        expiry: u64,
+        bond: ClaimBond,
+    ) -> u64 {
+        create_bounty(
+            &env,
+            guild_id,
+            creator,
+            title,
+            description,
+            reward_amount,
+            token,
+            expiry,
+            None,
+            Some(bond),
+        )
     }
 
-    // ============ Bounty Escrow Functions ============
-
-    /// Create a new bounty
+    /// Create a bounty whose reward is drawn from a guild treasury
+    ///
+    /// A `BountyFunding` transaction is proposed on the treasury; once it reaches
+    /// the treasury's approval threshold and is executed, the reward moves into the
+    /// bounty escrow and the bounty opens. Refunds on cancel/expiry go back to the
+    /// treasury.
     ///
     /// # Arguments
     /// * `guild_id` - The ID of the guild creating the bounty
-    /// * `creator` - Address of the bounty creator (must be guild admin/owner)
+    /// * `creator` - Address of the bounty creator (guild admin/owner and treasury signer)
+    /// * `treasury_id` - The guild treasury funding the reward
     /// * `title` - Short title for the bounty
     /// * `description` - Detailed description of the task
     /// * `reward_amount` - Amount of tokens as reward
     /// * `token` - Address of the token contract
     /// * `expiry` - Absolute timestamp when the bounty expires
+    /// * `bond` - Optional claim bond required from claimers
     ///
     /// # Returns
     /// The ID of the newly created bounty
-    pub fn create_bounty(
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_treasury_bounty(
         env: Env,
         guild_id: u64,
         creator: Address,
+        treasury_id: u64,
         title: String,
         description: String,
         reward_amount: i128,
         token: Address,
         expiry: u64,
+        bond: Option<ClaimBond>,
     ) -> u64 {
         create_bounty(
             &env,
@@ -1421,6 +2848,8 @@ impl StellarGuildsContract {
             reward_amount,
             token,
             expiry,
+            Some(treasury_id),
+            bond,
         )
     }
 
@@ -1437,6 +2866,46 @@ impl StellarGuildsContract {
         fund_bounty(&env, bounty_id, funder, amount)
     }
 
+    /// Add a reward leg in another token to an unclaimed bounty
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The ID of the bounty
+    /// * `caller` - Bounty creator or guild admin
+    /// * `token` - Address of the token contract for the new leg
+    /// * `amount` - Reward amount in that token
+    ///
+    /// # Returns
+    /// `true` if the reward leg was added
+    pub fn add_bounty_reward(
+        env: Env,
+        bounty_id: u64,
+        caller: Address,
+        token: Address,
+        amount: i128,
+    ) -> bool {
+        add_bounty_reward(&env, bounty_id, caller, token, amount)
+    }
+
+    /// Fund a specific reward leg of a bounty
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The ID of the bounty to fund
+    /// * `funder` - Address providing the funds
+    /// * `token` - Token of the reward leg being funded
+    /// * `amount` - Amount of tokens to fund
+    ///
+    /// # Returns
+    /// `true` if funding was successful
+    pub fn fund_bounty_token(
+        env: Env,
+        bounty_id: u64,
+        funder: Address,
+        token: Address,
+        amount: i128,
+    ) -> bool {
+        fund_bounty_token(&env, bounty_id, funder, token, amount)
+    }
+
     /// Claim a bounty (first-come-first-served)
     ///
     /// # Arguments
@@ -1449,6 +2918,32 @@ impl StellarGuildsContract {
         claim_bounty(&env, bounty_id, claimer)
     }
 
+    /// Give up a claim and reopen the bounty
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The ID of the claimed bounty
+    /// * `claimer` - Address of the current claimer
+    ///
+    /// # Returns
+    /// `true` if the claim was released (bond returned within the grace window,
+    /// slashed otherwise)
+    pub fn release_claim(env: Env, bounty_id: u64, claimer: Address) -> bool {
+        release_claim(&env, bounty_id, claimer)
+    }
+
+    /// Split a claimed bounty between co-claimants by agreed percentages
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The ID of the claimed bounty
+    /// * `splits` - Co-claimants and their shares in basis points (must total 10000
+    ///   and include the claimer; every co-claimant must authorize)
+    ///
+    /// # Returns
+    /// `true` if the split was recorded
+    pub fn set_bounty_splits(env: Env, bounty_id: u64, splits: Vec<ClaimantShare>) -> bool {
+        set_bounty_splits(&env, bounty_id, splits)
+    }
+
     /// Submit work for a claimed bounty
     ///
     /// # Arguments
@@ -1473,6 +2968,51 @@ impl StellarGuildsContract {
         approve_completion(&env, bounty_id, approver)
     }
 
+    /// Name dedicated reviewers for a bounty and require M-of-N approvals
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The ID of the bounty
+    /// * `caller` - Bounty creator or guild admin
+    /// * `reviewers` - Reviewer addresses (the claimer cannot be a reviewer)
+    /// * `required_approvals` - Approvals needed before the bounty completes
+    ///
+    /// # Returns
+    /// `true` if the reviewer set was updated
+    pub fn set_bounty_reviewers(
+        env: Env,
+        bounty_id: u64,
+        caller: Address,
+        reviewers: Vec<Address>,
+        required_approvals: u32,
+    ) -> bool {
+        set_bounty_reviewers(&env, bounty_id, caller, reviewers, required_approvals)
+    }
+
+    /// Approve or reject submitted work as a dedicated reviewer
+    ///
+    /// # Arguments
+    /// * `bounty_id` - The ID of the bounty under review
+    /// * `reviewer` - One of the bounty's reviewers
+    /// * `approve` - `true` to approve, `false` to reject and send back for rework
+    /// * `comment_hash` - Hash of the off-chain review comment
+    ///
+    /// # Returns
+    /// `true` if the review was recorded
+    pub fn review_bounty(
+        env: Env,
+        bounty_id: u64,
+        reviewer: Address,
+        approve: bool,
+        comment_hash: BytesN<32>,
+    ) -> bool {
+        review_bounty(&env, bounty_id, reviewer, approve, comment_hash)
+    }
+
+    /// Get every recorded review of a bounty
+    pub fn get_bounty_reviews(env: Env, bounty_id: u64) -> Vec<BountyReview> {
+        get_bounty_reviews(&env, bounty_id)
+    }
+
     /// Release escrow funds to the bounty claimer
     ///
     /// # Arguments
@@ -1529,6 +3069,118 @@ impl StellarGuildsContract {
         get_guild_bounties_list(&env, guild_id)
     }
 
+    // ============ Bounty Templates & Recurring Bounties ============
+
+    /// Create a reusable bounty template for a guild
+    ///
+    /// # Arguments
+    /// * `guild_id` - The ID of the guild
+    /// * `creator` - Guild admin/owner; recorded as creator of spawned bounties
+    /// * `title` - Title given to spawned bounties
+    /// * `description` - Description given to spawned bounties
+    /// * `reward_amount` - Reward amount of spawned bounties
+    /// * `token` - Reward token of spawned bounties
+    /// * `duration` - Seconds each spawned bounty stays open
+    /// * `treasury_id` - Optional treasury auto-funding spawned bounties through the
+    ///   creator's treasury allowance
+    ///
+    /// # Returns
+    /// The ID of the new template
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_bounty_template(
+        env: Env,
+        guild_id: u64,
+        creator: Address,
+        title: String,
+        description: String,
+        reward_amount: i128,
+        token: Address,
+        duration: u64,
+        treasury_id: Option<u64>,
+    ) -> u64 {
+        bounty_create_template(
+            &env,
+            guild_id,
+            creator,
+            title,
+            description,
+            reward_amount,
+            token,
+            duration,
+            treasury_id,
+        )
+    }
+
+    /// Make a template recurring (or stop recurrence with `period_seconds = 0`)
+    ///
+    /// # Arguments
+    /// * `template_id` - The ID of the template
+    /// * `caller` - Guild admin/owner
+    /// * `period_seconds` - Seconds between spawned bounties
+    /// * `first_spawn_at` - Timestamp from which the first bounty may be spawned
+    ///
+    /// # Returns
+    /// `true` if the schedule was updated
+    pub fn schedule_bounty_template(
+        env: Env,
+        template_id: u64,
+        caller: Address,
+        period_seconds: u64,
+        first_spawn_at: u64,
+    ) -> bool {
+        bounty_schedule_template(&env, template_id, caller, period_seconds, first_spawn_at)
+    }
+
+    /// Deactivate a bounty template
+    ///
+    /// # Arguments
+    /// * `template_id` - The ID of the template
+    /// * `caller` - Guild admin/owner
+    ///
+    /// # Returns
+    /// `true` if the template was deactivated
+    pub fn deactivate_bounty_template(env: Env, template_id: u64, caller: Address) -> bool {
+        bounty_deactivate_template(&env, template_id, caller)
+    }
+
+    /// Spawn a bounty from a template on demand
+    ///
+    /// # Arguments
+    /// * `template_id` - The ID of the template
+    /// * `caller` - Guild admin/owner
+    ///
+    /// # Returns
+    /// The ID of the spawned bounty
+    pub fn spawn_bounty_from_template(env: Env, template_id: u64, caller: Address) -> u64 {
+        bounty_spawn_from_template(&env, template_id, caller)
+    }
+
+    /// Keeper entrypoint: spawn the next due bounty of a recurring template
+    ///
+    /// # Arguments
+    /// * `template_id` - The ID of the scheduled template
+    ///
+    /// # Returns
+    /// The ID of the spawned bounty
+    pub fn run_bounty_schedule(env: Env, template_id: u64) -> u64 {
+        bounty_run_schedule(&env, template_id)
+    }
+
+    /// Get a bounty template by ID
+    pub fn get_bounty_template(env: Env, template_id: u64) -> BountyTemplate {
+        bounty_get_template(&env, template_id)
+    }
+
+    /// Get all bounty templates of a guild
+    pub fn get_guild_bounty_templates(env: Env, guild_id: u64) -> Vec<BountyTemplate> {
+        bounty_get_guild_templates(&env, guild_id)
+    }
+
+    /// Get all bounties spawned from a template
+    pub fn get_template_bounties(env: Env, template_id: u64) -> Vec<Bounty> {
+        bounty_get_template_bounties(&env, template_id)
+    }
+
     // ════════════════════════════════════════════════════════════════════════
     //  Multi-Signature Framework
     //  Provides M-of-N signing, configurable policies, and emergency controls.
@@ -1662,6 +3314,7 @@ impl StellarGuildsContract {
 
     /// Execute a treasury withdrawal proposal only after a multisig treasury operation is executed.
     /// This preserves backward compatibility while enabling strict multisig-gated flows.
+    #[allow(clippy::too_many_arguments)]
     pub fn ms_propose_treasury_withdrawal(
         env: Env,
         multisig_operation_id: u64,
@@ -1768,6 +3421,7 @@ impl StellarGuildsContract {
     // ─── Policy Management ────────────────────────────────────────────────
 
     /// Set a custom signing policy for a specific operation type (owner only).
+    #[allow(clippy::too_many_arguments)]
     pub fn ms_set_policy(
         env: Env,
         account_id: u64,
@@ -1832,6 +3486,7 @@ impl StellarGuildsContract {
     ///
     /// # Returns
     /// The ID of the newly created plan
+    #[allow(clippy::too_many_arguments)]
     pub fn create_subscription_plan(
         env: Env,
         guild_id: u64,
@@ -2037,6 +3692,30 @@ impl StellarGuildsContract {
         sub_process_due_subscriptions(&env, limit)
     }
 
+    /// Move a guild's collected subscription revenue into one of its treasuries
+    ///
+    /// # Arguments
+    /// * `guild_id` - Guild whose revenue is swept
+    /// * `treasury_id` - Treasury of the same guild receiving the revenue
+    /// * `token` - Token to sweep (None for native XLM)
+    /// * `caller` - Guild owner or admin
+    ///
+    /// # Returns
+    /// The amount swept
+    pub fn sweep_subscription_revenue(
+        env: Env,
+        guild_id: u64,
+        treasury_id: u64,
+        token: Option<Address>,
+        caller: Address,
+    ) -> i128 {
+        caller.require_auth();
+        match sub_sweep_revenue(&env, guild_id, treasury_id, token, caller) {
+            Ok(amount) => amount,
+            Err(e) => panic!("sweep_revenue error: {}", e as u32),
+        }
+    }
+
     // ============ Upgrade Functions ============
 
     /// Initialize upgrade functionality
@@ -2125,6 +3804,7 @@ impl StellarGuildsContract {
     }
 
     /// Register a migration plan for an upgrade
+    #[allow(clippy::too_many_arguments)]
     pub fn register_migration_plan(
         env: Env,
         caller: Address,
@@ -2242,7 +3922,8 @@ mod tests {
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(env, &contract_id);
 
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+        client.mock_all_auths().initialize(&Address::generate(env), &native.address());
 
         contract_id
     }
@@ -2252,12 +3933,31 @@ mod tests {
     #[test]
     fn test_initialize() {
         let (env, _, _, _, _) = setup();
-        let contract_id = register_and_init_contract(&env);
-
-        // Verify initialization was successful
+        let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(&env, &contract_id);
-        let result = client.initialize();
+        let admin = Address::generate(&env);
+        let native = env.register_stellar_asset_contract_v2(Address::generate(&env));
+
+        let result = client.mock_all_auths().initialize(&admin, &native.address());
         assert_eq!(result, true);
+        assert_eq!(client.get_admin(), admin);
+        assert_eq!(client.get_native_token(), native.address());
+
+        // A second call cannot repoint the native token
+        let other = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let retry = client.mock_all_auths().try_initialize(&admin, &other.address());
+        assert!(retry.is_err());
+        assert_eq!(client.get_native_token(), native.address());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_initialize_requires_admin_auth() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, StellarGuildsContract);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let native = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        client.initialize(&Address::generate(&env), &native.address());
     }
 
     #[test]
@@ -2913,7 +4613,8 @@ mod tests {
 
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(&env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        client.mock_all_auths().initialize(&Address::generate(&env), &native.address());
 
         let creator = Address::generate(&env);
         let token = Some(Address::generate(&env));
@@ -2932,7 +4633,8 @@ mod tests {
 
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(&env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        client.mock_all_auths().initialize(&Address::generate(&env), &native.address());
 
         let creator = Address::generate(&env);
         let recipient1 = Address::generate(&env);
@@ -2958,7 +4660,8 @@ mod tests {
 
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(&env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        client.mock_all_auths().initialize(&Address::generate(&env), &native.address());
 
         let creator = Address::generate(&env);
         let recipient = Address::generate(&env);
@@ -2982,7 +4685,8 @@ mod tests {
 
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(&env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        client.mock_all_auths().initialize(&Address::generate(&env), &native.address());
 
         let creator = Address::generate(&env);
         let recipient1 = Address::generate(&env);
@@ -3015,7 +4719,8 @@ mod tests {
 
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(&env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        client.mock_all_auths().initialize(&Address::generate(&env), &native.address());
 
         let creator = Address::generate(&env);
         let token = Some(Address::generate(&env));
@@ -10,7 +10,7 @@ use crate::milestone::types::{MilestoneInput, MilestoneStatus};
 use crate::StellarGuildsContract;
 use crate::StellarGuildsContractClient;
 use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{token, Address, Env, String, Vec};
 
 // ============ Test Helpers ============
 
@@ -36,10 +36,18 @@ fn set_ledger_timestamp(env: &Env, timestamp: u64) {
 fn register_and_init_contract(env: &Env) -> Address {
     let contract_id = env.register_contract(None, StellarGuildsContract);
     let client = StellarGuildsContractClient::new(env, &contract_id);
-    client.initialize();
+    let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client
+        .mock_all_auths()
+        .initialize(&Address::generate(env), &native.address());
     contract_id
 }
 
+fn mint_native(env: &Env, client: &StellarGuildsContractClient<'_>, to: &Address, amount: i128) {
+    let native = client.get_native_token();
+    token::StellarAssetClient::new(env, &native).mint(to, &amount);
+}
+
 fn setup_guild(client: &StellarGuildsContractClient<'_>, env: &Env, owner: &Address) -> u64 {
     let name = String::from_str(env, "Dev Guild");
     let description = String::from_str(env, "Developer Guild");
@@ -74,6 +82,7 @@ fn create_treasury_with_funds(
     signers.push_back(signer2.clone());
 
     let treasury_id = client.initialize_treasury(&guild_id, &signers, &2u32);
+    mint_native(env, client, owner, amount);
     client.deposit_treasury(&treasury_id, owner, &amount, &None);
     treasury_id
 }
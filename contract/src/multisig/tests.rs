@@ -4,7 +4,7 @@ mod tests {
     use crate::multisig::types::{OperationStatus, OperationType, TIMEOUT_24H, TIMEOUT_48H};
     use crate::{StellarGuildsContract, StellarGuildsContractClient};
     use soroban_sdk::testutils::{Address as _, Ledger as _, LedgerInfo};
-    use soroban_sdk::{Address, Env, String, Vec};
+    use soroban_sdk::{token, Address, Env, String, Vec};
 
     fn setup_env() -> (Env, Address, Address, Address) {
         let env = Env::default();
@@ -18,8 +18,21 @@ mod tests {
     fn init_client(env: &Env) -> StellarGuildsContractClient<'_> {
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(env));
         client
+            .mock_all_auths()
+            .initialize(&Address::generate(env), &native.address());
+        client
+    }
+
+    fn mint_native(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        to: &Address,
+        amount: i128,
+    ) {
+        let native = client.get_native_token();
+        token::StellarAssetClient::new(env, &native).mint(to, &amount);
     }
 
     fn set_timestamp(env: &Env, timestamp: u64) {
@@ -199,6 +212,7 @@ mod tests {
         treasury_signers.push_back(signer1.clone());
         treasury_signers.push_back(signer2.clone());
         let treasury_id = client.initialize_treasury(&guild_id, &treasury_signers, &2u32);
+        mint_native(&env, &client, &owner, 1_000i128);
         client.deposit_treasury(&treasury_id, &owner, &1_000i128, &None);
 
         // Multisig gate setup
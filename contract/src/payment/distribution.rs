@@ -1,15 +1,18 @@
 use crate::events::emit::emit_event;
 use crate::events::topics::{
     ACT_CANCELLED, ACT_DISTRIBUTED, ACT_FAILED, ACT_RECIPIENT_ADDED, MOD_PAYMENT,
-    ACT_CREATED,
+    ACT_CREATED, ACT_FUNDED,
 };
+use crate::guild::storage::resolve_token;
+use crate::ledger::{self, SubAccount};
 use crate::payment::storage::{
     add_recipient_to_pool, clear_pool_recipients, get_next_pool_id, get_payment_pool,
     get_pool_recipients, recipient_exists_in_pool, store_payment_pool, update_pool_status,
 };
 use crate::payment::types::{
     DistributionExecutedEvent, DistributionFailedEvent, DistributionRule, DistributionStatus,
-    PaymentPool, PaymentPoolCreatedEvent, PoolCancelledEvent, Recipient, RecipientAddedEvent,
+    PaymentPool, PaymentPoolCreatedEvent, PoolCancelledEvent, PoolFundedEvent, Recipient,
+    RecipientAddedEvent,
 };
 use soroban_sdk::{contracterror, Address, Env, String, Vec};
 
@@ -88,6 +91,54 @@ pub fn create_payment_pool(
     Ok(pool_id)
 }
 
+/// Deposit funds into a pending payment pool's sub-account.
+///
+/// A pool can only be executed once it holds at least its `total_amount`.
+///
+/// # Events emitted
+/// - `(payment, funded)` → `PoolFundedEvent`
+///
+/// # Returns
+/// The total amount the pool now holds
+///
+/// # Errors
+/// `InvalidAmount`, `PoolNotFound`, `PoolNotPending`
+pub fn fund_pool(
+    env: &Env,
+    pool_id: u64,
+    funder: Address,
+    amount: i128,
+) -> Result<i128, PaymentError> {
+    funder.require_auth();
+
+    if amount <= 0 {
+        return Err(PaymentError::InvalidAmount);
+    }
+    let pool = get_payment_pool(env, pool_id).ok_or(PaymentError::PoolNotFound)?;
+    if pool.status != DistributionStatus::Pending {
+        return Err(PaymentError::PoolNotPending);
+    }
+
+    let account = SubAccount::Pool(pool_id);
+    let token_addr = resolve_token(env, &pool.token);
+    ledger::deposit(env, &account, &token_addr, &funder, amount);
+    let total_funded = ledger::balance(env, &account, &token_addr);
+
+    emit_event(
+        env,
+        MOD_PAYMENT,
+        ACT_FUNDED,
+        PoolFundedEvent {
+            pool_id,
+            funder,
+            amount,
+            total_funded,
+        },
+    );
+
+    Ok(total_funded)
+}
+
 /// Add a recipient to a payment pool
 ///
 /// # Events emitted
@@ -223,9 +274,11 @@ fn calculate_recipient_amount(
 
 /// Execute the distribution for a payment pool.
 ///
-/// Transfers tokens to each recipient according to the pool's distribution rule.
-/// On success emits `(payment, distributed)`; on insufficient balance emits
-/// `(payment, failed)` and returns `Err(InsufficientBalance)`.
+/// Transfers tokens from the pool's sub-account to each recipient according to
+/// the pool's distribution rule; rounding dust and any over-funding go back to
+/// the creator. On success emits `(payment, distributed)`; if the pool holds
+/// less than `total_amount` emits `(payment, failed)` and returns
+/// `Err(InsufficientBalance)`.
 ///
 /// # Events emitted
 /// - `(payment, distributed)` → `DistributionExecutedEvent`   (on success)
@@ -260,15 +313,13 @@ pub fn execute_distribution(
         None
     };
 
-    // Check contract balance
-    let contract_balance = if let Some(token_addr) = &pool.token {
-        let token_client = soroban_sdk::token::Client::new(env, token_addr);
-        token_client.balance(&env.current_contract_address())
-    } else {
-        i128::MAX // TODO: implement native XLM balance check
-    };
+    // Only the pool's own sub-account can pay out
+    // `None` pays out native XLM through its Stellar Asset Contract
+    let account = SubAccount::Pool(pool_id);
+    let token_addr = resolve_token(env, &pool.token);
+    let pool_balance = ledger::balance(env, &account, &token_addr);
 
-    if contract_balance < pool.total_amount {
+    if pool_balance < pool.total_amount {
         update_pool_status(env, pool_id, DistributionStatus::Failed);
         emit_event(
             env,
@@ -276,7 +327,7 @@ pub fn execute_distribution(
             ACT_FAILED,
             DistributionFailedEvent {
                 pool_id,
-                reason: String::from_str(env, "Insufficient contract balance"),
+                reason: String::from_str(env, "Insufficient pool balance"),
             },
         );
         return Err(PaymentError::InsufficientBalance);
@@ -291,17 +342,18 @@ pub fn execute_distribution(
             continue;
         }
 
-        if let Some(token_addr) = &pool.token {
-            let token_client = soroban_sdk::token::Client::new(env, token_addr);
-            token_client.transfer(&env.current_contract_address(), &recipient.address, &amount);
-        }
-        // TODO: native XLM transfer
+        ledger::withdraw(env, &account, &token_addr, &recipient.address, amount);
 
         total_distributed = total_distributed
             .checked_add(amount)
             .ok_or(PaymentError::ArithmeticOverflow)?;
     }
 
+    let leftover = ledger::balance(env, &account, &token_addr);
+    if leftover > 0 {
+        ledger::withdraw(env, &account, &token_addr, &pool.created_by, leftover);
+    }
+
     pool.status = DistributionStatus::Executed;
     store_payment_pool(env, &pool);
 
@@ -343,7 +395,8 @@ pub fn get_recipient_amount(
     calculate_recipient_amount(&pool, &recipient, total_recipients, total_weight)
 }
 
-/// Cancel a pending payment pool and clear its recipients.
+/// Cancel a pending payment pool, clear its recipients and refund any funds it
+/// holds to the creator.
 ///
 /// # Events emitted
 /// - `(payment, cancelled)` → `PoolCancelledEvent`
@@ -357,6 +410,13 @@ pub fn cancel_distribution(env: &Env, pool_id: u64, caller: Address) -> Result<b
         return Err(PaymentError::PoolNotPending);
     }
 
+    let account = SubAccount::Pool(pool_id);
+    let token_addr = resolve_token(env, &pool.token);
+    let refunded = ledger::balance(env, &account, &token_addr);
+    if refunded > 0 {
+        ledger::withdraw(env, &account, &token_addr, &pool.created_by, refunded);
+    }
+
     update_pool_status(env, pool_id, DistributionStatus::Cancelled);
     clear_pool_recipients(env, pool_id);
 
@@ -367,6 +427,7 @@ pub fn cancel_distribution(env: &Env, pool_id: u64, caller: Address) -> Result<b
         PoolCancelledEvent {
             pool_id,
             cancelled_by: caller,
+            refunded,
         },
     );
 
@@ -26,7 +26,7 @@ pub mod types;
 // Re-export main functions for convenience
 pub use distribution::{
     add_recipient, batch_distribute, cancel_distribution, create_payment_pool,
-    execute_distribution, get_pool_status, get_recipient_amount, validate_distribution,
+    execute_distribution, fund_pool, get_pool_status, get_recipient_amount, validate_distribution,
 };
 // pub use storage::initialize_payment_storage;
 pub use types::{DistributionRule, DistributionStatus};
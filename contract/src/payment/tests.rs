@@ -20,7 +20,10 @@ fn setup_env() -> Env {
 fn register_and_init_contract(env: &Env) -> Address {
     let contract_id = env.register_contract(None, StellarGuildsContract);
     let client = StellarGuildsContractClient::new(env, &contract_id);
-    client.initialize();
+    let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client
+        .mock_all_auths()
+        .initialize(&Address::generate(env), &native.address());
     contract_id
 }
 
@@ -34,6 +37,19 @@ fn mint_tokens(env: &Env, token: &Address, to: &Address, amount: i128) {
     client.mint(to, &amount);
 }
 
+/// Mint `amount` to the creator and deposit it into the pool
+fn fund_pool(
+    env: &Env,
+    client: &StellarGuildsContractClient<'_>,
+    token: &Address,
+    creator: &Address,
+    pool_id: u64,
+    amount: i128,
+) {
+    mint_tokens(env, token, creator, amount);
+    client.fund_payment_pool(&pool_id, creator, &amount);
+}
+
 fn get_token_balance(env: &Env, token: &Address, addr: &Address) -> i128 {
     let client = token::TokenClient::new(env, token);
     client.balance(addr)
@@ -72,12 +88,10 @@ fn test_percentage_distribution_success() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    // Mint tokens to contract
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     // Create pool
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
 
     // Add recipients with percentage shares
     client.add_recipient(&pool_id, &recipient1, &50u32, &creator); // 50%
@@ -197,12 +211,10 @@ fn test_equal_split_distribution_success() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    // Mint tokens to contract
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     // Create pool with equal split
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::EqualSplit, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
 
     // Add recipients (share value doesn't matter for equal split, but must be > 0)
     client.add_recipient(&pool_id, &recipient1, &1u32, &creator);
@@ -231,6 +243,37 @@ fn test_equal_split_distribution_success() {
     assert_eq!(balance1, 333);
     assert_eq!(balance2, 333);
     assert_eq!(balance3, 333);
+
+    // Rounding dust goes back to the creator
+    assert_eq!(get_token_balance(&env, &token_addr, &creator), 1);
+}
+
+#[test]
+fn test_native_xlm_distribution() {
+    let env = setup_env();
+    let creator = Address::generate(&env);
+    let recipient1 = Address::generate(&env);
+    let recipient2 = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+    let native = client.get_native_token();
+
+    // Pool without a token pays out in XLM
+    let pool_id =
+        client.create_payment_pool(&1000i128, &None, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &native, &creator, pool_id, 1000);
+    client.add_recipient(&pool_id, &recipient1, &60u32, &creator);
+    client.add_recipient(&pool_id, &recipient2, &40u32, &creator);
+
+    let result = client.execute_distribution(&pool_id, &creator);
+    assert_eq!(result, true);
+
+    assert_eq!(get_token_balance(&env, &native, &recipient1), 600);
+    assert_eq!(get_token_balance(&env, &native, &recipient2), 400);
+    assert_eq!(get_token_balance(&env, &native, &contract_id), 0);
 }
 
 #[test]
@@ -247,10 +290,9 @@ fn test_equal_split_two_recipients() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::EqualSplit, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
 
     client.add_recipient(&pool_id, &recipient1, &1u32, &creator);
     client.add_recipient(&pool_id, &recipient2, &1u32, &creator);
@@ -288,11 +330,10 @@ fn test_weighted_distribution_success() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     // Create pool with weighted distribution
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::Weighted, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
 
     // Add recipients with different weights
     client.add_recipient(&pool_id, &recipient1, &5u32, &creator); // Weight 5
@@ -336,10 +377,9 @@ fn test_weighted_equal_weights() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::Weighted, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
 
     // Equal weights should behave like equal split
     client.add_recipient(&pool_id, &recipient1, &1u32, &creator);
@@ -410,10 +450,9 @@ fn test_execute_non_creator_fails() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
     client.add_recipient(&pool_id, &recipient1, &100u32, &creator);
 
     // Non-creator tries to execute
@@ -482,10 +521,9 @@ fn test_add_recipient_after_execution_fails() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
     client.add_recipient(&pool_id, &recipient1, &100u32, &creator);
 
     // Execute distribution
@@ -509,10 +547,9 @@ fn test_execute_already_executed_fails() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
     client.add_recipient(&pool_id, &recipient1, &100u32, &creator);
 
     // Execute once
@@ -563,10 +600,9 @@ fn test_cancel_after_execution_fails() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
     client.add_recipient(&pool_id, &recipient1, &100u32, &creator);
 
     // Execute
@@ -592,16 +628,15 @@ fn test_batch_distribute_success() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    // Mint enough tokens for both pools
-    mint_tokens(&env, &token_addr, &contract_id, 2000);
-
     // Create two pools
     let pool_id_1 =
         client.create_payment_pool(&500i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id_1, 500);
     client.add_recipient(&pool_id_1, &recipient1, &100u32, &creator);
 
     let pool_id_2 =
         client.create_payment_pool(&500i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id_2, 500);
     client.add_recipient(&pool_id_2, &recipient2, &100u32, &creator);
 
     // Batch distribute
@@ -635,12 +670,10 @@ fn test_batch_distribute_partial_failure() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    // Only mint enough tokens for one pool
-    mint_tokens(&env, &token_addr, &contract_id, 500);
-
-    // Create two pools
+    // Create two pools, only the first one funded
     let pool_id_1 =
         client.create_payment_pool(&500i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id_1, 500);
     client.add_recipient(&pool_id_1, &recipient1, &100u32, &creator);
 
     let pool_id_2 =
@@ -705,10 +738,9 @@ fn test_execute_no_recipients_fails() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    mint_tokens(&env, &token_addr, &contract_id, 1000);
-
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
 
     // Try to execute without any recipients
     client.execute_distribution(&pool_id, &creator);
@@ -728,7 +760,7 @@ fn test_execute_insufficient_balance_fails() {
     let contract_id = register_and_init_contract(&env);
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
-    // Don't mint tokens to contract
+    // Don't fund the pool
 
     let pool_id =
         client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
@@ -738,6 +770,56 @@ fn test_execute_insufficient_balance_fails() {
     client.execute_distribution(&pool_id, &creator);
 }
 
+#[test]
+#[should_panic(expected = "InsufficientBalance")]
+fn test_execute_cannot_spend_other_pool_funds() {
+    let env = setup_env();
+    let creator = Address::generate(&env);
+    let recipient1 = Address::generate(&env);
+    let token_addr = create_mock_token(&env, &creator);
+    let token = Some(token_addr.clone());
+
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    // The contract holds enough tokens, but they belong to another pool
+    let funded =
+        client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, funded, 1000);
+
+    let pool_id =
+        client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
+    client.add_recipient(&pool_id, &recipient1, &100u32, &creator);
+
+    client.execute_distribution(&pool_id, &creator);
+}
+
+#[test]
+fn test_cancel_refunds_pool_funds() {
+    let env = setup_env();
+    let creator = Address::generate(&env);
+    let recipient1 = Address::generate(&env);
+    let token_addr = create_mock_token(&env, &creator);
+    let token = Some(token_addr.clone());
+
+    env.mock_all_auths();
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let pool_id =
+        client.create_payment_pool(&1000i128, &token, &DistributionRule::Percentage, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 1000);
+    client.add_recipient(&pool_id, &recipient1, &100u32, &creator);
+
+    client.cancel_distribution(&pool_id, &creator);
+
+    assert_eq!(get_token_balance(&env, &token_addr, &creator), 1000);
+    assert_eq!(get_token_balance(&env, &token_addr, &contract_id), 0);
+}
+
 // ============ Precision and Arithmetic Tests ============
 
 #[test]
@@ -872,11 +954,11 @@ fn test_full_payment_lifecycle() {
     let client = StellarGuildsContractClient::new(&env, &contract_id);
 
     // Simulate a completed bounty with 3 contributors splitting reward
-    mint_tokens(&env, &token_addr, &contract_id, 10000);
 
     // Create payment pool with weighted distribution based on contribution
     let pool_id =
         client.create_payment_pool(&10000i128, &token, &DistributionRule::Weighted, &creator);
+    fund_pool(&env, &client, &token_addr, &creator, pool_id, 10000);
 
     // Add contributors with weights based on their contribution
     client.add_recipient(&pool_id, &contributor1, &5u32, &creator); // 50% contribution
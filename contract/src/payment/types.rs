@@ -66,6 +66,15 @@ pub struct PaymentPoolCreatedEvent {
     pub rule: DistributionRule,
 }
 
+/// Event emitted when a pool receives funds
+#[contracttype]
+pub struct PoolFundedEvent {
+    pub pool_id: u64,
+    pub funder: Address,
+    pub amount: i128,
+    pub total_funded: i128,
+}
+
 /// Event emitted when a recipient is added to a pool
 #[contracttype]
 pub struct RecipientAddedEvent {
@@ -94,4 +103,5 @@ pub struct DistributionFailedEvent {
 pub struct PoolCancelledEvent {
     pub pool_id: u64,
     pub cancelled_by: Address,
+    pub refunded: i128,
 }
@@ -11,6 +11,7 @@ use crate::reputation::types::{
     DECAY_NUMERATOR, DECAY_PERIOD_SECS,
 };
 
+use crate::governance::checkpoints::record_weight_checkpoint;
 use crate::governance::types::role_weight;
 
 // ────────────────────── Core Scoring ──────────────────────
@@ -59,6 +60,7 @@ pub fn record_contribution(
     profile.contributions_count += 1;
     profile.last_activity = now;
     store_profile(env, &profile);
+    record_weight_checkpoint(env, guild_id, contributor);
 
     // Emit reputation updated event
     let event = ReputationUpdatedEvent {
@@ -79,29 +81,37 @@ pub fn record_contribution(
 
 // ────────────────────── Decay ──────────────────────
 
-/// Apply time-based decay to a profile's decayed_score.
+/// Decay a score last decayed at `from` up to `to`.
 /// Uses iterative multiplication by 99/100 per elapsed period.
-fn apply_decay_to_profile(profile: &mut ReputationProfile, now: u64) {
-    if now <= profile.last_decay_applied {
-        return;
+pub fn decay_score(score: u64, from: u64, to: u64) -> u64 {
+    if to <= from {
+        return score;
     }
 
-    let elapsed = now - profile.last_decay_applied;
-    let periods = elapsed / DECAY_PERIOD_SECS;
-
-    if periods == 0 {
-        return;
-    }
+    let periods = (to - from) / DECAY_PERIOD_SECS;
 
     // Cap iterations to avoid excessive gas usage
     let capped_periods = if periods > 52 { 52 } else { periods };
 
-    let mut score = profile.decayed_score;
+    let mut score = score;
     for _ in 0..capped_periods {
         score = (score * DECAY_NUMERATOR) / DECAY_DENOMINATOR;
     }
+    score
+}
+
+/// Apply time-based decay to a profile's decayed_score.
+fn apply_decay_to_profile(profile: &mut ReputationProfile, now: u64) {
+    if now <= profile.last_decay_applied {
+        return;
+    }
+
+    let elapsed = now - profile.last_decay_applied;
+    if elapsed / DECAY_PERIOD_SECS == 0 {
+        return;
+    }
 
-    profile.decayed_score = score;
+    profile.decayed_score = decay_score(profile.decayed_score, profile.last_decay_applied, now);
     profile.last_decay_applied = now;
 }
 
@@ -264,7 +274,7 @@ fn maybe_award_badge(
 // ────────────────────── Helpers ──────────────────────
 
 /// Integer square root using Newton's method.
-fn integer_sqrt(n: u64) -> u64 {
+pub(crate) fn integer_sqrt(n: u64) -> u64 {
     if n == 0 {
         return 0;
     }
@@ -11,6 +11,7 @@ pub enum ContributionType {
     ProposalCreated,
     VoteCast,
     DisputeResolved,
+    BountyReviewed,
 }
 
 // ────────────────────── Scoring Constants ──────────────────────
@@ -21,6 +22,7 @@ pub const POINTS_MILESTONE_APPROVED: u32 = 50;
 pub const POINTS_PROPOSAL_CREATED: u32 = 20;
 pub const POINTS_VOTE_CAST: u32 = 5;
 pub const POINTS_DISPUTE_RESOLVED: u32 = 30;
+pub const POINTS_BOUNTY_REVIEWED: u32 = 15;
 
 /// Decay: 1% per period, applied lazily
 pub const DECAY_PERIOD_SECS: u64 = 604_800; // 1 week
@@ -121,5 +123,6 @@ pub fn points_for_contribution(ct: &ContributionType) -> u32 {
         ContributionType::ProposalCreated => POINTS_PROPOSAL_CREATED,
         ContributionType::VoteCast => POINTS_VOTE_CAST,
         ContributionType::DisputeResolved => POINTS_DISPUTE_RESOLVED,
+        ContributionType::BountyReviewed => POINTS_BOUNTY_REVIEWED,
     }
 }
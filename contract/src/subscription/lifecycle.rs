@@ -1,3 +1,7 @@
+use crate::guild::membership::has_permission;
+use crate::guild::storage::resolve_token;
+use crate::guild::types::Role;
+use crate::ledger::{self, SubAccount};
 use crate::subscription::storage::{
     add_active_subscription, add_guild_revenue, add_plan_to_guild, get_next_plan_id,
     get_next_revenue_record_id, get_next_subscription_id, get_plan, get_retry_config,
@@ -10,7 +14,10 @@ use crate::subscription::types::{
     SubscriptionCancelledEvent, SubscriptionChange, SubscriptionCreatedEvent, SubscriptionError,
     SubscriptionPlan, SubscriptionStatus, TierChangedEvent,
 };
-use soroban_sdk::{token, Address, Env, String, Vec};
+use crate::treasury::management::credit_from_sub_account;
+use crate::treasury::storage::get_treasury;
+use crate::treasury::types::TransactionType;
+use soroban_sdk::{Address, Env, String, Vec};
 
 /// Create a new subscription plan
 ///
@@ -179,7 +186,13 @@ pub fn process_payment(
         return Err(SubscriptionError::InvalidState);
     }
 
-    let payment_result = execute_payment(env, &subscription.subscriber, plan.price, &plan.token);
+    let payment_result = execute_payment(
+        env,
+        plan.guild_id,
+        &subscription.subscriber,
+        plan.price,
+        &plan.token,
+    );
 
     let now = env.ledger().timestamp();
 
@@ -267,24 +280,26 @@ pub fn process_payment(
     }
 }
 
-/// Execute the actual token transfer for payment
+/// Execute the actual token transfer for payment into the guild's
+/// subscription revenue sub-account
 fn execute_payment(
     env: &Env,
+    guild_id: u64,
     from: &Address,
     amount: i128,
     token: &Option<Address>,
 ) -> Result<(), ()> {
     from.require_auth();
 
-    if let Some(token_addr) = token {
-        // Transfer custom token
-        let token_client = token::Client::new(env, token_addr);
-        token_client.transfer(from, &env.current_contract_address(), &amount);
-    } else {
-        // For native XLM, we would need additional handling
-        // In a real implementation, this would check and transfer XLM
-        // For now, we assume the contract has a way to receive native tokens
-    }
+    // `None` charges native XLM through its Stellar Asset Contract
+    let token_addr = resolve_token(env, token);
+    ledger::deposit(
+        env,
+        &SubAccount::SubscriptionRevenue(guild_id),
+        &token_addr,
+        from,
+        amount,
+    );
 
     Ok(())
 }
@@ -640,6 +655,49 @@ pub fn process_due_subscriptions(env: &Env, limit: u32) -> u32 {
     processed
 }
 
+/// Move the subscription revenue a guild has collected in `token` into one
+/// of its treasuries, recorded there as an executed `Deposit`
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `guild_id` - Guild whose revenue is swept
+/// * `treasury_id` - Treasury of the same guild receiving the revenue
+/// * `token` - Token to sweep (None for native XLM)
+/// * `caller` - Guild owner or admin
+///
+/// # Returns
+/// The amount swept (0 if there was no revenue)
+pub fn sweep_revenue(
+    env: &Env,
+    guild_id: u64,
+    treasury_id: u64,
+    token: Option<Address>,
+    caller: Address,
+) -> Result<i128, SubscriptionError> {
+    if !has_permission(env, guild_id, caller, Role::Admin) {
+        return Err(SubscriptionError::Unauthorized);
+    }
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.guild_id != guild_id {
+        return Err(SubscriptionError::Unauthorized);
+    }
+
+    let account = SubAccount::SubscriptionRevenue(guild_id);
+    let amount = ledger::balance(env, &account, &resolve_token(env, &token));
+    credit_from_sub_account(
+        env,
+        treasury_id,
+        &account,
+        &token,
+        amount,
+        TransactionType::Deposit,
+        "subscription_revenue",
+        guild_id,
+    );
+
+    Ok(amount)
+}
+
 /// Get subscription status summary
 ///
 /// # Arguments
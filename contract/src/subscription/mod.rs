@@ -55,7 +55,7 @@ pub use storage::{
 pub use lifecycle::{
     cancel_subscription, change_tier, create_plan, days_until_billing, get_subscription_status,
     is_subscription_active, pause_subscription, process_due_subscriptions, process_payment,
-    resume_subscription, retry_payment, subscribe,
+    resume_subscription, retry_payment, subscribe, sweep_revenue,
 };
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
+use crate::ledger::SubAccount;
 use crate::subscription::types::{BillingCycle, MembershipTier, SubscriptionStatus};
 use crate::{StellarGuildsContract, StellarGuildsContractClient};
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::StellarAssetClient;
 use soroban_sdk::{Address, Env, String, Vec};
 
 fn setup_env() -> Env {
@@ -12,7 +14,10 @@ fn setup_env() -> Env {
 fn register_and_init_contract(env: &Env) -> Address {
     let contract_id = env.register_contract(None, StellarGuildsContract);
     let client = StellarGuildsContractClient::new(env, &contract_id);
-    client.initialize();
+    let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client
+        .mock_all_auths()
+        .initialize(&Address::generate(env), &native.address());
     contract_id
 }
 
@@ -623,3 +628,56 @@ fn test_change_tier_unauthorized() {
     // Try to change tier with different user - should panic
     let _ = client.change_subscription_tier(&subscription_id, &premium_plan_id, &true, &other_user);
 }
+
+#[test]
+fn test_sweep_revenue_into_treasury() {
+    let env = setup_env();
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+    let owner = Address::generate(&env);
+    let subscriber = Address::generate(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000_000);
+    env.mock_all_auths();
+
+    let guild_id = client.create_guild(
+        &String::from_str(&env, "Guild"),
+        &String::from_str(&env, "A guild"),
+        &owner,
+    );
+    let mut signers = Vec::new(&env);
+    signers.push_back(owner.clone());
+    let treasury_id = client.initialize_treasury(&guild_id, &signers, &1u32);
+
+    let plan_id = create_test_plan(
+        &env,
+        &client,
+        &owner,
+        guild_id,
+        MembershipTier::Standard,
+        1000,
+        BillingCycle::Monthly,
+    );
+    let subscription_id = client.subscribe(&plan_id, &subscriber, &true);
+    StellarAssetClient::new(&env, &client.get_native_token()).mint(&subscriber, &1000);
+    assert!(client.process_subscription_payment(&subscription_id));
+
+    let revenue = SubAccount::SubscriptionRevenue(guild_id);
+    assert_eq!(client.get_sub_account_balance(&revenue, &None), 1000);
+
+    // Only guild owners and admins can sweep
+    assert!(client
+        .try_sweep_subscription_revenue(&guild_id, &treasury_id, &None, &subscriber)
+        .is_err());
+
+    let swept = client.sweep_subscription_revenue(&guild_id, &treasury_id, &None, &owner);
+    assert_eq!(swept, 1000);
+    assert_eq!(client.get_sub_account_balance(&revenue, &None), 0);
+    assert_eq!(client.get_treasury_balance(&treasury_id, &None), 1000);
+
+    // Nothing left to sweep
+    assert_eq!(
+        client.sweep_subscription_revenue(&guild_id, &treasury_id, &None, &owner),
+        0
+    );
+}
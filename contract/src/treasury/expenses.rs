@@ -0,0 +1,272 @@
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::guild::storage::has_member;
+use crate::treasury::management::{pay_from_treasury, propose_expense_payment};
+use crate::treasury::multisig::expire_if_needed;
+use crate::treasury::storage::{
+    get_budget, get_expense, get_expense_approvers, get_member_expenses, get_next_expense_id,
+    get_transaction, get_treasury, get_treasury_expenses,
+    set_expense_approvers as store_expense_approvers, store_expense,
+};
+use crate::treasury::types::{
+    ExpenseApproversUpdatedEvent, ExpenseClaim, ExpenseReviewedEvent, ExpenseStatus,
+    ExpenseSubmittedEvent, TransactionStatus, TreasuryError,
+};
+
+/// Designate the addresses that may approve or reject expense claims on a
+/// treasury, in addition to its signers. Only the treasury owner may change
+/// the list; an empty list leaves review to the signers.
+pub fn set_expense_approvers(
+    env: &Env,
+    treasury_id: u64,
+    caller: Address,
+    approvers: Vec<Address>,
+) -> bool {
+    caller.require_auth();
+
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.owner != caller {
+        panic!("only owner can set expense approvers");
+    }
+
+    store_expense_approvers(env, treasury_id, &approvers);
+
+    let event = ExpenseApproversUpdatedEvent {
+        treasury_id,
+        approvers,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "exp_apprs")),
+        event,
+    );
+
+    true
+}
+
+/// Submit a claim to be reimbursed from the treasury for a cost the guild
+/// member paid out of pocket. `category` names the budget the payout is
+/// charged to and must have a budget set. Returns the claim ID.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_expense_claim(
+    env: &Env,
+    treasury_id: u64,
+    claimant: Address,
+    amount: i128,
+    token: Option<Address>,
+    category: String,
+    receipt_hash: BytesN<32>,
+    description: String,
+) -> u64 {
+    claimant.require_auth();
+
+    if amount <= 0 {
+        panic!("amount must be positive");
+    }
+    if category.is_empty() {
+        panic!("category required");
+    }
+
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+    if !has_member(env, treasury.guild_id, &claimant) {
+        panic!("claimant is not a guild member");
+    }
+    require_budget(env, treasury_id, &category);
+
+    let claim_id = get_next_expense_id(env);
+    let claim = ExpenseClaim {
+        id: claim_id,
+        treasury_id,
+        claimant: claimant.clone(),
+        amount,
+        token: token.clone(),
+        category: category.clone(),
+        receipt_hash,
+        description,
+        status: ExpenseStatus::Pending,
+        reviewed_by: claimant.clone(),
+        reviewed_at: 0,
+        payment_tx_id: 0,
+        created_at: env.ledger().timestamp(),
+    };
+    store_expense(env, &claim);
+
+    let event = ExpenseSubmittedEvent {
+        treasury_id,
+        claim_id,
+        claimant,
+        amount,
+        token,
+        category,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "exp_submit")),
+        event,
+    );
+
+    claim_id
+}
+
+/// Approve a pending claim and reimburse the claimant from the treasury,
+/// charged to the claim's budget category. The approver must be a designated
+/// expense approver or a treasury signer and cannot approve their own claim.
+///
+/// A claim at or above the treasury's high-value threshold is not paid
+/// directly: it becomes `Approved` and an `ExpensePayment` transaction needing
+/// the signer threshold and timelock pays it. If that transaction is rejected,
+/// vetoed or expires, the claim is pending review again. Returns the ID of the
+/// transaction paying the claim.
+pub fn approve_expense_claim(env: &Env, claim_id: u64, approver: Address) -> u64 {
+    let mut claim = load_for_review(env, claim_id, &approver);
+    let treasury = get_treasury(env, claim.treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+    require_budget(env, claim.treasury_id, &claim.category);
+
+    if claim.amount >= treasury.high_value_threshold_for(&claim.token) {
+        let payment_tx_id = propose_expense_payment(env, &treasury, &claim, approver.clone());
+        claim.status = ExpenseStatus::Approved;
+        claim.payment_tx_id = payment_tx_id;
+        finish_review(env, &mut claim, approver);
+        return payment_tx_id;
+    }
+
+    let payment_tx_id = pay_from_treasury(
+        env,
+        claim.treasury_id,
+        &claim.token,
+        &claim.claimant,
+        claim.amount,
+        &claim.category,
+        "expense",
+        claim_id,
+    )
+    .unwrap_or_else(|e| match e {
+        TreasuryError::BudgetExceeded => panic!("budget exceeded"),
+        TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+        TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
+    });
+
+    claim.status = ExpenseStatus::Paid;
+    claim.payment_tx_id = payment_tx_id;
+    finish_review(env, &mut claim, approver);
+
+    payment_tx_id
+}
+
+/// Reject a pending claim. Same reviewers as `approve_expense_claim`.
+pub fn reject_expense_claim(env: &Env, claim_id: u64, approver: Address) -> bool {
+    let mut claim = load_for_review(env, claim_id, &approver);
+    claim.status = ExpenseStatus::Rejected;
+    finish_review(env, &mut claim, approver);
+    true
+}
+
+/// Claims submitted by a member across all treasuries, oldest first.
+pub fn get_member_expense_claims(env: &Env, member: Address) -> Vec<ExpenseClaim> {
+    get_member_expenses(env, &member)
+}
+
+/// Claims on a treasury with the given status, oldest first.
+pub fn get_expense_claims_by_status(
+    env: &Env,
+    treasury_id: u64,
+    status: ExpenseStatus,
+) -> Vec<ExpenseClaim> {
+    let mut result = Vec::new(env);
+    for claim in get_treasury_expenses(env, treasury_id).iter() {
+        if claim.status == status {
+            result.push_back(claim);
+        }
+    }
+    result
+}
+
+/// Mark an approved claim paid once its `ExpensePayment` transaction executes.
+pub(crate) fn mark_expense_paid(env: &Env, claim_id: u64) {
+    let mut claim = get_expense(env, claim_id).expect("expense claim not found");
+    claim.status = ExpenseStatus::Paid;
+    store_expense(env, &claim);
+}
+
+/// Return an approved claim to `Pending` once its `ExpensePayment`
+/// transaction is rejected or vetoed, so reviewers can decide on it again.
+pub(crate) fn reopen_expense_claim(env: &Env, claim_id: u64) {
+    let mut claim = get_expense(env, claim_id).expect("expense claim not found");
+    if claim.status == ExpenseStatus::Approved {
+        reopen(&mut claim);
+        store_expense(env, &claim);
+    }
+}
+
+fn reopen(claim: &mut ExpenseClaim) {
+    claim.status = ExpenseStatus::Pending;
+    claim.payment_tx_id = 0;
+}
+
+/// Whether an approved claim's payment transaction closed without paying it.
+/// Transactions only expire lazily, so an expired payment is caught here.
+fn payment_lapsed(env: &Env, claim: &ExpenseClaim) -> bool {
+    let mut tx = get_transaction(env, claim.payment_tx_id).expect("tx not found");
+    expire_if_needed(&mut tx, env.ledger().timestamp());
+    matches!(
+        tx.status,
+        TransactionStatus::Rejected | TransactionStatus::Expired
+    )
+}
+
+/// Claims are only paid from a category with a budget, so every
+/// reimbursement is capped by an allocation the signers set.
+fn require_budget(env: &Env, treasury_id: u64, category: &String) {
+    let has_budget = get_budget(env, treasury_id, category)
+        .map(|budget| budget.allocated_amount > 0)
+        .unwrap_or(false);
+    if !has_budget {
+        panic!("expense category has no budget");
+    }
+}
+
+fn load_for_review(env: &Env, claim_id: u64, approver: &Address) -> ExpenseClaim {
+    approver.require_auth();
+
+    let mut claim = get_expense(env, claim_id).expect("expense claim not found");
+    if claim.status == ExpenseStatus::Approved && payment_lapsed(env, &claim) {
+        reopen(&mut claim);
+    }
+    if claim.status != ExpenseStatus::Pending {
+        panic!("expense claim not pending");
+    }
+    if &claim.claimant == approver {
+        panic!("cannot review own expense claim");
+    }
+
+    let treasury = get_treasury(env, claim.treasury_id).expect("treasury not found");
+    let is_approver = get_expense_approvers(env, claim.treasury_id)
+        .iter()
+        .any(|a| &a == approver);
+    if !is_approver && !treasury.is_signer(approver) {
+        panic!("caller is not an expense approver");
+    }
+
+    claim
+}
+
+fn finish_review(env: &Env, claim: &mut ExpenseClaim, reviewer: Address) {
+    claim.reviewed_by = reviewer.clone();
+    claim.reviewed_at = env.ledger().timestamp();
+    store_expense(env, claim);
+
+    let event = ExpenseReviewedEvent {
+        claim_id: claim.id,
+        reviewer,
+        status: claim.status.clone(),
+        payment_tx_id: claim.payment_tx_id,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "exp_review")),
+        event,
+    );
+}
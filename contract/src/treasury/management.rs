@@ -1,20 +1,29 @@
-use soroban_sdk::{token::Client as TokenClient, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
 
 use crate::analytics::storage::store_snapshot;
 use crate::analytics::types::TreasurySnapshot;
+use crate::guild::membership::has_permission;
+use crate::guild::storage::resolve_token;
+use crate::guild::types::Role;
+use crate::ledger::{self, SubAccount};
 
 use crate::treasury::multisig::{
-    add_approval, assert_signer, ensure_is_signer, expire_if_needed, required_approvals_for_tx,
-    validate_threshold, TX_EXPIRY_SECONDS,
+    add_approval, add_rejection, approval_impossible, assert_signer, current_approvals,
+    ensure_is_signer, expire_if_needed, is_timelocked, required_approvals_for_tx, timelock_for_tx,
+    validate_threshold, MAX_BATCH_LEGS, MAX_TIMELOCK_SECONDS, TX_EXPIRY_SECONDS,
 };
 use crate::treasury::storage::{
-    get_allowance, get_budget, get_next_treasury_id, get_next_tx_id, get_treasury,
+    get_allowance, get_budget, get_expense, get_next_treasury_id, get_next_tx_id, get_treasury,
     get_treasury_transactions, store_allowance, store_budget, store_transaction, store_treasury,
 };
 use crate::treasury::types::{
-    Allowance, Budget, DepositEvent, EmergencyPauseEvent, Transaction, TransactionApprovedEvent,
-    TransactionExecutedEvent, TransactionStatus, TransactionType, Treasury, TreasuryError,
-    TreasuryInitializedEvent, WithdrawalProposedEvent,
+    Allowance, BatchWithdrawalProposedEvent, BountyFundingProposedEvent, Budget,
+    ConfigChangeProposedEvent, DepositEvent, EmergencyPauseEvent, ExpenseClaim,
+    HighValueThresholdUpdatedEvent, InternalTransferEvent, InternalTransferProposedEvent,
+    SignersUpdatedEvent, TimelockUpdatedEvent, Transaction, TransactionApprovedEvent,
+    TransactionExecutedEvent, TransactionRejectedEvent, TransactionStatus,
+    TransactionTimelockedEvent, TransactionType, TransactionVetoedEvent, Treasury, TreasuryError,
+    TreasuryInitializedEvent, WithdrawalLeg, WithdrawalProposedEvent,
 };
 
 pub fn initialize_treasury(
@@ -23,9 +32,12 @@ pub fn initialize_treasury(
     signers: Vec<Address>,
     approval_threshold: u32,
 ) -> u64 {
-    // First signer is the owner
+    // First signer is the owner and must run the guild
     let owner = signers.get(0).expect("at least one signer required");
     owner.require_auth();
+    if !has_permission(env, guild_id, owner.clone(), Role::Admin) {
+        panic!("only guild owner or admin can create a treasury");
+    }
 
     let mut unique_signers = Vec::new(env);
     for addr in signers.iter() {
@@ -49,117 +61,1002 @@ pub fn initialize_treasury(
         signers: unique_signers,
         approval_threshold,
         high_value_threshold,
+        token_high_value_thresholds: soroban_sdk::Map::new(env),
         balance_xlm: 0,
         token_balances: soroban_sdk::Map::new(env),
         total_deposits: 0,
+        total_refunds: 0,
         total_withdrawals: 0,
         paused: false,
+        timelock_seconds: 0,
+    };
+
+    store_treasury(env, &treasury);
+
+    let event = TreasuryInitializedEvent {
+        treasury_id: id,
+        guild_id,
+        owner,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "init")),
+        event,
+    );
+
+    id
+}
+
+pub fn deposit(
+    env: &Env,
+    treasury_id: u64,
+    depositor: Address,
+    amount: i128,
+    token: Option<Address>,
+) -> bool {
+    depositor.require_auth();
+    if amount <= 0 {
+        panic!("amount must be positive");
+    }
+
+    let mut treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+
+    ledger::deposit(
+        env,
+        &SubAccount::Treasury(treasury_id),
+        &resolve_token(env, &token),
+        &depositor,
+        amount,
+    );
+
+    match token {
+        Some(ref token_addr) => {
+            let mut balances = treasury.token_balances.clone();
+            let current = balances.get(token_addr.clone()).unwrap_or(0i128);
+            balances.set(token_addr.clone(), current + amount);
+            treasury.token_balances = balances;
+        }
+        None => {
+            treasury.balance_xlm += amount;
+        }
+    }
+
+    treasury.total_deposits += amount;
+    store_treasury(env, &treasury);
+
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type: TransactionType::Deposit,
+        amount,
+        token: token.clone(),
+        recipient: Some(env.current_contract_address()),
+        proposer: depositor.clone(),
+        approvals: Vec::new(env),
+        rejections: Vec::new(env),
+        status: TransactionStatus::Executed,
+        created_at: now,
+        expires_at: now,
+        reason: String::from_str(env, "deposit"),
+        reference_id: None,
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    // Record analytics snapshot after deposit
+    record_snapshot(env, &treasury);
+
+    let event = DepositEvent {
+        treasury_id,
+        from: depositor,
+        amount,
+        token,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "deposit")),
+        event,
+    );
+
+    true
+}
+
+pub fn propose_withdrawal(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    recipient: Address,
+    amount: i128,
+    token: Option<Address>,
+    reason: String,
+) -> u64 {
+    if amount <= 0 {
+        panic!("amount must be positive");
+    }
+
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+
+    assert_signer(env, &treasury, &proposer);
+
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type: TransactionType::Withdrawal,
+        amount,
+        token: token.clone(),
+        recipient: Some(recipient.clone()),
+        proposer: proposer.clone(),
+        approvals,
+        rejections: Vec::new(env),
+        status: TransactionStatus::Pending,
+        created_at: now,
+        expires_at: now + TX_EXPIRY_SECONDS,
+        reason,
+        reference_id: None,
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    let event = WithdrawalProposedEvent {
+        treasury_id,
+        tx_id,
+        proposer,
+        recipient,
+        amount,
+        token,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "withdraw")),
+        event,
+    );
+
+    tx_id
+}
+
+/// Propose an `InternalTransfer` moving funds to another treasury of the same
+/// guild. The balances move inside the contract without a token round-trip,
+/// and the transfer shows in both histories without counting as a withdrawal
+/// or deposit. It needs the sending treasury's full approval threshold.
+pub fn propose_internal_transfer(
+    env: &Env,
+    from_treasury_id: u64,
+    to_treasury_id: u64,
+    proposer: Address,
+    amount: i128,
+    token: Option<Address>,
+    reason: String,
+) -> u64 {
+    if amount <= 0 {
+        panic!("amount must be positive");
+    }
+    if from_treasury_id == to_treasury_id {
+        panic!("cannot transfer to the same treasury");
+    }
+
+    let treasury = get_treasury(env, from_treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+    let destination = get_treasury(env, to_treasury_id).expect("destination treasury not found");
+    if destination.guild_id != treasury.guild_id {
+        panic!("treasuries belong to different guilds");
+    }
+
+    assert_signer(env, &treasury, &proposer);
+
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id: from_treasury_id,
+        tx_type: TransactionType::InternalTransfer,
+        amount,
+        token: token.clone(),
+        recipient: None,
+        proposer: proposer.clone(),
+        approvals,
+        rejections: Vec::new(env),
+        status: TransactionStatus::Pending,
+        created_at: now,
+        expires_at: now + TX_EXPIRY_SECONDS,
+        reason,
+        reference_id: Some(to_treasury_id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    let event = InternalTransferProposedEvent {
+        from_treasury_id,
+        to_treasury_id,
+        tx_id,
+        proposer,
+        amount,
+        token,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "xfer_prop")),
+        event,
+    );
+
+    tx_id
+}
+
+/// Propose a `BatchWithdrawal` paying every leg in one transaction.
+///
+/// The batch collects approvals once and executes atomically. Legs may pay
+/// different tokens: the balance, the "withdrawal" budget and the proposer's
+/// allowance are checked against each token's total here and again on
+/// execution, and if any leg cannot be paid the whole batch fails.
+pub fn propose_batch_withdrawal(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    legs: Vec<WithdrawalLeg>,
+    reason: String,
+) -> u64 {
+    if legs.is_empty() {
+        panic!("at least one leg required");
+    }
+    if legs.len() > MAX_BATCH_LEGS {
+        panic!("too many legs");
+    }
+    let mut total_amount = 0i128;
+    for leg in legs.iter() {
+        if leg.amount <= 0 {
+            panic!("amount must be positive");
+        }
+        total_amount += leg.amount;
+    }
+
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+
+    assert_signer(env, &treasury, &proposer);
+
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+    let leg_count = legs.len();
+
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type: TransactionType::BatchWithdrawal,
+        amount: total_amount,
+        token: None,
+        recipient: None,
+        proposer: proposer.clone(),
+        approvals,
+        rejections: Vec::new(env),
+        status: TransactionStatus::Pending,
+        created_at: now,
+        expires_at: now + TX_EXPIRY_SECONDS,
+        reason,
+        reference_id: None,
+        executable_at: 0,
+        replaces: None,
+        legs,
+    };
+    check_batch_totals(env, &treasury, &tx, &proposer);
+    store_transaction(env, &tx);
+
+    let event = BatchWithdrawalProposedEvent {
+        treasury_id,
+        tx_id,
+        proposer,
+        leg_count,
+        total_amount,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "batch_prop")),
+        event,
+    );
+
+    tx_id
+}
+
+/// Propose a `BountyFunding` transaction that moves `amount` of `token` from the
+/// treasury into the escrow of `bounty_id` once approved and executed.
+///
+/// The proposer must already be authorized by the caller (the bounty creator).
+pub fn propose_bounty_funding(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    bounty_id: u64,
+    amount: i128,
+    token: Address,
+) -> u64 {
+    if amount <= 0 {
+        panic!("amount must be positive");
+    }
+
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+
+    ensure_is_signer(&treasury, &proposer);
+
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type: TransactionType::BountyFunding,
+        amount,
+        token: Some(token.clone()),
+        recipient: Some(env.current_contract_address()),
+        proposer: proposer.clone(),
+        approvals,
+        rejections: Vec::new(env),
+        status: TransactionStatus::Pending,
+        created_at: now,
+        expires_at: now + TX_EXPIRY_SECONDS,
+        reason: String::from_str(env, "bounty_funding"),
+        reference_id: Some(bounty_id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    let event = BountyFundingProposedEvent {
+        treasury_id,
+        tx_id,
+        bounty_id,
+        proposer,
+        amount,
+        token,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "bnty_fund")),
+        event,
+    );
+
+    tx_id
+}
+
+/// Reject a still-open `BountyFunding` transaction once its bounty is cancelled
+/// or expired, so signers cannot execute it against a closed bounty.
+pub fn withdraw_bounty_funding(env: &Env, tx_id: u64) {
+    let mut tx = crate::treasury::storage::get_transaction(env, tx_id).expect("tx not found");
+    if matches!(
+        tx.status,
+        TransactionStatus::Pending | TransactionStatus::Approved
+    ) {
+        tx.status = TransactionStatus::Rejected;
+        store_transaction(env, &tx);
+    }
+}
+
+/// Fund a bounty straight from the treasury under a signer's allowance, without a
+/// multisig proposal. Used for bounties auto-spawned from recurring templates.
+///
+/// Unlike `execute_transaction`, a matching treasury allowance for `admin` is
+/// required: it is what pre-authorizes the spend. Records an executed
+/// `BountyFunding` transaction linked to the bounty and returns its id.
+pub fn fund_bounty_from_allowance(
+    env: &Env,
+    treasury_id: u64,
+    admin: &Address,
+    bounty_id: u64,
+    token: &Address,
+    amount: i128,
+) -> u64 {
+    if amount <= 0 {
+        panic!("amount must be positive");
+    }
+
+    let mut treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+
+    let token_opt = Some(token.clone());
+    if get_allowance(env, treasury_id, admin, &token_opt).is_none() {
+        panic!("allowance not found");
+    }
+
+    let category = String::from_str(env, "bounty");
+    enforce_budget(env, treasury_id, &category, amount).unwrap_or_else(|e| match e {
+        TreasuryError::BudgetExceeded => panic!("budget exceeded"),
+        TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+        TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
+    });
+
+    let op_type = crate::allowance::AllowanceOperation::BountyFunding;
+    enforce_allowance(env, treasury_id, admin, &token_opt, amount, &op_type).unwrap_or_else(|e| {
+        match e {
+            TreasuryError::BudgetExceeded => panic!("budget exceeded"),
+            TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+            TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
+        }
+    });
+
+    let current = treasury.token_balances.get(token.clone()).unwrap_or(0i128);
+    if current < amount {
+        panic!("insufficient treasury balance");
+    }
+    treasury.token_balances.set(token.clone(), current - amount);
+    treasury.total_withdrawals += amount;
+    store_treasury(env, &treasury);
+
+    ledger::transfer_internal(
+        env,
+        &SubAccount::Treasury(treasury_id),
+        &SubAccount::Bounty(bounty_id),
+        token,
+        amount,
+    );
+
+    crate::bounty::apply_treasury_funding(env, bounty_id, treasury_id, token, amount);
+
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type: TransactionType::BountyFunding,
+        amount,
+        token: token_opt,
+        recipient: Some(env.current_contract_address()),
+        proposer: admin.clone(),
+        approvals: Vec::new(env),
+        rejections: Vec::new(env),
+        status: TransactionStatus::Executed,
+        created_at: now,
+        expires_at: now,
+        reason: String::from_str(env, "bounty_auto_funding"),
+        reference_id: Some(bounty_id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    record_snapshot(env, &treasury);
+
+    let event = TransactionExecutedEvent { treasury_id, tx_id };
+    env.events().publish(
+        (
+            Symbol::new(env, "treasury"),
+            Symbol::new(env, "tx_executed"),
+        ),
+        event,
+    );
+
+    tx_id
+}
+
+/// Credit bounty-held funds (refunded escrow or a slashed claim bond) to a treasury.
+///
+/// Tokens never leave the contract: they move from the bounty's sub-account to
+/// the treasury's and an executed transaction of `tx_type` (`Refund` for
+/// escrow, `Deposit` for a slashed bond) linked to the bounty is recorded.
+pub fn credit_bounty_refund(
+    env: &Env,
+    treasury_id: u64,
+    bounty_id: u64,
+    token: &Address,
+    amount: i128,
+    tx_type: TransactionType,
+    reason: &str,
+) {
+    credit_from_sub_account(
+        env,
+        treasury_id,
+        &SubAccount::Bounty(bounty_id),
+        &Some(token.clone()),
+        amount,
+        tx_type,
+        reason,
+        bounty_id,
+    );
+}
+
+/// Move funds another module holds for a treasury (bounty escrow, a cancelled
+/// stream, revoked vesting, a forfeited deposit) into the treasury's
+/// sub-account and balances, recorded as an executed `tx_type` transaction
+/// linked to `reference_id`. A `Refund` returns the treasury's own money and
+/// counts toward `total_refunds`; anything else is new income and counts
+/// toward `total_deposits`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn credit_from_sub_account(
+    env: &Env,
+    treasury_id: u64,
+    from: &SubAccount,
+    token: &Option<Address>,
+    amount: i128,
+    tx_type: TransactionType,
+    reason: &str,
+    reference_id: u64,
+) {
+    if amount <= 0 {
+        return;
+    }
+
+    ledger::transfer_internal(
+        env,
+        from,
+        &SubAccount::Treasury(treasury_id),
+        &resolve_token(env, token),
+        amount,
+    );
+
+    let mut treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    match token {
+        Some(ref token_addr) => {
+            let current = treasury
+                .token_balances
+                .get(token_addr.clone())
+                .unwrap_or(0i128);
+            treasury
+                .token_balances
+                .set(token_addr.clone(), current + amount);
+        }
+        None => treasury.balance_xlm += amount,
+    }
+    if tx_type == TransactionType::Refund {
+        treasury.total_refunds += amount;
+    } else {
+        treasury.total_deposits += amount;
+    }
+    store_treasury(env, &treasury);
+
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type,
+        amount,
+        token: token.clone(),
+        recipient: Some(env.current_contract_address()),
+        proposer: env.current_contract_address(),
+        approvals: Vec::new(env),
+        rejections: Vec::new(env),
+        status: TransactionStatus::Executed,
+        created_at: now,
+        expires_at: now,
+        reason: String::from_str(env, reason),
+        reference_id: Some(reference_id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    record_snapshot(env, &treasury);
+
+    let event = DepositEvent {
+        treasury_id,
+        from: env.current_contract_address(),
+        amount,
+        token: token.clone(),
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "deposit")),
+        event,
+    );
+}
+
+/// Pay `amount` from a treasury straight to `recipient`, charged to the
+/// `category` budget and recorded as an executed `Withdrawal` linked to
+/// `reference_id`. Used for payouts approved outside the multisig flow
+/// (payroll installments, expense claims). Nothing changes when the balance
+/// or the budget is insufficient. Returns the recorded transaction ID.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pay_from_treasury(
+    env: &Env,
+    treasury_id: u64,
+    token: &Option<Address>,
+    recipient: &Address,
+    amount: i128,
+    category: &String,
+    reason: &str,
+    reference_id: u64,
+) -> Result<u64, TreasuryError> {
+    let mut treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    let balance = match token {
+        Some(ref token_addr) => treasury.token_balances.get(token_addr.clone()).unwrap_or(0),
+        None => treasury.balance_xlm,
+    };
+    if balance < amount {
+        return Err(TreasuryError::InsufficientBalance);
+    }
+
+    enforce_budget(env, treasury_id, category, amount)?;
+
+    match token {
+        Some(ref token_addr) => treasury
+            .token_balances
+            .set(token_addr.clone(), balance - amount),
+        None => treasury.balance_xlm -= amount,
+    }
+    treasury.total_withdrawals += amount;
+    store_treasury(env, &treasury);
+
+    ledger::withdraw(
+        env,
+        &SubAccount::Treasury(treasury_id),
+        &resolve_token(env, token),
+        recipient,
+        amount,
+    );
+
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type: TransactionType::Withdrawal,
+        amount,
+        token: token.clone(),
+        recipient: Some(recipient.clone()),
+        proposer: env.current_contract_address(),
+        approvals: Vec::new(env),
+        rejections: Vec::new(env),
+        status: TransactionStatus::Executed,
+        created_at: now,
+        expires_at: now,
+        reason: String::from_str(env, reason),
+        reference_id: Some(reference_id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    record_snapshot(env, &treasury);
+
+    Ok(tx_id)
+}
+
+/// Propose the `ExpensePayment` transaction reimbursing a high-value expense
+/// claim. It goes through the signer threshold and timelock like any other
+/// high-value outflow; the reviewer's approval counts only if they are a
+/// signer. Returns the transaction ID.
+pub(crate) fn propose_expense_payment(
+    env: &Env,
+    treasury: &Treasury,
+    claim: &ExpenseClaim,
+    reviewer: Address,
+) -> u64 {
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    if treasury.is_signer(&reviewer) {
+        approvals.push_back(reviewer.clone());
+    }
+
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id: treasury.id,
+        tx_type: TransactionType::ExpensePayment,
+        amount: claim.amount,
+        token: claim.token.clone(),
+        recipient: Some(claim.claimant.clone()),
+        proposer: reviewer.clone(),
+        approvals,
+        rejections: Vec::new(env),
+        status: TransactionStatus::Pending,
+        created_at: now,
+        expires_at: now + TX_EXPIRY_SECONDS,
+        reason: String::from_str(env, "expense"),
+        reference_id: Some(claim.id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
     };
+    store_transaction(env, &tx);
+
+    let event = WithdrawalProposedEvent {
+        treasury_id: treasury.id,
+        tx_id,
+        proposer: reviewer,
+        recipient: claim.claimant.clone(),
+        amount: claim.amount,
+        token: claim.token.clone(),
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "withdraw")),
+        event,
+    );
+
+    tx_id
+}
+
+/// Raise the delay between approval and execution of high-value transactions.
+/// Only the treasury owner may call it; shortening or disabling the timelock
+/// goes through `propose_timelock_change` instead.
+pub fn set_timelock(env: &Env, treasury_id: u64, caller: Address, timelock_seconds: u64) -> bool {
+    caller.require_auth();
+
+    let mut treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.owner != caller {
+        panic!("only owner can set timelock");
+    }
+    if timelock_seconds < treasury.timelock_seconds {
+        panic!("timelock reductions need signer approval");
+    }
+    if timelock_seconds > MAX_TIMELOCK_SECONDS {
+        panic!("timelock too long");
+    }
+
+    treasury.timelock_seconds = timelock_seconds;
+    store_treasury(env, &treasury);
+    emit_timelock_updated(env, &treasury);
+
+    true
+}
+
+fn emit_timelock_updated(env: &Env, treasury: &Treasury) {
+    let event = TimelockUpdatedEvent {
+        treasury_id: treasury.id,
+        timelock_seconds: treasury.timelock_seconds,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "timelock")),
+        event,
+    );
+}
+
+/// Cancel an approved transaction while it is still timelocked. Any signer may veto.
+pub fn veto_transaction(env: &Env, tx_id: u64, signer: Address) -> bool {
+    let mut tx = crate::treasury::storage::get_transaction(env, tx_id).expect("tx not found");
+    let treasury = get_treasury(env, tx.treasury_id).expect("treasury not found");
+    assert_signer(env, &treasury, &signer);
+
+    veto(env, &mut tx, signer, None);
+    true
+}
+
+/// Cancel a timelocked transaction on behalf of a passed governance proposal of
+/// the treasury's guild.
+pub fn governance_veto(env: &Env, tx_id: u64, guild_id: u64, proposal_id: u64) {
+    let mut tx = crate::treasury::storage::get_transaction(env, tx_id).expect("tx not found");
+    let treasury = get_treasury(env, tx.treasury_id).expect("treasury not found");
+    if treasury.guild_id != guild_id {
+        panic!("treasury does not belong to guild");
+    }
 
-    store_treasury(env, &treasury);
+    veto(
+        env,
+        &mut tx,
+        env.current_contract_address(),
+        Some(proposal_id),
+    );
+}
 
-    let event = TreasuryInitializedEvent {
-        treasury_id: id,
-        guild_id,
-        owner,
+fn veto(env: &Env, tx: &mut Transaction, vetoed_by: Address, proposal_id: Option<u64>) {
+    let now = env.ledger().timestamp();
+    expire_if_needed(tx, now);
+    if !is_timelocked(tx, now) {
+        panic!("transaction is not timelocked");
+    }
+
+    tx.status = TransactionStatus::Rejected;
+    store_transaction(env, tx);
+    reopen_expense_if_payment(env, tx);
+
+    let event = TransactionVetoedEvent {
+        treasury_id: tx.treasury_id,
+        tx_id: tx.id,
+        vetoed_by,
+        proposal_id,
     };
     env.events().publish(
-        (Symbol::new(env, "treasury"), Symbol::new(env, "init")),
+        (Symbol::new(env, "treasury"), Symbol::new(env, "tx_vetoed")),
         event,
     );
-
-    id
 }
 
-pub fn deposit(
-    env: &Env,
-    treasury_id: u64,
-    depositor: Address,
-    amount: i128,
-    token: Option<Address>,
-) -> bool {
-    depositor.require_auth();
-    if amount <= 0 {
-        panic!("amount must be positive");
+/// A rejected `ExpensePayment` leaves its claim to be reviewed again
+fn reopen_expense_if_payment(env: &Env, tx: &Transaction) {
+    if tx.tx_type == TransactionType::ExpensePayment {
+        crate::treasury::expenses::reopen_expense_claim(
+            env,
+            tx.reference_id.expect("expense claim required"),
+        );
     }
+}
 
-    let mut treasury = get_treasury(env, treasury_id).expect("treasury not found");
-    if treasury.paused {
-        panic!("treasury is paused");
-    }
+/// Record an explicit rejection. The transaction is closed as `Rejected` as soon
+/// as the remaining signers can no longer reach the required approvals.
+pub fn reject_transaction(env: &Env, tx_id: u64, signer: Address) -> bool {
+    signer.require_auth();
 
-    match token {
-        Some(ref token_addr) => {
-            let client = TokenClient::new(env, token_addr);
-            client.transfer(&depositor, &env.current_contract_address(), &amount);
+    let mut tx = crate::treasury::storage::get_transaction(env, tx_id).expect("tx not found");
+    let treasury = get_treasury(env, tx.treasury_id).expect("treasury not found");
 
-            let mut balances = treasury.token_balances.clone();
-            let current = balances.get(token_addr.clone()).unwrap_or(0i128);
-            balances.set(token_addr.clone(), current + amount);
-            treasury.token_balances = balances;
-        }
-        None => {
-            // For native XLM we assume a wrapped token or external transfer; we only track accounting here.
-            treasury.balance_xlm += amount;
-        }
+    let now = env.ledger().timestamp();
+    expire_if_needed(&mut tx, now);
+    if !matches!(tx.status, TransactionStatus::Pending) {
+        panic!("transaction not rejectable");
     }
 
-    treasury.total_deposits += amount;
-    store_treasury(env, &treasury);
+    ensure_is_signer(&treasury, &signer);
+    add_rejection(&mut tx, &signer);
 
-    let tx_id = get_next_tx_id(env);
-    let now = env.ledger().timestamp();
-    let tx = Transaction {
-        id: tx_id,
-        treasury_id,
-        tx_type: TransactionType::Deposit,
-        amount,
-        token: token.clone(),
-        recipient: Some(env.current_contract_address()),
-        proposer: depositor.clone(),
-        approvals: Vec::new(env),
-        status: TransactionStatus::Executed,
-        created_at: now,
-        expires_at: now,
-        reason: String::from_str(env, "deposit"),
-    };
+    let closed = approval_impossible(&treasury, &tx);
+    if closed {
+        tx.status = TransactionStatus::Rejected;
+        reopen_expense_if_payment(env, &tx);
+    }
     store_transaction(env, &tx);
 
-    // Record analytics snapshot after deposit
-    record_snapshot(env, &treasury);
-
-    let event = DepositEvent {
-        treasury_id,
-        from: depositor,
-        amount,
-        token,
+    let event = TransactionRejectedEvent {
+        treasury_id: tx.treasury_id,
+        tx_id,
+        rejecter: signer,
+        closed,
     };
     env.events().publish(
-        (Symbol::new(env, "treasury"), Symbol::new(env, "deposit")),
+        (Symbol::new(env, "treasury"), Symbol::new(env, "tx_reject")),
         event,
     );
 
     true
 }
 
-pub fn propose_withdrawal(
+/// Propose adding `new_signer` to the treasury's signers.
+pub fn propose_add_signer(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    new_signer: Address,
+) -> u64 {
+    propose_config_change(
+        env,
+        treasury_id,
+        proposer,
+        TransactionType::AddSigner,
+        Some(new_signer),
+        None,
+        0,
+        None,
+    )
+}
+
+/// Propose removing `signer` from the treasury's signers.
+pub fn propose_remove_signer(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    signer: Address,
+) -> u64 {
+    propose_config_change(
+        env,
+        treasury_id,
+        proposer,
+        TransactionType::RemoveSigner,
+        Some(signer),
+        None,
+        0,
+        None,
+    )
+}
+
+/// Propose replacing `old_signer` with `new_signer`.
+pub fn propose_rotate_signer(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    old_signer: Address,
+    new_signer: Address,
+) -> u64 {
+    propose_config_change(
+        env,
+        treasury_id,
+        proposer,
+        TransactionType::RotateSigner,
+        Some(new_signer),
+        Some(old_signer),
+        0,
+        None,
+    )
+}
+
+/// Propose a new approval threshold.
+pub fn propose_threshold_change(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    new_threshold: u32,
+) -> u64 {
+    propose_config_change(
+        env,
+        treasury_id,
+        proposer,
+        TransactionType::ChangeThreshold,
+        None,
+        None,
+        new_threshold as i128,
+        None,
+    )
+}
+
+/// Propose a new high-value threshold for `token` (`None` for native XLM).
+pub fn propose_high_value_threshold(
     env: &Env,
     treasury_id: u64,
     proposer: Address,
-    recipient: Address,
-    amount: i128,
     token: Option<Address>,
-    reason: String,
+    threshold: i128,
 ) -> u64 {
-    if amount <= 0 {
-        panic!("amount must be positive");
+    if threshold <= 0 {
+        panic!("threshold must be positive");
     }
+    propose_config_change(
+        env,
+        treasury_id,
+        proposer,
+        TransactionType::SetHighValueThreshold,
+        None,
+        None,
+        threshold,
+        token,
+    )
+}
 
-    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
-    if treasury.paused {
-        panic!("treasury is paused");
-    }
+/// Propose a new high-value timelock in seconds (`0` disables it).
+pub fn propose_timelock_change(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    timelock_seconds: u64,
+) -> u64 {
+    propose_config_change(
+        env,
+        treasury_id,
+        proposer,
+        TransactionType::SetTimelock,
+        None,
+        None,
+        timelock_seconds as i128,
+        None,
+    )
+}
 
+#[allow(clippy::too_many_arguments)]
+fn propose_config_change(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    tx_type: TransactionType,
+    recipient: Option<Address>,
+    replaces: Option<Address>,
+    amount: i128,
+    token: Option<Address>,
+) -> u64 {
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
     assert_signer(env, &treasury, &proposer);
 
     let tx_id = get_next_tx_id(env);
@@ -167,38 +1064,137 @@ pub fn propose_withdrawal(
     let mut approvals = Vec::new(env);
     approvals.push_back(proposer.clone());
 
-    let tx = Transaction {
+    let mut tx = Transaction {
         id: tx_id,
         treasury_id,
-        tx_type: TransactionType::Withdrawal,
+        tx_type: tx_type.clone(),
         amount,
-        token: token.clone(),
-        recipient: Some(recipient.clone()),
+        token,
+        recipient,
         proposer: proposer.clone(),
         approvals,
+        rejections: Vec::new(env),
         status: TransactionStatus::Pending,
         created_at: now,
         expires_at: now + TX_EXPIRY_SECONDS,
-        reason,
+        reason: String::from_str(env, "config_change"),
+        reference_id: None,
+        executable_at: 0,
+        replaces,
+        legs: Vec::new(env),
     };
+    // Validate up front so an impossible change never collects approvals
+    let mut preview = treasury.clone();
+    apply_to_treasury(&mut preview, &tx);
+
+    if current_approvals(&treasury, &tx) >= required_approvals_for_tx(&treasury, &tx) {
+        tx.status = TransactionStatus::Approved;
+    }
     store_transaction(env, &tx);
 
-    let event = WithdrawalProposedEvent {
+    let event = ConfigChangeProposedEvent {
         treasury_id,
         tx_id,
         proposer,
-        recipient,
-        amount,
-        token,
+        tx_type,
     };
     env.events().publish(
-        (Symbol::new(env, "treasury"), Symbol::new(env, "withdraw")),
+        (Symbol::new(env, "treasury"), Symbol::new(env, "cfg_prop")),
         event,
     );
 
     tx_id
 }
 
+/// Apply the signer-set or threshold change carried by `tx` to `treasury`,
+/// validating the resulting configuration.
+fn apply_to_treasury(treasury: &mut Treasury, tx: &Transaction) {
+    match tx.tx_type {
+        TransactionType::AddSigner => {
+            let signer = tx.recipient.clone().expect("signer required");
+            if treasury.is_signer(&signer) {
+                panic!("already a signer");
+            }
+            treasury.signers.push_back(signer);
+        }
+        TransactionType::RemoveSigner => {
+            let signer = tx.recipient.clone().expect("signer required");
+            let index = treasury
+                .signers
+                .first_index_of(&signer)
+                .unwrap_or_else(|| panic!("not a signer"));
+            if signer == treasury.owner {
+                panic!("cannot remove owner");
+            }
+            treasury.signers.remove(index);
+        }
+        TransactionType::RotateSigner => {
+            let old_signer = tx.replaces.clone().expect("signer required");
+            let new_signer = tx.recipient.clone().expect("signer required");
+            let index = treasury
+                .signers
+                .first_index_of(&old_signer)
+                .unwrap_or_else(|| panic!("not a signer"));
+            if treasury.is_signer(&new_signer) {
+                panic!("already a signer");
+            }
+            treasury.signers.set(index, new_signer.clone());
+            if old_signer == treasury.owner {
+                treasury.owner = new_signer;
+            }
+        }
+        TransactionType::ChangeThreshold => {
+            treasury.approval_threshold = tx.amount as u32;
+        }
+        TransactionType::SetHighValueThreshold => match tx.token {
+            Some(ref token) => {
+                treasury
+                    .token_high_value_thresholds
+                    .set(token.clone(), tx.amount);
+            }
+            None => treasury.high_value_threshold = tx.amount,
+        },
+        TransactionType::SetTimelock => {
+            if tx.amount > MAX_TIMELOCK_SECONDS as i128 {
+                panic!("timelock too long");
+            }
+            treasury.timelock_seconds = tx.amount as u64;
+        }
+        _ => panic!("not a config change"),
+    }
+
+    validate_threshold(treasury.signers.len(), treasury.approval_threshold);
+}
+
+fn apply_config_change(env: &Env, treasury: &mut Treasury, tx: &Transaction) {
+    apply_to_treasury(treasury, tx);
+    store_treasury(env, treasury);
+
+    if tx.tx_type == TransactionType::SetTimelock {
+        emit_timelock_updated(env, treasury);
+    } else if tx.tx_type == TransactionType::SetHighValueThreshold {
+        let event = HighValueThresholdUpdatedEvent {
+            treasury_id: treasury.id,
+            token: tx.token.clone(),
+            threshold: tx.amount,
+        };
+        env.events().publish(
+            (Symbol::new(env, "treasury"), Symbol::new(env, "hv_thresh")),
+            event,
+        );
+    } else {
+        let event = SignersUpdatedEvent {
+            treasury_id: treasury.id,
+            signers: treasury.signers.clone(),
+            approval_threshold: treasury.approval_threshold,
+        };
+        env.events().publish(
+            (Symbol::new(env, "treasury"), Symbol::new(env, "signers")),
+            event,
+        );
+    }
+}
+
 pub fn approve_transaction(env: &Env, tx_id: u64, approver: Address) -> bool {
     approver.require_auth();
 
@@ -218,12 +1214,34 @@ pub fn approve_transaction(env: &Env, tx_id: u64, approver: Address) -> bool {
     add_approval(&mut tx, &approver);
 
     let required = required_approvals_for_tx(&treasury, &tx);
-    if (tx.approvals.len() as u32) >= required {
+    let newly_approved =
+        tx.status == TransactionStatus::Pending && current_approvals(&treasury, &tx) >= required;
+    if newly_approved {
         tx.status = TransactionStatus::Approved;
+
+        // High-value outflows wait out the treasury timelock before they can run;
+        // the execution window starts once the timelock ends.
+        let delay = timelock_for_tx(&treasury, &tx);
+        if delay > 0 {
+            tx.executable_at = now + delay;
+            tx.expires_at = tx.expires_at.max(tx.executable_at + TX_EXPIRY_SECONDS);
+        }
     }
 
     store_transaction(env, &tx);
 
+    if newly_approved && tx.executable_at > 0 {
+        let event = TransactionTimelockedEvent {
+            treasury_id: tx.treasury_id,
+            tx_id,
+            executable_at: tx.executable_at,
+        };
+        env.events().publish(
+            (Symbol::new(env, "treasury"), Symbol::new(env, "tx_locked")),
+            event,
+        );
+    }
+
     let event = TransactionApprovedEvent {
         treasury_id: tx.treasury_id,
         tx_id,
@@ -237,7 +1255,7 @@ pub fn approve_transaction(env: &Env, tx_id: u64, approver: Address) -> bool {
     true
 }
 
-fn enforce_budget(
+pub(crate) fn enforce_budget(
     env: &Env,
     treasury_id: u64,
     category: &String,
@@ -330,11 +1348,21 @@ pub fn execute_transaction(env: &Env, tx_id: u64, executor: Address) -> bool {
     if !matches!(tx.status, TransactionStatus::Approved) {
         panic!("transaction must be approved");
     }
+    // signers may have changed since the transaction was approved
+    if current_approvals(&treasury, &tx) < required_approvals_for_tx(&treasury, &tx) {
+        panic!("approvals no longer meet threshold");
+    }
+    if is_timelocked(&tx, now) {
+        panic!("transaction is timelocked");
+    }
 
     match tx.tx_type {
         TransactionType::Withdrawal
         | TransactionType::BountyFunding
-        | TransactionType::MilestonePayment => {
+        | TransactionType::MilestonePayment
+        | TransactionType::StreamFunding
+        | TransactionType::VestingFunding
+        | TransactionType::ExpensePayment => {
             let recipient = tx.recipient.clone().expect("recipient required");
 
             // budget category name from tx_type
@@ -342,6 +1370,14 @@ pub fn execute_transaction(env: &Env, tx_id: u64, executor: Address) -> bool {
                 TransactionType::Withdrawal => String::from_str(env, "withdrawal"),
                 TransactionType::BountyFunding => String::from_str(env, "bounty"),
                 TransactionType::MilestonePayment => String::from_str(env, "milestone"),
+                TransactionType::StreamFunding => String::from_str(env, "stream"),
+                TransactionType::VestingFunding => String::from_str(env, "vesting"),
+                TransactionType::ExpensePayment => {
+                    let claim_id = tx.reference_id.expect("expense claim required");
+                    get_expense(env, claim_id)
+                        .expect("expense claim not found")
+                        .category
+                }
                 _ => String::from_str(env, "other"),
             };
 
@@ -351,10 +1387,16 @@ pub fn execute_transaction(env: &Env, tx_id: u64, executor: Address) -> bool {
             enforce_budget(env, tx.treasury_id, &category, tx.amount).unwrap_or_else(|e| match e {
                 TreasuryError::BudgetExceeded => panic!("budget exceeded"),
                 TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+                TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
             });
 
             let op_type = match tx.tx_type {
-                TransactionType::Withdrawal => crate::allowance::AllowanceOperation::Withdrawal,
+                TransactionType::Withdrawal
+                | TransactionType::StreamFunding
+                | TransactionType::VestingFunding
+                | TransactionType::ExpensePayment => {
+                    crate::allowance::AllowanceOperation::Withdrawal
+                }
                 TransactionType::BountyFunding => {
                     crate::allowance::AllowanceOperation::BountyFunding
                 }
@@ -375,12 +1417,28 @@ pub fn execute_transaction(env: &Env, tx_id: u64, executor: Address) -> bool {
             .unwrap_or_else(|e| match e {
                 TreasuryError::BudgetExceeded => panic!("budget exceeded"),
                 TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+                TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
             });
 
+            // Bounty, stream and vesting funding stay inside the contract: the
+            // bounty escrow, stream or grant takes over the tokens instead of a
+            // transfer to an external recipient.
+            let escrow_bounty_id = match tx.tx_type {
+                TransactionType::BountyFunding => tx.reference_id,
+                _ => None,
+            };
+            let internal_account = match tx.tx_type {
+                TransactionType::StreamFunding => Some(SubAccount::Stream(
+                    tx.reference_id.expect("stream required"),
+                )),
+                TransactionType::VestingFunding => Some(SubAccount::Vesting(
+                    tx.reference_id.expect("grant required"),
+                )),
+                _ => escrow_bounty_id.map(SubAccount::Bounty),
+            };
+
             match tx.token {
                 Some(ref token_addr) => {
-                    let client = TokenClient::new(env, token_addr);
-
                     let mut balances = treasury.token_balances.clone();
                     let current = balances.get(token_addr.clone()).unwrap_or(0i128);
                     if current < tx.amount {
@@ -389,7 +1447,15 @@ pub fn execute_transaction(env: &Env, tx_id: u64, executor: Address) -> bool {
                     balances.set(token_addr.clone(), current - tx.amount);
                     treasury.token_balances = balances;
 
-                    client.transfer(&env.current_contract_address(), &recipient, &tx.amount);
+                    if let Some(bounty_id) = escrow_bounty_id {
+                        crate::bounty::apply_treasury_funding(
+                            env,
+                            bounty_id,
+                            tx.treasury_id,
+                            token_addr,
+                            tx.amount,
+                        );
+                    }
                 }
                 None => {
                     if treasury.balance_xlm < tx.amount {
@@ -399,15 +1465,62 @@ pub fn execute_transaction(env: &Env, tx_id: u64, executor: Address) -> bool {
                 }
             }
 
+            let source = SubAccount::Treasury(tx.treasury_id);
+            let token_addr = resolve_token(env, &tx.token);
+            match internal_account {
+                Some(ref account) => {
+                    ledger::transfer_internal(env, &source, account, &token_addr, tx.amount)
+                }
+                None => ledger::withdraw(env, &source, &token_addr, &recipient, tx.amount),
+            }
+            match internal_account {
+                Some(SubAccount::Stream(stream_id)) => {
+                    crate::treasury::streams::activate_stream(env, stream_id)
+                }
+                Some(SubAccount::Vesting(grant_id)) => {
+                    crate::vesting::activate_treasury_grant(env, grant_id)
+                }
+                _ => {}
+            }
+            if tx.tx_type == TransactionType::ExpensePayment {
+                crate::treasury::expenses::mark_expense_paid(
+                    env,
+                    tx.reference_id.expect("expense claim required"),
+                );
+            }
+
             treasury.total_withdrawals += tx.amount;
             store_treasury(env, &treasury);
         }
-        TransactionType::Deposit => {
+        TransactionType::BatchWithdrawal => {
+            execute_batch(env, &mut treasury, &tx, &executor);
+        }
+        TransactionType::InternalTransfer => {
+            execute_internal_transfer(env, &mut treasury, &tx);
+        }
+        TransactionType::Deposit
+        | TransactionType::InternalTransferReceived
+        | TransactionType::Refund => {
             panic!("cannot execute deposit transaction");
         }
         TransactionType::AllowanceGrant => {
             // state-only; execution path not used in this simplified version
         }
+        TransactionType::PayrollOrder => {
+            // no funds move now; installments are paid by the payroll keeper
+            crate::treasury::payroll::activate_payroll(
+                env,
+                tx.reference_id.expect("payroll order required"),
+            );
+        }
+        TransactionType::AddSigner
+        | TransactionType::RemoveSigner
+        | TransactionType::RotateSigner
+        | TransactionType::ChangeThreshold
+        | TransactionType::SetHighValueThreshold
+        | TransactionType::SetTimelock => {
+            apply_config_change(env, &mut treasury, &tx);
+        }
     }
 
     tx.status = TransactionStatus::Executed;
@@ -432,6 +1545,198 @@ pub fn execute_transaction(env: &Env, tx_id: u64, executor: Address) -> bool {
     true
 }
 
+/// Move the balance of an internal transfer to the destination treasury and
+/// record the `InternalTransferReceived` entry in its history. Charged to the
+/// sender's "transfer" budget; neither side's deposit or withdrawal totals
+/// change since the funds stay in the guild.
+fn execute_internal_transfer(env: &Env, treasury: &mut Treasury, tx: &Transaction) {
+    let to_treasury_id = tx.reference_id.expect("destination treasury required");
+    let mut destination =
+        get_treasury(env, to_treasury_id).expect("destination treasury not found");
+    if destination.paused {
+        panic!("destination treasury is paused");
+    }
+
+    let category = String::from_str(env, "transfer");
+    enforce_budget(env, tx.treasury_id, &category, tx.amount).unwrap_or_else(|e| match e {
+        TreasuryError::BudgetExceeded => panic!("budget exceeded"),
+        TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+        TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
+    });
+
+    match tx.token {
+        Some(ref token_addr) => {
+            let current = treasury
+                .token_balances
+                .get(token_addr.clone())
+                .unwrap_or(0i128);
+            if current < tx.amount {
+                panic!("insufficient treasury balance");
+            }
+            treasury
+                .token_balances
+                .set(token_addr.clone(), current - tx.amount);
+            let received = destination
+                .token_balances
+                .get(token_addr.clone())
+                .unwrap_or(0i128);
+            destination
+                .token_balances
+                .set(token_addr.clone(), received + tx.amount);
+        }
+        None => {
+            if treasury.balance_xlm < tx.amount {
+                panic!("insufficient XLM balance");
+            }
+            treasury.balance_xlm -= tx.amount;
+            destination.balance_xlm += tx.amount;
+        }
+    }
+    store_treasury(env, treasury);
+    store_treasury(env, &destination);
+
+    ledger::transfer_internal(
+        env,
+        &SubAccount::Treasury(tx.treasury_id),
+        &SubAccount::Treasury(to_treasury_id),
+        &resolve_token(env, &tx.token),
+        tx.amount,
+    );
+
+    let received_tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let received = Transaction {
+        id: received_tx_id,
+        treasury_id: to_treasury_id,
+        tx_type: TransactionType::InternalTransferReceived,
+        amount: tx.amount,
+        token: tx.token.clone(),
+        recipient: None,
+        proposer: tx.proposer.clone(),
+        approvals: tx.approvals.clone(),
+        rejections: Vec::new(env),
+        status: TransactionStatus::Executed,
+        created_at: now,
+        expires_at: now,
+        reason: tx.reason.clone(),
+        reference_id: Some(tx.id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &received);
+    record_snapshot(env, &destination);
+
+    let event = InternalTransferEvent {
+        from_treasury_id: tx.treasury_id,
+        to_treasury_id,
+        tx_id: tx.id,
+        received_tx_id,
+        amount: tx.amount,
+        token: tx.token.clone(),
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "transfer")),
+        event,
+    );
+}
+
+/// Check every token total of a batch against the treasury balance, the
+/// remaining "withdrawal" budget and `spender`'s treasury allowance without
+/// charging them.
+fn check_batch_totals(env: &Env, treasury: &Treasury, tx: &Transaction, spender: &Address) {
+    let category = String::from_str(env, "withdrawal");
+    let now = env.ledger().timestamp();
+    let budget_left = get_budget(env, tx.treasury_id, &category)
+        .filter(|b| b.allocated_amount > 0)
+        .map(|b| {
+            if b.period_seconds > 0 && now >= b.period_start.saturating_add(b.period_seconds) {
+                b.allocated_amount
+            } else {
+                b.allocated_amount - b.spent_amount
+            }
+        });
+
+    for (token, total) in tx.token_totals().iter() {
+        let balance = match token {
+            Some(ref token_addr) => treasury
+                .token_balances
+                .get(token_addr.clone())
+                .unwrap_or(0i128),
+            None => treasury.balance_xlm,
+        };
+        if balance < total {
+            panic!("insufficient treasury balance");
+        }
+        if budget_left.is_some_and(|left| total > left) {
+            panic!("budget exceeded");
+        }
+        if let Some(mut allowance) = get_allowance(env, tx.treasury_id, spender, &token) {
+            allowance.ensure_period_current(env);
+            if allowance.remaining_amount < total {
+                panic!("allowance exceeded");
+            }
+        }
+    }
+}
+
+/// Pay every leg of a batch. The "withdrawal" budget, the executor's
+/// allowance and the balance are charged once per token total; any failure
+/// panics and so reverts the legs already paid.
+fn execute_batch(env: &Env, treasury: &mut Treasury, tx: &Transaction, executor: &Address) {
+    let category = String::from_str(env, "withdrawal");
+    let op_type = crate::allowance::AllowanceOperation::Withdrawal;
+    for (token, total) in tx.token_totals().iter() {
+        enforce_budget(env, tx.treasury_id, &category, total).unwrap_or_else(|e| match e {
+            TreasuryError::BudgetExceeded => panic!("budget exceeded"),
+            TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+            TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
+        });
+        enforce_allowance(env, tx.treasury_id, executor, &token, total, &op_type).unwrap_or_else(
+            |e| match e {
+                TreasuryError::BudgetExceeded => panic!("budget exceeded"),
+                TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+                TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
+            },
+        );
+
+        match token {
+            Some(ref token_addr) => {
+                let current = treasury
+                    .token_balances
+                    .get(token_addr.clone())
+                    .unwrap_or(0i128);
+                if current < total {
+                    panic!("insufficient treasury balance");
+                }
+                treasury
+                    .token_balances
+                    .set(token_addr.clone(), current - total);
+            }
+            None => {
+                if treasury.balance_xlm < total {
+                    panic!("insufficient XLM balance");
+                }
+                treasury.balance_xlm -= total;
+            }
+        }
+    }
+
+    let source = SubAccount::Treasury(tx.treasury_id);
+    for leg in tx.legs.iter() {
+        ledger::withdraw(
+            env,
+            &source,
+            &resolve_token(env, &leg.token),
+            &leg.recipient,
+            leg.amount,
+        );
+    }
+
+    treasury.total_withdrawals += tx.amount;
+    store_treasury(env, treasury);
+}
+
 pub fn execute_milestone_payment(
     env: &Env,
     treasury_id: u64,
@@ -453,6 +1758,7 @@ pub fn execute_milestone_payment(
     enforce_budget(env, treasury_id, &category, amount).unwrap_or_else(|e| match e {
         TreasuryError::BudgetExceeded => panic!("budget exceeded"),
         TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+        TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
     });
 
     // Allowance enforcement (if any) keyed by current contract address;
@@ -463,14 +1769,13 @@ pub fn execute_milestone_payment(
         match e {
             TreasuryError::BudgetExceeded => panic!("budget exceeded"),
             TreasuryError::AllowanceExceeded => panic!("allowance exceeded"),
+            TreasuryError::InsufficientBalance => panic!("insufficient treasury balance"),
         }
     });
 
     // Move funds from treasury to recipient
     match token {
         Some(ref token_addr) => {
-            let client = TokenClient::new(env, token_addr);
-
             let mut balances = treasury.token_balances.clone();
             let current = balances.get(token_addr.clone()).unwrap_or(0i128);
             if current < amount {
@@ -478,8 +1783,6 @@ pub fn execute_milestone_payment(
             }
             balances.set(token_addr.clone(), current - amount);
             treasury.token_balances = balances;
-
-            client.transfer(&env.current_contract_address(), &recipient, &amount);
         }
         None => {
             if treasury.balance_xlm < amount {
@@ -489,6 +1792,14 @@ pub fn execute_milestone_payment(
         }
     }
 
+    ledger::withdraw(
+        env,
+        &SubAccount::Treasury(treasury_id),
+        &resolve_token(env, &token),
+        &recipient,
+        amount,
+    );
+
     treasury.total_withdrawals += amount;
     store_treasury(env, &treasury);
 
@@ -504,10 +1815,15 @@ pub fn execute_milestone_payment(
         recipient: Some(recipient),
         proposer: executor,
         approvals: Vec::new(env),
+        rejections: Vec::new(env),
         status: TransactionStatus::Executed,
         created_at: now,
         expires_at: now,
         reason: String::from_str(env, "milestone_payment"),
+        reference_id: None,
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
     };
     store_transaction(env, &tx);
 
@@ -581,8 +1897,27 @@ pub fn get_balance(env: &Env, treasury_id: u64, token: Option<Address>) -> i128
     }
 }
 
+/// The latest `limit` transactions of a treasury, oldest first.
+///
+/// Batch withdrawals are expanded into one entry per leg; the entries share
+/// the batch's id, status and approvals and carry the leg's recipient, token
+/// and amount.
 pub fn get_transaction_history(env: &Env, treasury_id: u64, limit: u32) -> Vec<Transaction> {
-    let all = get_treasury_transactions(env, treasury_id);
+    let mut all = Vec::new(env);
+    for tx in get_treasury_transactions(env, treasury_id).iter() {
+        if tx.tx_type != TransactionType::BatchWithdrawal {
+            all.push_back(tx);
+            continue;
+        }
+        for leg in tx.legs.iter() {
+            let mut entry = tx.clone();
+            entry.recipient = Some(leg.recipient);
+            entry.token = leg.token;
+            entry.amount = leg.amount;
+            entry.legs = Vec::new(env);
+            all.push_back(entry);
+        }
+    }
     let len = all.len();
 
     if len <= limit {
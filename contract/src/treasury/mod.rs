@@ -1,19 +1,38 @@
+pub mod expenses;
 pub mod management;
 pub mod multisig;
+pub mod payroll;
 pub mod storage;
+pub mod streams;
 pub mod types;
 
+pub use expenses::{
+    approve_expense_claim, get_expense_claims_by_status, get_member_expense_claims,
+    reject_expense_claim, set_expense_approvers, submit_expense_claim,
+};
 pub use management::{
-    approve_transaction, deposit, emergency_pause, execute_milestone_payment, execute_transaction,
-    get_balance, get_transaction_history, grant_allowance, initialize_treasury, propose_withdrawal,
-    set_budget,
+    approve_transaction, credit_bounty_refund, deposit, emergency_pause, execute_milestone_payment,
+    execute_transaction, fund_bounty_from_allowance, get_balance, get_transaction_history,
+    governance_veto, grant_allowance, initialize_treasury, propose_add_signer,
+    propose_batch_withdrawal, propose_bounty_funding, propose_high_value_threshold,
+    propose_internal_transfer, propose_remove_signer, propose_rotate_signer,
+    propose_threshold_change, propose_timelock_change, propose_withdrawal, reject_transaction,
+    set_budget, set_timelock, veto_transaction, withdraw_bounty_funding,
+};
+pub use payroll::{cancel_payroll, propose_payroll, run_payroll};
+pub use streams::{
+    cancel_stream, get_stream_withdrawable, pause_stream, propose_stream, resume_stream,
+    withdraw_from_stream,
 };
 
 #[allow(unused_imports)]
 pub use storage::initialize_treasury_storage;
 
 #[allow(unused_imports)]
-pub use types::{Allowance, Budget, Transaction, TransactionStatus, TransactionType, Treasury};
+pub use types::{
+    Allowance, Budget, ExpenseClaim, ExpenseStatus, PayrollEntry, PayrollOrder, PayrollStatus,
+    Stream, StreamStatus, Transaction, TransactionStatus, TransactionType, Treasury, WithdrawalLeg,
+};
 // Tests disabled pending fixes
 #[cfg(test)]
 mod tests;
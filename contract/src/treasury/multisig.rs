@@ -3,6 +3,8 @@ use soroban_sdk::{Address, Env};
 use crate::treasury::types::{Transaction, TransactionStatus, TransactionType, Treasury};
 
 pub const TX_EXPIRY_SECONDS: u64 = 60 * 60 * 24 * 7; // 7 days
+pub const MAX_TIMELOCK_SECONDS: u64 = 60 * 60 * 24 * 30; // 30 days
+pub const MAX_BATCH_LEGS: u32 = 50;
 
 pub fn validate_threshold(signers_len: u32, threshold: u32) {
     if signers_len == 0 {
@@ -36,29 +38,132 @@ pub fn has_approved(tx: &Transaction, addr: &Address) -> bool {
     tx.approvals.iter().any(|a| a == addr.clone())
 }
 
+pub fn has_rejected(tx: &Transaction, addr: &Address) -> bool {
+    tx.rejections.iter().any(|a| a == addr.clone())
+}
+
 pub fn add_approval(tx: &mut Transaction, addr: &Address) {
     if has_approved(tx, addr) {
         panic!("duplicate approval");
     }
+    if has_rejected(tx, addr) {
+        panic!("signer already rejected");
+    }
     tx.approvals.push_back(addr.clone());
 }
 
+pub fn add_rejection(tx: &mut Transaction, addr: &Address) {
+    if has_rejected(tx, addr) {
+        panic!("duplicate rejection");
+    }
+    if has_approved(tx, addr) {
+        panic!("signer already approved");
+    }
+    tx.rejections.push_back(addr.clone());
+}
+
+/// Whether the transaction moves funds out of the treasury at or above the
+/// high-value threshold of its token. A batch is high-value when its total in
+/// any one token is. Internal transfers count too, so a large move to another
+/// guild treasury waits out the timelock.
+pub fn is_high_value(treasury: &Treasury, tx: &Transaction) -> bool {
+    let is_outflow = matches!(
+        tx.tx_type,
+        TransactionType::Withdrawal
+            | TransactionType::InternalTransfer
+            | TransactionType::BountyFunding
+            | TransactionType::MilestonePayment
+            | TransactionType::StreamFunding
+            | TransactionType::VestingFunding
+            | TransactionType::BatchWithdrawal
+            | TransactionType::ExpensePayment
+    );
+    is_outflow
+        && tx
+            .token_totals()
+            .iter()
+            .any(|(token, total)| total >= treasury.high_value_threshold_for(&token))
+}
+
+/// Whether the transaction changes the signer set or thresholds.
+pub fn is_config_change(tx: &Transaction) -> bool {
+    matches!(
+        tx.tx_type,
+        TransactionType::AddSigner
+            | TransactionType::RemoveSigner
+            | TransactionType::RotateSigner
+            | TransactionType::ChangeThreshold
+            | TransactionType::SetHighValueThreshold
+            | TransactionType::SetTimelock
+    )
+}
+
 pub fn required_approvals_for_tx(treasury: &Treasury, tx: &Transaction) -> u32 {
     match tx.tx_type {
         TransactionType::Withdrawal
         | TransactionType::BountyFunding
-        | TransactionType::MilestonePayment => {
-            if tx.amount >= treasury.high_value_threshold {
+        | TransactionType::MilestonePayment
+        | TransactionType::StreamFunding
+        | TransactionType::VestingFunding
+        | TransactionType::BatchWithdrawal
+        | TransactionType::ExpensePayment => {
+            if is_high_value(treasury, tx) {
                 treasury.approval_threshold
             } else {
                 // low-value operations: single signer is enough, but cannot exceed threshold
                 1u32.min(treasury.approval_threshold)
             }
         }
+        // changes to the signer set always need the full threshold
+        _ if is_config_change(tx) => treasury.approval_threshold,
+        // a payroll order authorizes every future installment at once
+        TransactionType::PayrollOrder => treasury.approval_threshold,
+        // transfers always need the full threshold of the sending treasury
+        TransactionType::InternalTransfer => treasury.approval_threshold,
         _ => 1,
     }
 }
 
+/// Approvals on `tx` from addresses that are still signers. Approvals left by
+/// removed or rotated-out signers no longer count.
+pub fn current_approvals(treasury: &Treasury, tx: &Transaction) -> u32 {
+    tx.approvals
+        .iter()
+        .filter(|a| treasury.is_signer(a))
+        .count() as u32
+}
+
+/// Rejections on `tx` from addresses that are still signers.
+pub fn current_rejections(treasury: &Treasury, tx: &Transaction) -> u32 {
+    tx.rejections
+        .iter()
+        .filter(|a| treasury.is_signer(a))
+        .count() as u32
+}
+
+/// Whether enough signers rejected that the required approvals can no longer be reached.
+pub fn approval_impossible(treasury: &Treasury, tx: &Transaction) -> bool {
+    let remaining = treasury
+        .signers
+        .len()
+        .saturating_sub(current_rejections(treasury, tx));
+    remaining < required_approvals_for_tx(treasury, tx)
+}
+
+/// Delay between approval and execution; only high-value outflows and
+/// internal transfers are timelocked.
+pub fn timelock_for_tx(treasury: &Treasury, tx: &Transaction) -> u64 {
+    if is_high_value(treasury, tx) {
+        treasury.timelock_seconds
+    } else {
+        0
+    }
+}
+
+pub fn is_timelocked(tx: &Transaction, now: u64) -> bool {
+    matches!(tx.status, TransactionStatus::Approved) && now < tx.executable_at
+}
+
 pub fn is_expired(tx: &Transaction, now: u64) -> bool {
     now >= tx.expires_at
 }
@@ -0,0 +1,293 @@
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::treasury::management::pay_from_treasury;
+use crate::treasury::multisig::{assert_signer, TX_EXPIRY_SECONDS};
+use crate::treasury::storage::{
+    get_next_payroll_id, get_next_tx_id, get_payroll, get_transaction, get_treasury, store_payroll,
+    store_transaction,
+};
+use crate::treasury::types::{
+    FailedPayout, PayoutFailedEvent, PayrollCancelledEvent, PayrollEntry, PayrollOrder,
+    PayrollProposedEvent, PayrollRunEvent, PayrollStatus, Transaction, TransactionStatus,
+    TransactionType, TreasuryError,
+};
+
+/// Propose a recurring payroll order paying every entry once per
+/// `interval_seconds`, starting at `first_payment_at` until `end_time`.
+///
+/// Signers approve the order once through a `PayrollOrder` transaction, which
+/// always needs the full approval threshold. After it executes, installments
+/// are paid by the `run_payroll` keeper. Returns the order ID.
+#[allow(clippy::too_many_arguments)]
+pub fn propose_payroll(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    entries: Vec<PayrollEntry>,
+    token: Option<Address>,
+    interval_seconds: u64,
+    first_payment_at: u64,
+    end_time: u64,
+) -> u64 {
+    if entries.is_empty() {
+        panic!("at least one payroll entry required");
+    }
+    if interval_seconds == 0 {
+        panic!("interval must be positive");
+    }
+    if end_time < first_payment_at {
+        panic!("end must not be before the first payment");
+    }
+    let mut installment_amount = 0i128;
+    for entry in entries.iter() {
+        if entry.amount <= 0 {
+            panic!("amount must be positive");
+        }
+        installment_amount += entry.amount;
+    }
+
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+    assert_signer(env, &treasury, &proposer);
+
+    let order_id = get_next_payroll_id(env);
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type: TransactionType::PayrollOrder,
+        amount: installment_amount,
+        token: token.clone(),
+        recipient: None,
+        proposer: proposer.clone(),
+        approvals,
+        rejections: Vec::new(env),
+        status: TransactionStatus::Pending,
+        created_at: now,
+        expires_at: now + TX_EXPIRY_SECONDS,
+        reason: String::from_str(env, "payroll_order"),
+        reference_id: Some(order_id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    let order = PayrollOrder {
+        id: order_id,
+        treasury_id,
+        entries,
+        token: token.clone(),
+        interval_seconds,
+        next_payment_at: first_payment_at,
+        end_time,
+        installments_paid: 0,
+        failed_payouts: Vec::new(env),
+        status: PayrollStatus::Pending,
+        approval_tx_id: tx_id,
+        created_by: proposer,
+        created_at: now,
+    };
+    store_payroll(env, &order);
+
+    let event = PayrollProposedEvent {
+        treasury_id,
+        order_id,
+        tx_id,
+        installment_amount,
+        token,
+        interval_seconds,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "pay_prop")),
+        event,
+    );
+
+    order_id
+}
+
+/// Activate a payroll order once its approval transaction executes.
+/// Called from `execute_transaction`.
+pub(crate) fn activate_payroll(env: &Env, order_id: u64) {
+    let mut order = get_payroll(env, order_id).expect("payroll order not found");
+    if order.status != PayrollStatus::Pending {
+        panic!("payroll order not pending");
+    }
+    order.status = PayrollStatus::Active;
+    store_payroll(env, &order);
+}
+
+/// Keeper entrypoint: retry failed payouts of a payroll order and pay its next
+/// installment if it is due.
+///
+/// Anyone may call this. At most one installment is paid per call, so a keeper
+/// catches up on missed installments by calling repeatedly. A payout that fails
+/// for lack of balance or `payroll` budget is queued instead of aborting the
+/// rest of the installment, and retried at most once per interval. Queued
+/// payouts stay owed until paid or until a signer cancels the order. Returns
+/// the number of payouts made.
+pub fn run_payroll(env: &Env, order_id: u64) -> u32 {
+    let mut order = get_payroll(env, order_id).expect("payroll order not found");
+    if order.status != PayrollStatus::Active {
+        panic!("payroll order not active");
+    }
+    let treasury = get_treasury(env, order.treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+
+    let now = env.ledger().timestamp();
+    let installment_due = order.next_payment_at <= now && order.next_payment_at <= order.end_time;
+    let retry_due = |payout: &FailedPayout| {
+        now >= payout
+            .last_attempt_at
+            .saturating_add(order.interval_seconds)
+    };
+    if !installment_due && !order.failed_payouts.iter().any(|p| retry_due(&p)) {
+        panic!("no payout due");
+    }
+
+    let mut paid = 0u32;
+    let mut failed = 0u32;
+
+    let mut still_failed = Vec::new(env);
+    for mut payout in order.failed_payouts.iter() {
+        if !retry_due(&payout) {
+            still_failed.push_back(payout);
+            continue;
+        }
+        payout.attempts += 1;
+        payout.last_attempt_at = now;
+        match try_pay(env, &order, &payout.recipient, payout.amount) {
+            Ok(()) => paid += 1,
+            Err(e) => {
+                emit_failure(env, &order, &payout, e);
+                still_failed.push_back(payout);
+                failed += 1;
+            }
+        }
+    }
+    order.failed_payouts = still_failed;
+
+    if installment_due {
+        for entry in order.entries.iter() {
+            match try_pay(env, &order, &entry.recipient, entry.amount) {
+                Ok(()) => paid += 1,
+                Err(e) => {
+                    let payout = FailedPayout {
+                        recipient: entry.recipient,
+                        amount: entry.amount,
+                        due_at: order.next_payment_at,
+                        attempts: 1,
+                        last_attempt_at: now,
+                    };
+                    emit_failure(env, &order, &payout, e);
+                    order.failed_payouts.push_back(payout);
+                    failed += 1;
+                }
+            }
+        }
+        order.installments_paid += 1;
+        order.next_payment_at += order.interval_seconds;
+    }
+
+    if order.next_payment_at > order.end_time && order.failed_payouts.is_empty() {
+        order.status = PayrollStatus::Completed;
+    }
+    store_payroll(env, &order);
+
+    let event = PayrollRunEvent {
+        order_id,
+        paid,
+        failed,
+        next_payment_at: order.next_payment_at,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "pay_run")),
+        event,
+    );
+
+    paid
+}
+
+/// Cancel a payroll order. Any treasury signer may cancel; queued failed
+/// payouts are dropped and a pending approval transaction is rejected.
+pub fn cancel_payroll(env: &Env, order_id: u64, signer: Address) -> bool {
+    let mut order = get_payroll(env, order_id).expect("payroll order not found");
+    let treasury = get_treasury(env, order.treasury_id).expect("treasury not found");
+    assert_signer(env, &treasury, &signer);
+
+    match order.status {
+        PayrollStatus::Pending => {
+            let mut tx = get_transaction(env, order.approval_tx_id).expect("tx not found");
+            if matches!(
+                tx.status,
+                TransactionStatus::Pending | TransactionStatus::Approved
+            ) {
+                tx.status = TransactionStatus::Rejected;
+                store_transaction(env, &tx);
+            }
+        }
+        PayrollStatus::Active => {}
+        PayrollStatus::Completed | PayrollStatus::Cancelled => {
+            panic!("payroll order already closed")
+        }
+    }
+
+    order.status = PayrollStatus::Cancelled;
+    order.failed_payouts = Vec::new(env);
+    store_payroll(env, &order);
+
+    let event = PayrollCancelledEvent { order_id, signer };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "pay_cancel")),
+        event,
+    );
+
+    true
+}
+
+/// Pay one payroll leg from the treasury under the `payroll` budget.
+fn try_pay(
+    env: &Env,
+    order: &PayrollOrder,
+    recipient: &Address,
+    amount: i128,
+) -> Result<(), TreasuryError> {
+    pay_from_treasury(
+        env,
+        order.treasury_id,
+        &order.token,
+        recipient,
+        amount,
+        &String::from_str(env, "payroll"),
+        "payroll",
+        order.id,
+    )
+    .map(|_| ())
+}
+
+fn emit_failure(
+    env: &Env,
+    order: &PayrollOrder,
+    payout: &FailedPayout,
+    error: TreasuryError,
+) {
+    let event = PayoutFailedEvent {
+        order_id: order.id,
+        recipient: payout.recipient.clone(),
+        amount: payout.amount,
+        attempts: payout.attempts,
+        budget_exceeded: error == TreasuryError::BudgetExceeded,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "pay_failed")),
+        event,
+    );
+}
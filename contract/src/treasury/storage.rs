@@ -1,6 +1,8 @@
 use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol, Vec};
 
-use crate::treasury::types::{Allowance, Budget, Transaction, Treasury};
+use crate::treasury::types::{
+    Allowance, Budget, ExpenseClaim, PayrollOrder, Stream, Transaction, Treasury,
+};
 
 const TREASURY_CNT_KEY: Symbol = symbol_short!("t_cnt");
 const TREASURIES_KEY: Symbol = symbol_short!("trsries");
@@ -12,6 +14,20 @@ const TREASURY_TX_INDEX_KEY: Symbol = symbol_short!("t_tx_idx");
 const BUDGETS_KEY: Symbol = symbol_short!("budgets");
 const ALLOWANCES_KEY: Symbol = symbol_short!("allows");
 
+const STREAM_CNT_KEY: Symbol = symbol_short!("strm_cnt");
+const STREAMS_KEY: Symbol = symbol_short!("streams");
+const TREASURY_STREAM_INDEX_KEY: Symbol = symbol_short!("t_strm_ix");
+
+const PAYROLL_CNT_KEY: Symbol = symbol_short!("pr_cnt");
+const PAYROLLS_KEY: Symbol = symbol_short!("payrolls");
+const TREASURY_PAYROLL_INDEX_KEY: Symbol = symbol_short!("t_pr_idx");
+
+const EXPENSE_CNT_KEY: Symbol = symbol_short!("exp_cnt");
+const EXPENSES_KEY: Symbol = symbol_short!("expenses");
+const TREASURY_EXPENSE_INDEX_KEY: Symbol = symbol_short!("t_exp_idx");
+const MEMBER_EXPENSE_INDEX_KEY: Symbol = symbol_short!("m_exp_idx");
+const EXPENSE_APPROVERS_KEY: Symbol = symbol_short!("exp_appr");
+
 #[allow(dead_code)]
 pub fn initialize_treasury_storage(env: &Env) {
     let storage = env.storage().persistent();
@@ -197,3 +213,251 @@ pub fn list_budgets_for_treasury(env: &Env, treasury_id: u64) -> Vec<Budget> {
     }
     result
 }
+
+pub fn get_next_stream_id(env: &Env) -> u64 {
+    let storage = env.storage().persistent();
+    let current: u64 = storage.get(&STREAM_CNT_KEY).unwrap_or(0u64);
+    let next = current + 1;
+    storage.set(&STREAM_CNT_KEY, &next);
+    next
+}
+
+pub fn store_stream(env: &Env, stream: &Stream) {
+    let mut streams: Map<u64, Stream> = env
+        .storage()
+        .persistent()
+        .get(&STREAMS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    streams.set(stream.id, stream.clone());
+    env.storage().persistent().set(&STREAMS_KEY, &streams);
+
+    let mut index: Map<u64, Vec<u64>> = env
+        .storage()
+        .persistent()
+        .get(&TREASURY_STREAM_INDEX_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let mut list = index
+        .get(stream.treasury_id)
+        .unwrap_or_else(|| Vec::new(env));
+    if !list.iter().any(|id| id == stream.id) {
+        list.push_back(stream.id);
+        index.set(stream.treasury_id, list);
+        env.storage()
+            .persistent()
+            .set(&TREASURY_STREAM_INDEX_KEY, &index);
+    }
+}
+
+pub fn get_stream(env: &Env, stream_id: u64) -> Option<Stream> {
+    let streams: Map<u64, Stream> = env
+        .storage()
+        .persistent()
+        .get(&STREAMS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    streams.get(stream_id)
+}
+
+pub fn get_treasury_streams(env: &Env, treasury_id: u64) -> Vec<Stream> {
+    let index: Map<u64, Vec<u64>> = env
+        .storage()
+        .persistent()
+        .get(&TREASURY_STREAM_INDEX_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let mut result = Vec::new(env);
+    for id in index
+        .get(treasury_id)
+        .unwrap_or_else(|| Vec::new(env))
+        .iter()
+    {
+        if let Some(stream) = get_stream(env, id) {
+            result.push_back(stream);
+        }
+    }
+    result
+}
+
+pub fn get_next_payroll_id(env: &Env) -> u64 {
+    let storage = env.storage().persistent();
+    let current: u64 = storage.get(&PAYROLL_CNT_KEY).unwrap_or(0u64);
+    let next = current + 1;
+    storage.set(&PAYROLL_CNT_KEY, &next);
+    next
+}
+
+pub fn store_payroll(env: &Env, order: &PayrollOrder) {
+    let mut orders: Map<u64, PayrollOrder> = env
+        .storage()
+        .persistent()
+        .get(&PAYROLLS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    orders.set(order.id, order.clone());
+    env.storage().persistent().set(&PAYROLLS_KEY, &orders);
+
+    let mut index: Map<u64, Vec<u64>> = env
+        .storage()
+        .persistent()
+        .get(&TREASURY_PAYROLL_INDEX_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let mut list = index
+        .get(order.treasury_id)
+        .unwrap_or_else(|| Vec::new(env));
+    if !list.iter().any(|id| id == order.id) {
+        list.push_back(order.id);
+        index.set(order.treasury_id, list);
+        env.storage()
+            .persistent()
+            .set(&TREASURY_PAYROLL_INDEX_KEY, &index);
+    }
+}
+
+pub fn get_payroll(env: &Env, order_id: u64) -> Option<PayrollOrder> {
+    let orders: Map<u64, PayrollOrder> = env
+        .storage()
+        .persistent()
+        .get(&PAYROLLS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    orders.get(order_id)
+}
+
+pub fn get_treasury_payrolls(env: &Env, treasury_id: u64) -> Vec<PayrollOrder> {
+    let index: Map<u64, Vec<u64>> = env
+        .storage()
+        .persistent()
+        .get(&TREASURY_PAYROLL_INDEX_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let mut result = Vec::new(env);
+    for id in index
+        .get(treasury_id)
+        .unwrap_or_else(|| Vec::new(env))
+        .iter()
+    {
+        if let Some(order) = get_payroll(env, id) {
+            result.push_back(order);
+        }
+    }
+    result
+}
+
+pub fn get_next_expense_id(env: &Env) -> u64 {
+    let storage = env.storage().persistent();
+    let current: u64 = storage.get(&EXPENSE_CNT_KEY).unwrap_or(0u64);
+    let next = current + 1;
+    storage.set(&EXPENSE_CNT_KEY, &next);
+    next
+}
+
+pub fn store_expense(env: &Env, claim: &ExpenseClaim) {
+    let mut claims: Map<u64, ExpenseClaim> = env
+        .storage()
+        .persistent()
+        .get(&EXPENSES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    let is_new = !claims.contains_key(claim.id);
+    claims.set(claim.id, claim.clone());
+    env.storage().persistent().set(&EXPENSES_KEY, &claims);
+
+    if is_new {
+        let mut treasury_index: Map<u64, Vec<u64>> = env
+            .storage()
+            .persistent()
+            .get(&TREASURY_EXPENSE_INDEX_KEY)
+            .unwrap_or_else(|| Map::new(env));
+        let mut list = treasury_index
+            .get(claim.treasury_id)
+            .unwrap_or_else(|| Vec::new(env));
+        list.push_back(claim.id);
+        treasury_index.set(claim.treasury_id, list);
+        env.storage()
+            .persistent()
+            .set(&TREASURY_EXPENSE_INDEX_KEY, &treasury_index);
+
+        let mut member_index: Map<Address, Vec<u64>> = env
+            .storage()
+            .persistent()
+            .get(&MEMBER_EXPENSE_INDEX_KEY)
+            .unwrap_or_else(|| Map::new(env));
+        let mut list = member_index
+            .get(claim.claimant.clone())
+            .unwrap_or_else(|| Vec::new(env));
+        list.push_back(claim.id);
+        member_index.set(claim.claimant.clone(), list);
+        env.storage()
+            .persistent()
+            .set(&MEMBER_EXPENSE_INDEX_KEY, &member_index);
+    }
+}
+
+pub fn get_expense(env: &Env, claim_id: u64) -> Option<ExpenseClaim> {
+    let claims: Map<u64, ExpenseClaim> = env
+        .storage()
+        .persistent()
+        .get(&EXPENSES_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    claims.get(claim_id)
+}
+
+pub fn get_treasury_expenses(env: &Env, treasury_id: u64) -> Vec<ExpenseClaim> {
+    let index: Map<u64, Vec<u64>> = env
+        .storage()
+        .persistent()
+        .get(&TREASURY_EXPENSE_INDEX_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    expenses_by_id(env, index.get(treasury_id).unwrap_or_else(|| Vec::new(env)))
+}
+
+pub fn get_member_expenses(env: &Env, member: &Address) -> Vec<ExpenseClaim> {
+    let index: Map<Address, Vec<u64>> = env
+        .storage()
+        .persistent()
+        .get(&MEMBER_EXPENSE_INDEX_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    expenses_by_id(
+        env,
+        index.get(member.clone()).unwrap_or_else(|| Vec::new(env)),
+    )
+}
+
+fn expenses_by_id(env: &Env, ids: Vec<u64>) -> Vec<ExpenseClaim> {
+    let mut result = Vec::new(env);
+    for id in ids.iter() {
+        if let Some(claim) = get_expense(env, id) {
+            result.push_back(claim);
+        }
+    }
+    result
+}
+
+pub fn get_expense_approvers(env: &Env, treasury_id: u64) -> Vec<Address> {
+    let approvers: Map<u64, Vec<Address>> = env
+        .storage()
+        .persistent()
+        .get(&EXPENSE_APPROVERS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    approvers.get(treasury_id).unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_expense_approvers(env: &Env, treasury_id: u64, list: &Vec<Address>) {
+    let mut approvers: Map<u64, Vec<Address>> = env
+        .storage()
+        .persistent()
+        .get(&EXPENSE_APPROVERS_KEY)
+        .unwrap_or_else(|| Map::new(env));
+
+    approvers.set(treasury_id, list.clone());
+    env.storage()
+        .persistent()
+        .set(&EXPENSE_APPROVERS_KEY, &approvers);
+}
@@ -0,0 +1,306 @@
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::guild::storage::resolve_token;
+use crate::ledger::{self, SubAccount};
+use crate::treasury::management::credit_from_sub_account;
+use crate::treasury::multisig::{assert_signer, TX_EXPIRY_SECONDS};
+use crate::treasury::storage::{
+    get_next_stream_id, get_next_tx_id, get_stream, get_transaction, get_treasury, store_stream,
+    store_transaction,
+};
+use crate::treasury::types::{
+    Stream, StreamCancelledEvent, StreamProposedEvent, StreamStatus, StreamStatusChangedEvent,
+    StreamWithdrawnEvent, Transaction, TransactionStatus, TransactionType,
+};
+
+/// Propose a payment stream from the treasury to `recipient`.
+///
+/// The stream is created `Pending` together with a `StreamFunding` transaction
+/// for its full deposit. Once that transaction is approved and executed the
+/// deposit is reserved from the treasury balance and the stream starts
+/// accruing. Returns the stream ID.
+#[allow(clippy::too_many_arguments)]
+pub fn propose_stream(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    recipient: Address,
+    token: Option<Address>,
+    rate_per_second: i128,
+    start_time: u64,
+    end_time: u64,
+    cliff_time: u64,
+) -> u64 {
+    if rate_per_second <= 0 {
+        panic!("rate must be positive");
+    }
+    if end_time <= start_time {
+        panic!("end must be after start");
+    }
+    if cliff_time < start_time || cliff_time > end_time {
+        panic!("cliff must be within the stream");
+    }
+    let deposit = rate_per_second
+        .checked_mul((end_time - start_time) as i128)
+        .expect("stream amount overflow");
+
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+    assert_signer(env, &treasury, &proposer);
+
+    let stream_id = get_next_stream_id(env);
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type: TransactionType::StreamFunding,
+        amount: deposit,
+        token: token.clone(),
+        recipient: Some(recipient.clone()),
+        proposer: proposer.clone(),
+        approvals,
+        rejections: Vec::new(env),
+        status: TransactionStatus::Pending,
+        created_at: now,
+        expires_at: now + TX_EXPIRY_SECONDS,
+        reason: String::from_str(env, "stream"),
+        reference_id: Some(stream_id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    let stream = Stream {
+        id: stream_id,
+        treasury_id,
+        recipient: recipient.clone(),
+        token: token.clone(),
+        rate_per_second,
+        start_time,
+        end_time,
+        cliff_time,
+        deposit,
+        withdrawn: 0,
+        status: StreamStatus::Pending,
+        paused_at: 0,
+        paused_seconds: 0,
+        funding_tx_id: tx_id,
+        created_by: proposer,
+        created_at: now,
+    };
+    store_stream(env, &stream);
+
+    let event = StreamProposedEvent {
+        treasury_id,
+        stream_id,
+        tx_id,
+        recipient,
+        rate_per_second,
+        deposit,
+        token,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "strm_prop")),
+        event,
+    );
+
+    stream_id
+}
+
+/// Start a stream once its funding transaction has moved the deposit into
+/// the stream's sub-account. Called from `execute_transaction`.
+pub(crate) fn activate_stream(env: &Env, stream_id: u64) {
+    let mut stream = get_stream(env, stream_id).expect("stream not found");
+    if stream.status != StreamStatus::Pending {
+        panic!("stream not pending");
+    }
+    stream.status = StreamStatus::Active;
+    store_stream(env, &stream);
+
+    emit_status_changed(env, &stream, env.current_contract_address());
+}
+
+/// Withdraw everything the stream has accrued to its recipient so far.
+/// Returns the amount paid out.
+pub fn withdraw_from_stream(env: &Env, stream_id: u64, recipient: Address) -> i128 {
+    recipient.require_auth();
+
+    let mut stream = get_stream(env, stream_id).expect("stream not found");
+    if stream.recipient != recipient {
+        panic!("only the recipient can withdraw");
+    }
+    if !matches!(stream.status, StreamStatus::Active | StreamStatus::Paused) {
+        panic!("stream not active");
+    }
+
+    let amount = stream.withdrawable_amount(env.ledger().timestamp());
+    if amount <= 0 {
+        panic!("nothing to withdraw");
+    }
+
+    ledger::withdraw(
+        env,
+        &SubAccount::Stream(stream_id),
+        &resolve_token(env, &stream.token),
+        &recipient,
+        amount,
+    );
+
+    stream.withdrawn += amount;
+    if stream.withdrawn == stream.deposit {
+        stream.status = StreamStatus::Completed;
+    }
+    store_stream(env, &stream);
+
+    let event = StreamWithdrawnEvent {
+        stream_id,
+        recipient,
+        amount,
+    };
+    env.events().publish(
+        (Symbol::new(env, "treasury"), Symbol::new(env, "strm_wdraw")),
+        event,
+    );
+
+    amount
+}
+
+/// Stop accrual of an active stream. Any treasury signer may pause.
+pub fn pause_stream(env: &Env, stream_id: u64, signer: Address) -> bool {
+    let mut stream = get_stream(env, stream_id).expect("stream not found");
+    let treasury = get_treasury(env, stream.treasury_id).expect("treasury not found");
+    assert_signer(env, &treasury, &signer);
+
+    if stream.status != StreamStatus::Active {
+        panic!("stream not active");
+    }
+    stream.status = StreamStatus::Paused;
+    stream.paused_at = env.ledger().timestamp();
+    store_stream(env, &stream);
+
+    emit_status_changed(env, &stream, signer);
+    true
+}
+
+/// Resume a paused stream, shifting its schedule by the time spent paused
+/// after the stream started.
+pub fn resume_stream(env: &Env, stream_id: u64, signer: Address) -> bool {
+    let mut stream = get_stream(env, stream_id).expect("stream not found");
+    let treasury = get_treasury(env, stream.treasury_id).expect("treasury not found");
+    assert_signer(env, &treasury, &signer);
+
+    if stream.status != StreamStatus::Paused {
+        panic!("stream not paused");
+    }
+    let now = env.ledger().timestamp();
+    // time paused before the (already shifted) start delays nothing
+    let pause_start = stream
+        .paused_at
+        .max(stream.start_time + stream.paused_seconds);
+    stream.paused_seconds += now.saturating_sub(pause_start);
+    stream.paused_at = 0;
+    stream.status = StreamStatus::Active;
+    store_stream(env, &stream);
+
+    emit_status_changed(env, &stream, signer);
+    true
+}
+
+/// Cancel a stream. Any treasury signer may cancel.
+///
+/// The accrued but unclaimed amount is paid out to the recipient and the
+/// unstreamed remainder returns to the treasury. A stream whose funding
+/// transaction has not executed yet is closed together with that transaction.
+pub fn cancel_stream(env: &Env, stream_id: u64, signer: Address) -> bool {
+    let mut stream = get_stream(env, stream_id).expect("stream not found");
+    let treasury = get_treasury(env, stream.treasury_id).expect("treasury not found");
+    assert_signer(env, &treasury, &signer);
+
+    let mut paid_to_recipient = 0i128;
+    let mut refunded = 0i128;
+
+    match stream.status {
+        StreamStatus::Pending => {
+            let mut tx = get_transaction(env, stream.funding_tx_id).expect("tx not found");
+            if matches!(
+                tx.status,
+                TransactionStatus::Pending | TransactionStatus::Approved
+            ) {
+                tx.status = TransactionStatus::Rejected;
+                store_transaction(env, &tx);
+            }
+        }
+        StreamStatus::Active | StreamStatus::Paused => {
+            let streamed = stream.streamed_amount(env.ledger().timestamp());
+            let token = resolve_token(env, &stream.token);
+            let account = SubAccount::Stream(stream_id);
+
+            paid_to_recipient = streamed - stream.withdrawn;
+            if paid_to_recipient > 0 {
+                ledger::withdraw(env, &account, &token, &stream.recipient, paid_to_recipient);
+            }
+            stream.withdrawn = streamed;
+
+            refunded = stream.deposit - streamed;
+            credit_from_sub_account(
+                env,
+                stream.treasury_id,
+                &account,
+                &stream.token,
+                refunded,
+                TransactionType::Refund,
+                "stream_refund",
+                stream_id,
+            );
+        }
+        StreamStatus::Cancelled | StreamStatus::Completed => panic!("stream already closed"),
+    }
+
+    stream.status = StreamStatus::Cancelled;
+    stream.paused_at = 0;
+    store_stream(env, &stream);
+
+    let event = StreamCancelledEvent {
+        stream_id,
+        signer,
+        paid_to_recipient,
+        refunded,
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, "treasury"),
+            Symbol::new(env, "strm_cancel"),
+        ),
+        event,
+    );
+
+    true
+}
+
+/// Amount the recipient of a stream could withdraw right now.
+pub fn get_stream_withdrawable(env: &Env, stream_id: u64) -> i128 {
+    let stream = get_stream(env, stream_id).expect("stream not found");
+    stream.withdrawable_amount(env.ledger().timestamp())
+}
+
+fn emit_status_changed(env: &Env, stream: &Stream, signer: Address) {
+    let event = StreamStatusChangedEvent {
+        stream_id: stream.id,
+        signer,
+        status: stream.status.clone(),
+    };
+    env.events().publish(
+        (
+            Symbol::new(env, "treasury"),
+            Symbol::new(env, "strm_status"),
+        ),
+        event,
+    );
+}
@@ -1,10 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use crate::treasury::types::{TransactionStatus, TransactionType};
+    use crate::governance::types::VoteDecision;
+    use crate::guild::types::Role;
+    use crate::ledger::SubAccount;
+    use crate::treasury::types::{
+        ExpenseStatus, PayrollEntry, PayrollStatus, TransactionStatus, TransactionType,
+        WithdrawalLeg,
+    };
     use crate::StellarGuildsContract;
     use crate::StellarGuildsContractClient;
     use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
-    use soroban_sdk::{Address, Env, String, Vec};
+    use soroban_sdk::{token, Address, BytesN, Env, String, Vec};
 
     fn setup_env() -> Env {
         let env = Env::default();
@@ -28,10 +34,23 @@ mod tests {
     fn register_and_init_contract(env: &Env) -> Address {
         let contract_id = env.register_contract(None, StellarGuildsContract);
         let client = StellarGuildsContractClient::new(env, &contract_id);
-        client.initialize();
+        let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+        client
+            .mock_all_auths()
+            .initialize(&Address::generate(env), &native.address());
         contract_id
     }
 
+    fn mint_native(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        to: &Address,
+        amount: i128,
+    ) {
+        let native = client.get_native_token();
+        token::StellarAssetClient::new(env, &native).mint(to, &amount);
+    }
+
     fn setup_guild(client: &StellarGuildsContractClient<'_>, env: &Env, owner: &Address) -> u64 {
         let name = String::from_str(env, "Test Guild");
         let description = String::from_str(env, "A test guild");
@@ -49,6 +68,15 @@ mod tests {
 
         env.mock_all_auths();
 
+        // only guild admins can create treasuries
+        let guild_owner = client
+            .get_all_members(&guild_id)
+            .iter()
+            .find(|m| m.role == Role::Owner)
+            .unwrap()
+            .address;
+        client.add_member(&guild_id, &owner, &Role::Admin, &guild_owner);
+
         let mut signers = Vec::new(env);
         signers.push_back(owner.clone());
         signers.push_back(signer1.clone());
@@ -76,6 +104,8 @@ mod tests {
         let depositor = owner.clone();
         let amount: i128 = 500;
 
+        mint_native(&env, &client, &depositor, amount);
+
         let ok = client.deposit_treasury(&treasury_id, &depositor, &amount, &None);
         assert!(ok);
 
@@ -105,6 +135,7 @@ mod tests {
         let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
 
         let amount: i128 = 2000;
+        mint_native(&env, &client, &owner, amount);
         client.deposit_treasury(&treasury_id, &owner, &amount, &None);
 
         let recipient = Address::generate(&env);
@@ -129,6 +160,38 @@ mod tests {
         assert_eq!(history.len(), 2);
     }
 
+    #[test]
+    fn test_native_xlm_moves_through_sac() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let native = token::TokenClient::new(&env, &client.get_native_token());
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+
+        mint_native(&env, &client, &owner, 1000);
+        client.deposit_treasury(&treasury_id, &owner, &1000i128, &None);
+        assert_eq!(native.balance(&owner), 0);
+        assert_eq!(native.balance(&contract_id), 1000);
+
+        let recipient = Address::generate(&env);
+        let reason = String::from_str(&env, "payout");
+        let tx_id =
+            client.propose_withdrawal(&treasury_id, &signer1, &recipient, &400i128, &None, &reason);
+        client.approve_transaction(&tx_id, &signer2);
+        client.execute_transaction(&tx_id, &owner);
+
+        assert_eq!(native.balance(&recipient), 400);
+        assert_eq!(native.balance(&contract_id), 600);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 600);
+    }
+
     #[test]
     #[should_panic] // Removed strict string match to handle HostError envelope
     fn test_multisig_threshold_not_met() {
@@ -144,6 +207,8 @@ mod tests {
         let guild_id = setup_guild(&client, &env, &owner);
         let (treasury_id, owner, signer1, _signer2) = create_treasury(&env, &client, guild_id);
 
+        mint_native(&env, &client, &owner, 2000i128);
+
         client.deposit_treasury(&treasury_id, &owner, &2000i128, &None);
         let recipient = Address::generate(&env);
 
@@ -176,6 +241,8 @@ mod tests {
         let guild_id = setup_guild(&client, &env, &owner);
         let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
 
+        mint_native(&env, &client, &owner, 2000i128);
+
         client.deposit_treasury(&treasury_id, &owner, &2000i128, &None);
         let recipient = Address::generate(&env);
 
@@ -213,6 +280,8 @@ mod tests {
         let guild_id = setup_guild(&client, &env, &owner);
         let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
 
+        mint_native(&env, &client, &owner, 5000i128);
+
         client.deposit_treasury(&treasury_id, &owner, &5000i128, &None);
 
         let category = String::from_str(&env, "withdrawal");
@@ -259,6 +328,8 @@ mod tests {
         let guild_id = setup_guild(&client, &env, &owner);
         let (treasury_id, owner, signer1, _signer2) = create_treasury(&env, &client, guild_id);
 
+        mint_native(&env, &client, &owner, 1000i128);
+
         client.deposit_treasury(&treasury_id, &owner, &1000i128, &None);
 
         client.emergency_pause(&treasury_id, &signer1, &true);
@@ -269,4 +340,1381 @@ mod tests {
         // Panics here: treasury is paused
         client.propose_withdrawal(&treasury_id, &signer1, &recipient, &100i128, &None, &reason);
     }
+
+    fn setup_timelocked_withdrawal(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        guild_id: u64,
+        timelock: u64,
+        amount: i128,
+    ) -> (u64, u64, Address, Address) {
+        let (treasury_id, owner, signer1, signer2) = create_treasury(env, client, guild_id);
+        client.set_treasury_timelock(&treasury_id, &owner, &timelock);
+
+        mint_native(env, client, &owner, 5000);
+        client.deposit_treasury(&treasury_id, &owner, &5000i128, &None);
+
+        let recipient = Address::generate(env);
+        let reason = String::from_str(env, "large payout");
+        let tx_id =
+            client.propose_withdrawal(&treasury_id, &signer1, &recipient, &amount, &None, &reason);
+        client.approve_transaction(&tx_id, &signer2);
+
+        (treasury_id, tx_id, owner, signer2)
+    }
+
+    #[test]
+    fn test_high_value_withdrawal_waits_for_timelock() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, tx_id, owner, _signer2) =
+            setup_timelocked_withdrawal(&env, &client, guild_id, 3600, 1500);
+
+        let tx = client
+            .get_transaction_history(&treasury_id, &10u32)
+            .get(1)
+            .unwrap();
+        assert_eq!(tx.status, TransactionStatus::Approved);
+        assert_eq!(tx.executable_at, 1000 + 3600);
+
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+
+        set_ledger_timestamp(&env, 1000 + 3600);
+        client.execute_transaction(&tx_id, &owner);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 3500);
+    }
+
+    #[test]
+    fn test_low_value_withdrawal_skips_timelock() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, tx_id, owner, _signer2) =
+            setup_timelocked_withdrawal(&env, &client, guild_id, 3600, 500);
+
+        client.execute_transaction(&tx_id, &owner);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 4500);
+    }
+
+    #[test]
+    #[should_panic(expected = "transaction not executable")]
+    fn test_signer_veto_cancels_timelocked_transaction() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, tx_id, owner, signer2) =
+            setup_timelocked_withdrawal(&env, &client, guild_id, 3600, 1500);
+
+        client.veto_transaction(&tx_id, &signer2);
+
+        let tx = client
+            .get_transaction_history(&treasury_id, &10u32)
+            .get(1)
+            .unwrap();
+        assert_eq!(tx.status, TransactionStatus::Rejected);
+
+        set_ledger_timestamp(&env, 1000 + 3600);
+        client.execute_transaction(&tx_id, &owner);
+    }
+
+    #[test]
+    #[should_panic(expected = "transaction is not timelocked")]
+    fn test_veto_after_timelock_fails() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (_treasury_id, tx_id, _owner, signer2) =
+            setup_timelocked_withdrawal(&env, &client, guild_id, 3600, 1500);
+
+        set_ledger_timestamp(&env, 1000 + 3600);
+        client.veto_transaction(&tx_id, &signer2);
+    }
+
+    #[test]
+    fn test_governance_veto_cancels_timelocked_transaction() {
+        let env = setup_env();
+        let guild_owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &guild_owner);
+        let (treasury_id, tx_id, _owner, _signer2) =
+            setup_timelocked_withdrawal(&env, &client, guild_id, 10 * 86400, 1500);
+
+        let proposal_id = client.propose_treasury_veto(
+            &guild_id,
+            &guild_owner,
+            &tx_id,
+            &String::from_str(&env, "Veto payout"),
+            &String::from_str(&env, "Too large"),
+        );
+        client.vote(&proposal_id, &guild_owner, &VoteDecision::For);
+
+        let proposal = client.get_proposal(&proposal_id);
+        set_ledger_timestamp(&env, proposal.voting_end + 1);
+        assert!(client.execute_proposal(&proposal_id, &guild_owner));
+
+        let tx = client
+            .get_transaction_history(&treasury_id, &10u32)
+            .get(1)
+            .unwrap();
+        assert_eq!(tx.status, TransactionStatus::Rejected);
+    }
+
+    #[test]
+    fn test_rejections_close_transaction_once_approval_impossible() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+        mint_native(&env, &client, &owner, 2000);
+        client.deposit_treasury(&treasury_id, &owner, &2000i128, &None);
+
+        let recipient = Address::generate(&env);
+        let reason = String::from_str(&env, "payout");
+        let tx_id = client.propose_withdrawal(
+            &treasury_id,
+            &signer1,
+            &recipient,
+            &1500i128,
+            &None,
+            &reason,
+        );
+
+        // Two signers can still approve
+        client.reject_transaction(&tx_id, &owner);
+        let tx = client
+            .get_transaction_history(&treasury_id, &10u32)
+            .get(1)
+            .unwrap();
+        assert_eq!(tx.status, TransactionStatus::Pending);
+
+        client.reject_transaction(&tx_id, &signer2);
+        let tx = client
+            .get_transaction_history(&treasury_id, &10u32)
+            .get(1)
+            .unwrap();
+        assert_eq!(tx.status, TransactionStatus::Rejected);
+        assert_eq!(tx.rejections.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "signer already approved")]
+    fn test_reject_after_approving_fails() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, _owner, signer1, _signer2) = create_treasury(&env, &client, guild_id);
+
+        let recipient = Address::generate(&env);
+        let reason = String::from_str(&env, "payout");
+        let tx_id =
+            client.propose_withdrawal(&treasury_id, &signer1, &recipient, &100i128, &None, &reason);
+
+        client.reject_transaction(&tx_id, &signer1);
+    }
+
+    #[test]
+    fn test_add_signer_requires_threshold_approval() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, _signer2) = create_treasury(&env, &client, guild_id);
+
+        let new_signer = Address::generate(&env);
+        let tx_id = client.propose_add_signer(&treasury_id, &owner, &new_signer);
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+
+        client.approve_transaction(&tx_id, &signer1);
+        client.execute_transaction(&tx_id, &owner);
+
+        let treasury = client.get_treasury(&treasury_id);
+        assert_eq!(treasury.signers.len(), 4);
+        assert!(treasury.signers.contains(&new_signer));
+    }
+
+    #[test]
+    fn test_rotate_signer_and_change_threshold() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+
+        let replacement = Address::generate(&env);
+        let tx_id = client.propose_rotate_signer(&treasury_id, &owner, &signer2, &replacement);
+        client.approve_transaction(&tx_id, &signer1);
+        client.execute_transaction(&tx_id, &owner);
+
+        let tx_id = client.propose_threshold_change(&treasury_id, &owner, &3u32);
+        client.approve_transaction(&tx_id, &replacement);
+        client.execute_transaction(&tx_id, &owner);
+
+        let treasury = client.get_treasury(&treasury_id);
+        assert!(treasury.signers.contains(&replacement));
+        assert!(!treasury.signers.contains(&signer2));
+        assert_eq!(treasury.approval_threshold, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid threshold")]
+    fn test_remove_signer_below_threshold_fails() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+
+        let tx_id = client.propose_threshold_change(&treasury_id, &owner, &3u32);
+        client.approve_transaction(&tx_id, &signer1);
+        client.execute_transaction(&tx_id, &owner);
+
+        // Two signers left could never reach a threshold of three
+        client.propose_remove_signer(&treasury_id, &owner, &signer2);
+    }
+
+    #[test]
+    fn test_removed_signer_approvals_stop_counting() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+
+        // approved by signer2 and signer1 before signer2 leaves
+        let approved_tx =
+            client.propose_add_signer(&treasury_id, &signer2, &Address::generate(&env));
+        client.approve_transaction(&approved_tx, &signer1);
+        // only signer2 has approved this one so far
+        let pending_tx =
+            client.propose_add_signer(&treasury_id, &signer2, &Address::generate(&env));
+
+        let removal = client.propose_remove_signer(&treasury_id, &owner, &signer2);
+        client.approve_transaction(&removal, &signer1);
+        client.execute_transaction(&removal, &owner);
+
+        assert!(client
+            .try_execute_transaction(&approved_tx, &owner)
+            .is_err());
+        client.approve_transaction(&approved_tx, &owner);
+        client.execute_transaction(&approved_tx, &owner);
+
+        client.approve_transaction(&pending_tx, &signer1);
+        assert!(client.try_execute_transaction(&pending_tx, &owner).is_err());
+        client.approve_transaction(&pending_tx, &owner);
+        client.execute_transaction(&pending_tx, &owner);
+
+        assert_eq!(client.get_treasury(&treasury_id).signers.len(), 4);
+    }
+
+    #[test]
+    fn test_timelock_reduction_needs_threshold_approval() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, _s2) = create_treasury(&env, &client, guild_id);
+        client.set_treasury_timelock(&treasury_id, &owner, &3600u64);
+
+        // The owner alone can only lengthen the timelock
+        assert!(client
+            .try_set_treasury_timelock(&treasury_id, &owner, &0u64)
+            .is_err());
+        client.set_treasury_timelock(&treasury_id, &owner, &7200u64);
+
+        let tx_id = client.propose_timelock_change(&treasury_id, &owner, &0u64);
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+        assert_eq!(client.get_treasury(&treasury_id).timelock_seconds, 7200);
+
+        client.approve_transaction(&tx_id, &signer1);
+        client.execute_transaction(&tx_id, &owner);
+        assert_eq!(client.get_treasury(&treasury_id).timelock_seconds, 0);
+    }
+
+    #[test]
+    fn test_high_value_threshold_per_token() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+        client.set_treasury_timelock(&treasury_id, &owner, &3600u64);
+
+        let token = env
+            .register_stellar_asset_contract_v2(owner.clone())
+            .address();
+        token::StellarAssetClient::new(&env, &token).mint(&owner, &1000);
+        client.deposit_treasury(&treasury_id, &owner, &1000i128, &Some(token.clone()));
+        mint_native(&env, &client, &owner, 1000);
+        client.deposit_treasury(&treasury_id, &owner, &1000i128, &None);
+
+        let tx_id =
+            client.propose_high_value_threshold(&treasury_id, &owner, &Some(token.clone()), &100);
+        client.approve_transaction(&tx_id, &signer1);
+        client.execute_transaction(&tx_id, &owner);
+
+        let treasury = client.get_treasury(&treasury_id);
+        assert_eq!(treasury.high_value_threshold_for(&Some(token.clone())), 100);
+        assert_eq!(treasury.high_value_threshold_for(&None), 1000);
+
+        // 150 of the token is high-value and gets timelocked; 150 XLM is not
+        let recipient = Address::generate(&env);
+        let reason = String::from_str(&env, "payout");
+        let token_tx = client.propose_withdrawal(
+            &treasury_id,
+            &signer1,
+            &recipient,
+            &150i128,
+            &Some(token.clone()),
+            &reason,
+        );
+        client.approve_transaction(&token_tx, &signer2);
+        let xlm_tx =
+            client.propose_withdrawal(&treasury_id, &signer1, &recipient, &150i128, &None, &reason);
+        client.approve_transaction(&xlm_tx, &signer2);
+
+        assert!(client.try_execute_transaction(&token_tx, &owner).is_err());
+        client.execute_transaction(&xlm_tx, &owner);
+    }
+
+    fn setup_funded_stream(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        guild_id: u64,
+    ) -> (u64, u64, Address, Address) {
+        let (treasury_id, owner, signer1, signer2) = create_treasury(env, client, guild_id);
+        mint_native(env, client, &owner, 5000);
+        client.deposit_treasury(&treasury_id, &owner, &5000i128, &None);
+
+        // 1 XLM per second for 600 seconds, cliff after 100 seconds
+        let recipient = Address::generate(env);
+        let stream_id = client.propose_stream(
+            &treasury_id,
+            &signer1,
+            &recipient,
+            &None,
+            &1i128,
+            &1000u64,
+            &1600u64,
+            &1100u64,
+        );
+        let stream = client.get_stream(&stream_id);
+        client.approve_transaction(&stream.funding_tx_id, &signer2);
+        client.execute_transaction(&stream.funding_tx_id, &owner);
+
+        (treasury_id, stream_id, recipient, owner)
+    }
+
+    #[test]
+    fn test_stream_paused_before_start_shifts_by_time_after_start() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 900);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (_treasury_id, stream_id, _recipient, signer) =
+            setup_funded_stream(&env, &client, guild_id);
+
+        // Paused at 900, resumed at 1100: only the 100s after the start count
+        client.pause_stream(&stream_id, &signer);
+        set_ledger_timestamp(&env, 1100);
+        client.resume_stream(&stream_id, &signer);
+        assert_eq!(client.get_stream(&stream_id).paused_seconds, 100);
+
+        set_ledger_timestamp(&env, 1300);
+        assert_eq!(client.get_stream_withdrawable(&stream_id), 200);
+    }
+
+    #[test]
+    fn test_stream_accrues_pauses_and_cancels() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let native = token::Client::new(&env, &client.get_native_token());
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, stream_id, recipient, signer) =
+            setup_funded_stream(&env, &client, guild_id);
+
+        // The full deposit is reserved up front
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 4400);
+        assert_eq!(
+            client.get_stream(&stream_id).status,
+            crate::treasury::StreamStatus::Active
+        );
+
+        set_ledger_timestamp(&env, 1050);
+        assert_eq!(client.get_stream_withdrawable(&stream_id), 0);
+
+        set_ledger_timestamp(&env, 1200);
+        assert_eq!(client.withdraw_from_stream(&stream_id, &recipient), 200);
+        assert_eq!(native.balance(&recipient), 200);
+
+        client.pause_stream(&stream_id, &signer);
+        set_ledger_timestamp(&env, 1300);
+        assert_eq!(client.get_stream_withdrawable(&stream_id), 0);
+
+        client.resume_stream(&stream_id, &signer);
+        set_ledger_timestamp(&env, 1400);
+        assert_eq!(client.get_stream_withdrawable(&stream_id), 100);
+
+        // Accrued funds go to the recipient, the rest back to the treasury
+        client.cancel_stream(&stream_id, &signer);
+        assert_eq!(native.balance(&recipient), 300);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 4700);
+        assert_eq!(client.get_stream_withdrawable(&stream_id), 0);
+
+        let summary = client.get_spending_summary(&treasury_id, &0u64, &2000u64);
+        assert_eq!(summary.total_withdrawals, 600);
+        assert_eq!(summary.total_deposits, 5000);
+        assert_eq!(summary.total_refunds, 300);
+        assert_eq!(summary.net_flow, 4700);
+    }
+
+    #[test]
+    fn test_stream_completes_after_full_withdrawal() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (_treasury_id, stream_id, recipient, _signer) =
+            setup_funded_stream(&env, &client, guild_id);
+
+        set_ledger_timestamp(&env, 5000);
+        assert_eq!(client.withdraw_from_stream(&stream_id, &recipient), 600);
+        assert_eq!(
+            client.get_stream(&stream_id).status,
+            crate::treasury::StreamStatus::Completed
+        );
+        assert!(client
+            .try_withdraw_from_stream(&stream_id, &recipient)
+            .is_err());
+    }
+
+    #[test]
+    fn test_cancel_pending_stream_rejects_funding() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, _signer2) = create_treasury(&env, &client, guild_id);
+
+        let recipient = Address::generate(&env);
+        let stream_id = client.propose_stream(
+            &treasury_id,
+            &signer1,
+            &recipient,
+            &None,
+            &1i128,
+            &1000u64,
+            &1600u64,
+            &1000u64,
+        );
+        let tx_id = client.get_stream(&stream_id).funding_tx_id;
+        client.cancel_stream(&stream_id, &owner);
+
+        assert!(client.try_approve_transaction(&tx_id, &owner).is_err());
+        let tx = client
+            .get_transaction_history(&treasury_id, &10u32)
+            .get(0)
+            .unwrap();
+        assert_eq!(tx.tx_type, TransactionType::StreamFunding);
+        assert_eq!(tx.status, TransactionStatus::Rejected);
+    }
+
+    #[test]
+    #[should_panic(expected = "only the recipient can withdraw")]
+    fn test_stream_withdraw_by_non_recipient_fails() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (_treasury_id, stream_id, _recipient, signer) =
+            setup_funded_stream(&env, &client, guild_id);
+
+        set_ledger_timestamp(&env, 1200);
+        client.withdraw_from_stream(&stream_id, &signer);
+    }
+
+    fn payroll_entries(env: &Env, a: &Address, b: &Address) -> Vec<PayrollEntry> {
+        let mut entries = Vec::new(env);
+        entries.push_back(PayrollEntry {
+            recipient: a.clone(),
+            amount: 300,
+        });
+        entries.push_back(PayrollEntry {
+            recipient: b.clone(),
+            amount: 400,
+        });
+        entries
+    }
+
+    #[test]
+    fn test_payroll_pays_installments_and_retries_failures() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let native = token::Client::new(&env, &client.get_native_token());
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+        mint_native(&env, &client, &owner, 2500);
+        client.deposit_treasury(&treasury_id, &owner, &1000i128, &None);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let order_id = client.propose_payroll(
+            &treasury_id,
+            &signer1,
+            &payroll_entries(&env, &alice, &bob),
+            &None,
+            &100u64,
+            &1100u64,
+            &1300u64,
+        );
+        let tx_id = client.get_payroll_order(&order_id).approval_tx_id;
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+        client.approve_transaction(&tx_id, &signer2);
+        client.execute_transaction(&tx_id, &owner);
+
+        assert!(client.try_run_payroll(&order_id).is_err());
+
+        set_ledger_timestamp(&env, 1100);
+        assert_eq!(client.run_payroll(&order_id), 2);
+
+        // Only 300 left: bob's payout fails and is queued
+        set_ledger_timestamp(&env, 1200);
+        assert_eq!(client.run_payroll(&order_id), 1);
+        let order = client.get_payroll_order(&order_id);
+        assert_eq!(order.failed_payouts.len(), 1);
+        assert_eq!(order.failed_payouts.get(0).unwrap().recipient, bob);
+
+        // A failed payout is retried at most once per interval
+        client.deposit_treasury(&treasury_id, &owner, &1500i128, &None);
+        set_ledger_timestamp(&env, 1250);
+        assert!(client.try_run_payroll(&order_id).is_err());
+
+        set_ledger_timestamp(&env, 1300);
+        assert_eq!(client.run_payroll(&order_id), 3);
+
+        let order = client.get_payroll_order(&order_id);
+        assert_eq!(order.installments_paid, 3);
+        assert_eq!(order.status, PayrollStatus::Completed);
+        assert_eq!(native.balance(&alice), 900);
+        assert_eq!(native.balance(&bob), 1200);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 400);
+
+        let summary = client.get_spending_summary(&treasury_id, &0u64, &2000u64);
+        assert_eq!(summary.total_withdrawals, 2100);
+    }
+
+    #[test]
+    fn test_payroll_respects_payroll_budget() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+        mint_native(&env, &client, &owner, 5000);
+        client.deposit_treasury(&treasury_id, &owner, &5000i128, &None);
+        client.set_budget(
+            &treasury_id,
+            &String::from_str(&env, "payroll"),
+            &500i128,
+            &3600u64,
+            &owner,
+        );
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let order_id = client.propose_payroll(
+            &treasury_id,
+            &signer1,
+            &payroll_entries(&env, &alice, &bob),
+            &None,
+            &100u64,
+            &1000u64,
+            &5000u64,
+        );
+        let tx_id = client.get_payroll_order(&order_id).approval_tx_id;
+        client.approve_transaction(&tx_id, &signer2);
+        client.execute_transaction(&tx_id, &owner);
+
+        assert_eq!(client.run_payroll(&order_id), 1);
+        let order = client.get_payroll_order(&order_id);
+        assert_eq!(order.failed_payouts.len(), 1);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 4700);
+
+        // Repeated keeper calls cannot burn through retries
+        assert!(client.try_run_payroll(&order_id).is_err());
+
+        // Failed payouts stay owed however often they fail
+        for run in 1..=6u64 {
+            set_ledger_timestamp(&env, 1000 + run * 100);
+            client.run_payroll(&order_id);
+        }
+        let queued = client.get_payroll_order(&order_id).failed_payouts;
+        let first = queued.get(0).unwrap();
+        assert_eq!(first.recipient, bob);
+        assert_eq!(first.attempts, 7);
+        assert_eq!(first.last_attempt_at, 1600);
+
+        // Only a signer cancelling the order drops them
+        client.cancel_payroll(&order_id, &owner);
+        assert!(client
+            .get_payroll_order(&order_id)
+            .failed_payouts
+            .is_empty());
+    }
+
+    #[test]
+    fn test_cancel_pending_payroll_rejects_approval() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, _signer2) = create_treasury(&env, &client, guild_id);
+
+        let order_id = client.propose_payroll(
+            &treasury_id,
+            &signer1,
+            &payroll_entries(&env, &owner, &signer1),
+            &None,
+            &100u64,
+            &1000u64,
+            &5000u64,
+        );
+        client.cancel_payroll(&order_id, &owner);
+
+        let order = client.get_payroll_order(&order_id);
+        assert_eq!(order.status, PayrollStatus::Cancelled);
+        assert!(client
+            .try_approve_transaction(&order.approval_tx_id, &owner)
+            .is_err());
+        assert!(client.try_run_payroll(&order_id).is_err());
+    }
+
+    fn batch_legs(
+        env: &Env,
+        a: &Address,
+        b: &Address,
+        token: &Option<Address>,
+    ) -> Vec<WithdrawalLeg> {
+        let mut legs = Vec::new(env);
+        legs.push_back(WithdrawalLeg {
+            recipient: a.clone(),
+            token: token.clone(),
+            amount: 300,
+        });
+        legs.push_back(WithdrawalLeg {
+            recipient: b.clone(),
+            token: token.clone(),
+            amount: 400,
+        });
+        legs.push_back(WithdrawalLeg {
+            recipient: b.clone(),
+            token: token.clone(),
+            amount: 250,
+        });
+        legs
+    }
+
+    #[test]
+    fn test_batch_withdrawal_pays_all_legs_after_one_approval() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+
+        let token = env
+            .register_stellar_asset_contract_v2(owner.clone())
+            .address();
+        token::StellarAssetClient::new(&env, &token).mint(&owner, &500);
+        client.deposit_treasury(&treasury_id, &owner, &500i128, &Some(token.clone()));
+        mint_native(&env, &client, &owner, 2000);
+        client.deposit_treasury(&treasury_id, &owner, &2000i128, &None);
+
+        // Two native legs and one token leg
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let mut legs = batch_legs(&env, &alice, &bob, &None);
+        legs.set(
+            2,
+            WithdrawalLeg {
+                recipient: bob.clone(),
+                token: Some(token.clone()),
+                amount: 250,
+            },
+        );
+        let tx_id = client.propose_batch_withdrawal(
+            &treasury_id,
+            &signer1,
+            &legs,
+            &String::from_str(&env, "contributors"),
+        );
+        client.approve_transaction(&tx_id, &signer2);
+        client.execute_transaction(&tx_id, &owner);
+
+        let native = token::Client::new(&env, &client.get_native_token());
+        assert_eq!(native.balance(&alice), 300);
+        assert_eq!(native.balance(&bob), 400);
+        assert_eq!(token::Client::new(&env, &token).balance(&bob), 250);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 1300);
+        assert_eq!(
+            client.get_treasury_balance(&treasury_id, &Some(token.clone())),
+            250
+        );
+
+        // Two deposits plus one entry per leg
+        let history = client.get_transaction_history(&treasury_id, &10u32);
+        assert_eq!(history.len(), 5);
+        let leg = history.get(4).unwrap();
+        assert_eq!(leg.id, tx_id);
+        assert_eq!(leg.tx_type, TransactionType::BatchWithdrawal);
+        assert_eq!(leg.status, TransactionStatus::Executed);
+        assert_eq!(leg.recipient, Some(bob));
+        assert_eq!(leg.token, Some(token));
+        assert_eq!(leg.amount, 250);
+    }
+
+    #[test]
+    fn test_batch_withdrawal_checks_each_token_total() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, _s2) = create_treasury(&env, &client, guild_id);
+
+        // Plenty of native but only 200 of the token
+        let token = env
+            .register_stellar_asset_contract_v2(owner.clone())
+            .address();
+        token::StellarAssetClient::new(&env, &token).mint(&owner, &200);
+        client.deposit_treasury(&treasury_id, &owner, &200i128, &Some(token.clone()));
+        mint_native(&env, &client, &owner, 2000);
+        client.deposit_treasury(&treasury_id, &owner, &2000i128, &None);
+
+        let mut legs = batch_legs(&env, &Address::generate(&env), &owner, &None);
+        legs.push_back(WithdrawalLeg {
+            recipient: owner.clone(),
+            token: Some(token.clone()),
+            amount: 150,
+        });
+        legs.push_back(WithdrawalLeg {
+            recipient: signer1.clone(),
+            token: Some(token),
+            amount: 100,
+        });
+        assert!(client
+            .try_propose_batch_withdrawal(
+                &treasury_id,
+                &signer1,
+                &legs,
+                &String::from_str(&env, "contributors"),
+            )
+            .is_err());
+
+        // The native total alone would fit the budget, not both tokens' totals
+        legs.pop_back();
+        let category = String::from_str(&env, "withdrawal");
+        client.set_budget(&treasury_id, &category, &1000i128, &3600u64, &owner);
+        let tx_id = client.propose_batch_withdrawal(
+            &treasury_id,
+            &signer1,
+            &legs,
+            &String::from_str(&env, "contributors"),
+        );
+        client.approve_transaction(&tx_id, &owner);
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+    }
+
+    #[test]
+    fn test_batch_withdrawal_aborts_when_any_leg_fails() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+
+        mint_native(&env, &client, &owner, 1000);
+        client.deposit_treasury(&treasury_id, &owner, &1000i128, &None);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let tx_id = client.propose_batch_withdrawal(
+            &treasury_id,
+            &signer1,
+            &batch_legs(&env, &alice, &bob, &None),
+            &String::from_str(&env, "contributors"),
+        );
+        client.approve_transaction(&tx_id, &signer2);
+
+        // Another payout leaves enough for the first two legs but not the third
+        let other_id = client.propose_withdrawal(
+            &treasury_id,
+            &signer1,
+            &Address::generate(&env),
+            &200i128,
+            &None,
+            &String::from_str(&env, "other"),
+        );
+        client.approve_transaction(&other_id, &signer2);
+        client.execute_transaction(&other_id, &owner);
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+
+        let native = token::Client::new(&env, &client.get_native_token());
+        assert_eq!(native.balance(&alice), 0);
+        assert_eq!(native.balance(&bob), 0);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 800);
+        let batch = client
+            .get_transaction_history(&treasury_id, &10u32)
+            .iter()
+            .find(|tx| tx.id == tx_id)
+            .unwrap();
+        assert_eq!(batch.status, TransactionStatus::Approved);
+    }
+
+    #[test]
+    #[should_panic(expected = "budget exceeded")]
+    fn test_batch_withdrawal_budget_checks_aggregate() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+
+        mint_native(&env, &client, &owner, 2000);
+        client.deposit_treasury(&treasury_id, &owner, &2000i128, &None);
+
+        // Each leg fits the budget on its own, the batch does not
+        let category = String::from_str(&env, "withdrawal");
+        client.set_budget(&treasury_id, &category, &500i128, &3600u64, &owner);
+
+        let mut legs = Vec::new(&env);
+        for _ in 0..2 {
+            legs.push_back(WithdrawalLeg {
+                recipient: Address::generate(&env),
+                token: None,
+                amount: 300,
+            });
+        }
+        let tx_id = client.propose_batch_withdrawal(
+            &treasury_id,
+            &signer1,
+            &legs,
+            &String::from_str(&env, "contributors"),
+        );
+        client.approve_transaction(&tx_id, &signer2);
+        client.execute_transaction(&tx_id, &owner);
+    }
+
+    /// Treasury holding 2000 XLM with a 2000 "travel" budget, plus a guild
+    /// member who can file expenses.
+    fn setup_expense_treasury(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+    ) -> (u64, Address, Address, Address) {
+        let guild_owner = Address::generate(env);
+        let guild_id = setup_guild(client, env, &guild_owner);
+        let (treasury_id, owner, signer1, _signer2) = create_treasury(env, client, guild_id);
+
+        let member = Address::generate(env);
+        client.add_member(&guild_id, &member, &Role::Member, &guild_owner);
+
+        mint_native(env, client, &owner, 2000);
+        client.deposit_treasury(&treasury_id, &owner, &2000i128, &None);
+        client.set_budget(
+            &treasury_id,
+            &String::from_str(env, "travel"),
+            &2000i128,
+            &3600u64,
+            &owner,
+        );
+
+        (treasury_id, owner, signer1, member)
+    }
+
+    fn submit_expense(
+        env: &Env,
+        client: &StellarGuildsContractClient<'_>,
+        treasury_id: u64,
+        member: &Address,
+        amount: i128,
+    ) -> u64 {
+        client.submit_expense_claim(
+            &treasury_id,
+            member,
+            &amount,
+            &None,
+            &String::from_str(env, "travel"),
+            &BytesN::from_array(env, &[1u8; 32]),
+            &String::from_str(env, "conference train tickets"),
+        )
+    }
+
+    #[test]
+    fn test_expense_claim_paid_by_designated_approver() {
+        let env = setup_env();
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let (treasury_id, owner, _signer1, member) = setup_expense_treasury(&env, &client);
+
+        let accountant = Address::generate(&env);
+        let mut approvers = Vec::new(&env);
+        approvers.push_back(accountant.clone());
+        client.set_expense_approvers(&treasury_id, &owner, &approvers);
+
+        let category = String::from_str(&env, "travel");
+        client.set_budget(&treasury_id, &category, &500i128, &3600u64, &owner);
+
+        let paid_id = submit_expense(&env, &client, treasury_id, &member, 300);
+        let pending_id = submit_expense(&env, &client, treasury_id, &member, 100);
+
+        let tx_id = client.approve_expense_claim(&paid_id, &accountant);
+        let claim = client.get_expense_claim(&paid_id);
+        assert_eq!(claim.status, ExpenseStatus::Paid);
+        assert_eq!(claim.reviewed_by, accountant);
+        assert_eq!(claim.payment_tx_id, tx_id);
+
+        let native = token::Client::new(&env, &client.get_native_token());
+        assert_eq!(native.balance(&member), 300);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 1700);
+        let utilization = client.get_budget_utilization(&treasury_id).get(0).unwrap();
+        assert_eq!(utilization.category, category);
+        assert_eq!(utilization.spent, 300);
+
+        assert_eq!(client.get_member_expense_claims(&member).len(), 2);
+        let pending = client.get_expense_claims_by_status(&treasury_id, &ExpenseStatus::Pending);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending.get(0).unwrap().id, pending_id);
+        let paid = client.get_expense_claims_by_status(&treasury_id, &ExpenseStatus::Paid);
+        assert_eq!(paid.len(), 1);
+    }
+
+    #[test]
+    fn test_expense_claim_over_category_budget_stays_pending() {
+        let env = setup_env();
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let (treasury_id, owner, signer1, member) = setup_expense_treasury(&env, &client);
+
+        let category = String::from_str(&env, "travel");
+        client.set_budget(&treasury_id, &category, &200i128, &3600u64, &owner);
+
+        let claim_id = submit_expense(&env, &client, treasury_id, &member, 300);
+        assert!(client
+            .try_approve_expense_claim(&claim_id, &signer1)
+            .is_err());
+        assert_eq!(
+            client.get_expense_claim(&claim_id).status,
+            ExpenseStatus::Pending
+        );
+
+        client.reject_expense_claim(&claim_id, &signer1);
+        let claim = client.get_expense_claim(&claim_id);
+        assert_eq!(claim.status, ExpenseStatus::Rejected);
+        assert_eq!(claim.payment_tx_id, 0);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 2000);
+    }
+
+    #[test]
+    fn test_expense_claim_review_permissions() {
+        let env = setup_env();
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let (treasury_id, _owner, _signer1, member) = setup_expense_treasury(&env, &client);
+
+        let outsider = Address::generate(&env);
+        assert!(client
+            .try_submit_expense_claim(
+                &treasury_id,
+                &outsider,
+                &100i128,
+                &None,
+                &String::from_str(&env, "travel"),
+                &BytesN::from_array(&env, &[1u8; 32]),
+                &String::from_str(&env, "not a member"),
+            )
+            .is_err());
+
+        let claim_id = submit_expense(&env, &client, treasury_id, &member, 100);
+        assert!(client
+            .try_approve_expense_claim(&claim_id, &outsider)
+            .is_err());
+        assert!(client
+            .try_approve_expense_claim(&claim_id, &member)
+            .is_err());
+    }
+
+    #[test]
+    fn test_expense_claim_needs_category_budget() {
+        let env = setup_env();
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let (treasury_id, _owner, _signer1, member) = setup_expense_treasury(&env, &client);
+
+        assert!(client
+            .try_submit_expense_claim(
+                &treasury_id,
+                &member,
+                &100i128,
+                &None,
+                &String::from_str(&env, "equipment"),
+                &BytesN::from_array(&env, &[1u8; 32]),
+                &String::from_str(&env, "new laptop"),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_high_value_expense_claim_needs_signers_and_timelock() {
+        let env = setup_env();
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let (treasury_id, owner, signer1, member) = setup_expense_treasury(&env, &client);
+        client.set_treasury_timelock(&treasury_id, &owner, &3600u64);
+
+        let claim_id = submit_expense(&env, &client, treasury_id, &member, 1200);
+        let tx_id = client.approve_expense_claim(&claim_id, &signer1);
+
+        // approving the claim only proposes its payment
+        let claim = client.get_expense_claim(&claim_id);
+        assert_eq!(claim.status, ExpenseStatus::Approved);
+        assert_eq!(claim.payment_tx_id, tx_id);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 2000);
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+
+        client.approve_transaction(&tx_id, &owner);
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+
+        set_ledger_timestamp(&env, 1000 + 3600);
+        client.execute_transaction(&tx_id, &owner);
+
+        let native = token::Client::new(&env, &client.get_native_token());
+        assert_eq!(native.balance(&member), 1200);
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 800);
+        assert_eq!(
+            client.get_expense_claim(&claim_id).status,
+            ExpenseStatus::Paid
+        );
+        let utilization = client.get_budget_utilization(&treasury_id).get(0).unwrap();
+        assert_eq!(utilization.spent, 1200);
+    }
+
+    #[test]
+    fn test_expense_claim_reopens_when_payment_fails() {
+        let env = setup_env();
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let (treasury_id, owner, signer1, member) = setup_expense_treasury(&env, &client);
+
+        let accountant = Address::generate(&env);
+        let mut approvers = Vec::new(&env);
+        approvers.push_back(accountant.clone());
+        client.set_expense_approvers(&treasury_id, &owner, &approvers);
+
+        let claim_id = submit_expense(&env, &client, treasury_id, &member, 1200);
+
+        // signers rejecting the payment send the claim back for review
+        let tx_id = client.approve_expense_claim(&claim_id, &accountant);
+        client.reject_transaction(&tx_id, &owner);
+        assert_eq!(
+            client.get_expense_claim(&claim_id).status,
+            ExpenseStatus::Approved
+        );
+        client.reject_transaction(&tx_id, &signer1);
+        let claim = client.get_expense_claim(&claim_id);
+        assert_eq!(claim.status, ExpenseStatus::Pending);
+        assert_eq!(claim.payment_tx_id, 0);
+
+        // so does a payment left to expire
+        let retry_id = client.approve_expense_claim(&claim_id, &accountant);
+        assert_ne!(retry_id, tx_id);
+        set_ledger_timestamp(&env, 1000 + 7 * 24 * 60 * 60);
+        assert!(client.reject_expense_claim(&claim_id, &accountant));
+        assert_eq!(
+            client.get_expense_claim(&claim_id).status,
+            ExpenseStatus::Rejected
+        );
+        assert_eq!(client.get_treasury_balance(&treasury_id, &None), 2000);
+    }
+
+    #[test]
+    #[should_panic(expected = "only guild owner or admin can create a treasury")]
+    fn test_non_admin_cannot_create_treasury() {
+        let env = setup_env();
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+        let guild_id = setup_guild(&client, &env, &Address::generate(&env));
+
+        let outsider = Address::generate(&env);
+        let mut signers = Vec::new(&env);
+        signers.push_back(outsider);
+        client.initialize_treasury(&guild_id, &signers, &1u32);
+    }
+
+    #[test]
+    fn test_internal_transfer_moves_balance_between_treasuries() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let (operations, owner, signer1, signer2) = create_treasury(&env, &client, guild_id);
+        let (reserve, _, _, _) = create_treasury(&env, &client, guild_id);
+
+        mint_native(&env, &client, &owner, 5000);
+        client.deposit_treasury(&operations, &owner, &5000i128, &None);
+
+        let tx_id = client.propose_internal_transfer(
+            &operations,
+            &reserve,
+            &signer1,
+            &3000i128,
+            &None,
+            &String::from_str(&env, "top up reserve"),
+        );
+        // Needs the full threshold and, being high-value, waits out the timelock
+        client.set_treasury_timelock(&operations, &owner, &3600u64);
+        client.approve_transaction(&tx_id, &signer2);
+        assert!(client.try_execute_transaction(&tx_id, &owner).is_err());
+        set_ledger_timestamp(&env, 1000 + 3600);
+        client.execute_transaction(&tx_id, &owner);
+
+        assert_eq!(client.get_treasury_balance(&operations, &None), 2000);
+        assert_eq!(client.get_treasury_balance(&reserve, &None), 3000);
+        assert_eq!(
+            client.get_sub_account_balance(&SubAccount::Treasury(reserve), &None),
+            3000
+        );
+
+        let sent = client.get_transaction_history(&operations, &10u32);
+        let sent_tx = sent.get(1).unwrap();
+        assert_eq!(sent_tx.tx_type, TransactionType::InternalTransfer);
+        assert_eq!(sent_tx.reference_id, Some(reserve));
+
+        let received = client.get_transaction_history(&reserve, &10u32);
+        assert_eq!(received.len(), 1);
+        let received_tx = received.get(0).unwrap();
+        assert_eq!(
+            received_tx.tx_type,
+            TransactionType::InternalTransferReceived
+        );
+        assert_eq!(received_tx.status, TransactionStatus::Executed);
+        assert_eq!(received_tx.reference_id, Some(tx_id));
+        assert_eq!(received_tx.amount, 3000);
+
+        // Neither side counts the transfer as a withdrawal or deposit
+        let summary = client.get_spending_summary(&operations, &0u64, &5000u64);
+        assert_eq!(summary.total_deposits, 5000);
+        assert_eq!(summary.total_withdrawals, 0);
+        let summary = client.get_spending_summary(&reserve, &0u64, &5000u64);
+        assert_eq!(summary.total_deposits, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "treasuries belong to different guilds")]
+    fn test_internal_transfer_to_other_guild_fails() {
+        let env = setup_env();
+        let owner = Address::generate(&env);
+
+        set_ledger_timestamp(&env, 1000);
+        env.mock_all_auths();
+
+        let contract_id = register_and_init_contract(&env);
+        let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+        let guild_id = setup_guild(&client, &env, &owner);
+        let other_guild_id = setup_guild(&client, &env, &owner);
+        let (treasury_id, _owner, signer1, _signer2) = create_treasury(&env, &client, guild_id);
+        let (other_id, _, _, _) = create_treasury(&env, &client, other_guild_id);
+
+        client.propose_internal_transfer(
+            &treasury_id,
+            &other_id,
+            &signer1,
+            &100i128,
+            &None,
+            &String::from_str(&env, "wrong guild"),
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address, Env, Map, String, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, Env, Map, String, Vec};
 
 /// Error types for treasury operations
 #[contracterror]
@@ -7,6 +7,7 @@ use soroban_sdk::{contracterror, contracttype, Address, Env, Map, String, Vec};
 pub enum TreasuryError {
     BudgetExceeded = 1,
     AllowanceExceeded = 2,
+    InsufficientBalance = 3,
 }
 
 #[contracttype]
@@ -17,6 +18,34 @@ pub enum TransactionType {
     BountyFunding,
     MilestonePayment,
     AllowanceGrant,
+    /// Add `recipient` as a signer
+    AddSigner,
+    /// Remove `recipient` from the signers
+    RemoveSigner,
+    /// Replace signer `replaces` with `recipient`
+    RotateSigner,
+    /// Set the approval threshold to `amount`
+    ChangeThreshold,
+    /// Set the high-value threshold of `token` to `amount`
+    SetHighValueThreshold,
+    /// Reserve `amount` for the payment stream `reference_id`
+    StreamFunding,
+    /// Lock `amount` for the vesting grant `reference_id`
+    VestingFunding,
+    /// Authorize the recurring payroll order `reference_id`; `amount` is one installment
+    PayrollOrder,
+    /// Pay every leg in `legs` atomically; `amount` is the sum of all legs
+    BatchWithdrawal,
+    /// Move `amount` to the treasury `reference_id` of the same guild
+    InternalTransfer,
+    /// Receiving side of the `InternalTransfer` transaction `reference_id`
+    InternalTransferReceived,
+    /// Unspent funds of the bounty, stream or grant `reference_id` returned to the treasury
+    Refund,
+    /// Set the high-value timelock to `amount` seconds
+    SetTimelock,
+    /// Reimburse the high-value expense claim `reference_id`, charged to its budget category
+    ExpensePayment,
 }
 
 #[contracttype]
@@ -40,10 +69,29 @@ pub struct Transaction {
     pub recipient: Option<Address>,
     pub proposer: Address,
     pub approvals: Vec<Address>,
+    /// Signers that explicitly rejected the transaction
+    pub rejections: Vec<Address>,
     pub status: TransactionStatus,
     pub created_at: u64,
     pub expires_at: u64,
     pub reason: String,
+    /// Linked record for this transaction (e.g. the bounty a `BountyFunding` tx pays into)
+    pub reference_id: Option<u64>,
+    /// Earliest execution time of an approved, timelocked transaction (0 if not timelocked)
+    pub executable_at: u64,
+    /// Signer being replaced by a `RotateSigner` transaction
+    pub replaces: Option<Address>,
+    /// Legs of a `BatchWithdrawal` (empty for every other type)
+    pub legs: Vec<WithdrawalLeg>,
+}
+
+/// A single payment of a batch withdrawal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalLeg {
+    pub recipient: Address,
+    pub token: Option<Address>,
+    pub amount: i128,
 }
 
 #[contracttype]
@@ -54,12 +102,19 @@ pub struct Treasury {
     pub owner: Address,
     pub signers: Vec<Address>,
     pub approval_threshold: u32,
+    /// High-value threshold for native XLM, also used for tokens without their own
     pub high_value_threshold: i128,
+    /// Per-token high-value thresholds
+    pub token_high_value_thresholds: Map<Address, i128>,
     pub balance_xlm: i128,
     pub token_balances: Map<Address, i128>,
     pub total_deposits: i128,
     pub total_withdrawals: i128,
+    /// Funds returned by bounties, streams and grants the treasury paid for
+    pub total_refunds: i128,
     pub paused: bool,
+    /// Delay between approval and execution of high-value transactions (0 disables)
+    pub timelock_seconds: u64,
 }
 
 #[contracttype]
@@ -85,6 +140,141 @@ pub struct Allowance {
     pub period_start: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StreamStatus {
+    /// Waiting for its funding transaction to execute
+    Pending,
+    Active,
+    Paused,
+    Cancelled,
+    /// Fully streamed and withdrawn
+    Completed,
+}
+
+/// A treasury-funded payment stream that accrues `rate_per_second` to the
+/// recipient between `start_time` and `end_time`.
+///
+/// Pausing stops accrual; the time spent paused is added to the schedule so
+/// the full deposit is still streamed once resumed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stream {
+    pub id: u64,
+    pub treasury_id: u64,
+    pub recipient: Address,
+    pub token: Option<Address>,
+    pub rate_per_second: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Nothing can be withdrawn before the cliff
+    pub cliff_time: u64,
+    /// Full amount reserved from the treasury: `rate_per_second * (end_time - start_time)`
+    pub deposit: i128,
+    pub withdrawn: i128,
+    pub status: StreamStatus,
+    /// Start of the current pause (0 when not paused)
+    pub paused_at: u64,
+    /// Total time spent paused so far
+    pub paused_seconds: u64,
+    /// Treasury transaction that funds the stream
+    pub funding_tx_id: u64,
+    pub created_by: Address,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayrollEntry {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayrollStatus {
+    /// Waiting for its approval transaction to execute
+    Pending,
+    Active,
+    /// Every installment has run and no failed payout is left to retry
+    Completed,
+    Cancelled,
+}
+
+/// A payout leg that could not be paid when due; retried by the keeper at
+/// most once per payroll interval
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FailedPayout {
+    pub recipient: Address,
+    pub amount: i128,
+    /// Installment time the payout was originally due
+    pub due_at: u64,
+    pub attempts: u32,
+    /// When the payout last failed; the next retry waits one interval
+    pub last_attempt_at: u64,
+}
+
+/// Recurring payment order paying every entry once per `interval_seconds`
+/// until `end_time`, approved once through the treasury threshold flow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayrollOrder {
+    pub id: u64,
+    pub treasury_id: u64,
+    pub entries: Vec<PayrollEntry>,
+    pub token: Option<Address>,
+    pub interval_seconds: u64,
+    /// Due time of the next installment
+    pub next_payment_at: u64,
+    /// No installment is due after this timestamp
+    pub end_time: u64,
+    pub installments_paid: u32,
+    /// Payouts waiting to be retried
+    pub failed_payouts: Vec<FailedPayout>,
+    pub status: PayrollStatus,
+    pub approval_tx_id: u64,
+    pub created_by: Address,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpenseStatus {
+    /// Waiting for an expense approver or treasury signer
+    Pending,
+    /// Approved and reimbursed from the treasury
+    Paid,
+    Rejected,
+    /// High-value claim approved by a reviewer, waiting for its `ExpensePayment`
+    /// transaction to pass the signer threshold and timelock
+    Approved,
+}
+
+/// Out-of-pocket guild cost a member asks the treasury to reimburse
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpenseClaim {
+    pub id: u64,
+    pub treasury_id: u64,
+    pub claimant: Address,
+    pub amount: i128,
+    pub token: Option<Address>,
+    /// Budget category the reimbursement is charged to
+    pub category: String,
+    /// Hash of the receipt document stored off-chain
+    pub receipt_hash: BytesN<32>,
+    pub description: String,
+    pub status: ExpenseStatus,
+    /// Approver or signer who decided the claim (the claimant while pending)
+    pub reviewed_by: Address,
+    /// Timestamp of the decision (0 while pending)
+    pub reviewed_at: u64,
+    /// Transaction paying the claim (0 while pending or rejected)
+    pub payment_tx_id: u64,
+    pub created_at: u64,
+}
+
 // Events
 
 #[contracttype]
@@ -115,6 +305,50 @@ pub struct WithdrawalProposedEvent {
     pub token: Option<Address>,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InternalTransferProposedEvent {
+    pub from_treasury_id: u64,
+    pub to_treasury_id: u64,
+    pub tx_id: u64,
+    pub proposer: Address,
+    pub amount: i128,
+    pub token: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InternalTransferEvent {
+    pub from_treasury_id: u64,
+    pub to_treasury_id: u64,
+    pub tx_id: u64,
+    /// `InternalTransferReceived` entry recorded in the destination history
+    pub received_tx_id: u64,
+    pub amount: i128,
+    pub token: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchWithdrawalProposedEvent {
+    pub treasury_id: u64,
+    pub tx_id: u64,
+    pub proposer: Address,
+    pub leg_count: u32,
+    pub total_amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyFundingProposedEvent {
+    pub treasury_id: u64,
+    pub tx_id: u64,
+    pub bounty_id: u64,
+    pub proposer: Address,
+    pub amount: i128,
+    pub token: Address,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TransactionApprovedEvent {
@@ -123,6 +357,41 @@ pub struct TransactionApprovedEvent {
     pub approver: Address,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionRejectedEvent {
+    pub treasury_id: u64,
+    pub tx_id: u64,
+    pub rejecter: Address,
+    /// Whether the rejection made approval impossible and closed the transaction
+    pub closed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigChangeProposedEvent {
+    pub treasury_id: u64,
+    pub tx_id: u64,
+    pub proposer: Address,
+    pub tx_type: TransactionType,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignersUpdatedEvent {
+    pub treasury_id: u64,
+    pub signers: Vec<Address>,
+    pub approval_threshold: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HighValueThresholdUpdatedEvent {
+    pub treasury_id: u64,
+    pub token: Option<Address>,
+    pub threshold: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TransactionExecutedEvent {
@@ -149,6 +418,139 @@ pub struct AllowanceGrantedEvent {
     pub period_seconds: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimelockUpdatedEvent {
+    pub treasury_id: u64,
+    pub timelock_seconds: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionTimelockedEvent {
+    pub treasury_id: u64,
+    pub tx_id: u64,
+    pub executable_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionVetoedEvent {
+    pub treasury_id: u64,
+    pub tx_id: u64,
+    /// Vetoing signer, or the contract itself for a governance veto
+    pub vetoed_by: Address,
+    /// Governance proposal that vetoed the transaction, if any
+    pub proposal_id: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamProposedEvent {
+    pub treasury_id: u64,
+    pub stream_id: u64,
+    pub tx_id: u64,
+    pub recipient: Address,
+    pub rate_per_second: i128,
+    pub deposit: i128,
+    pub token: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamWithdrawnEvent {
+    pub stream_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamStatusChangedEvent {
+    pub stream_id: u64,
+    pub signer: Address,
+    pub status: StreamStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamCancelledEvent {
+    pub stream_id: u64,
+    pub signer: Address,
+    /// Accrued amount paid out to the recipient on cancellation
+    pub paid_to_recipient: i128,
+    /// Unstreamed amount returned to the treasury
+    pub refunded: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayrollProposedEvent {
+    pub treasury_id: u64,
+    pub order_id: u64,
+    pub tx_id: u64,
+    pub installment_amount: i128,
+    pub token: Option<Address>,
+    pub interval_seconds: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayrollRunEvent {
+    pub order_id: u64,
+    /// Payouts made in this run, retries included
+    pub paid: u32,
+    /// Payouts that failed in this run and are queued for retry
+    pub failed: u32,
+    pub next_payment_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutFailedEvent {
+    pub order_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub attempts: u32,
+    /// Whether the `payroll` budget, rather than the balance, was exhausted
+    pub budget_exceeded: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayrollCancelledEvent {
+    pub order_id: u64,
+    pub signer: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpenseApproversUpdatedEvent {
+    pub treasury_id: u64,
+    pub approvers: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpenseSubmittedEvent {
+    pub treasury_id: u64,
+    pub claim_id: u64,
+    pub claimant: Address,
+    pub amount: i128,
+    pub token: Option<Address>,
+    pub category: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpenseReviewedEvent {
+    pub claim_id: u64,
+    pub reviewer: Address,
+    pub status: ExpenseStatus,
+    /// Withdrawal transaction paying the claim (0 if rejected)
+    pub payment_tx_id: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EmergencyPauseEvent {
@@ -160,6 +562,64 @@ impl Treasury {
     pub fn is_signer(&self, addr: &Address) -> bool {
         self.signers.iter().any(|a| &a == addr)
     }
+
+    pub fn high_value_threshold_for(&self, token: &Option<Address>) -> i128 {
+        match token {
+            Some(addr) => self
+                .token_high_value_thresholds
+                .get(addr.clone())
+                .unwrap_or(self.high_value_threshold),
+            None => self.high_value_threshold,
+        }
+    }
+}
+
+impl Transaction {
+    /// Total amount per token moved out by this transaction: one entry for
+    /// single-token transactions, one per distinct token for a batch.
+    pub fn token_totals(&self) -> Vec<(Option<Address>, i128)> {
+        let mut totals: Vec<(Option<Address>, i128)> = Vec::new(self.legs.env());
+        if self.tx_type != TransactionType::BatchWithdrawal {
+            totals.push_back((self.token.clone(), self.amount));
+            return totals;
+        }
+        for leg in self.legs.iter() {
+            match totals.iter().position(|(token, _)| token == leg.token) {
+                Some(i) => {
+                    let (token, total) = totals.get(i as u32).unwrap();
+                    totals.set(i as u32, (token, total + leg.amount));
+                }
+                None => totals.push_back((leg.token.clone(), leg.amount)),
+            }
+        }
+        totals
+    }
+}
+
+impl Stream {
+    /// Total amount streamed to the recipient as of `now`, withdrawn or not.
+    pub fn streamed_amount(&self, now: u64) -> i128 {
+        let clock = match self.status {
+            StreamStatus::Pending => return 0,
+            // Everything owed was paid out when the stream closed
+            StreamStatus::Cancelled | StreamStatus::Completed => return self.withdrawn,
+            StreamStatus::Paused => self.paused_at,
+            StreamStatus::Active => now,
+        };
+        let stream_time = clock.saturating_sub(self.paused_seconds);
+        if stream_time < self.cliff_time {
+            return 0;
+        }
+        let elapsed = stream_time
+            .min(self.end_time)
+            .saturating_sub(self.start_time);
+        (self.rate_per_second * elapsed as i128).min(self.deposit)
+    }
+
+    /// Streamed amount the recipient has not withdrawn yet.
+    pub fn withdrawable_amount(&self, now: u64) -> i128 {
+        self.streamed_amount(now) - self.withdrawn
+    }
 }
 
 impl Allowance {
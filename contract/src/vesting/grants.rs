@@ -0,0 +1,363 @@
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::events::emit::emit_event;
+use crate::events::topics::{ACT_CREATED, ACT_FUNDED, ACT_RELEASED, ACT_REVOKED, MOD_VESTING};
+use crate::guild::storage::resolve_token;
+use crate::ledger::{self, SubAccount};
+use crate::treasury::management::credit_from_sub_account;
+use crate::treasury::multisig::{assert_signer, TX_EXPIRY_SECONDS};
+use crate::treasury::storage::{get_next_tx_id, get_transaction, get_treasury, store_transaction};
+use crate::treasury::types::{Transaction, TransactionStatus, TransactionType};
+use crate::vesting::storage::{add_beneficiary_grant, get_grant, get_next_grant_id, store_grant};
+use crate::vesting::types::{
+    GrantCreatedEvent, GrantFundedEvent, GrantRevokedEvent, GrantSource, GrantStatus,
+    TokensReleasedEvent, VestingGrant, VestingSchedule, VestingSummary,
+};
+
+/// Lock `total_amount` from `funder` in a new vesting grant for `beneficiary`.
+///
+/// The grant is active immediately. On revocation the unvested amount returns
+/// to the funder.
+///
+/// # Events emitted
+/// - `(vesting, created)` → `GrantCreatedEvent`
+pub fn create_grant(
+    env: &Env,
+    funder: Address,
+    beneficiary: Address,
+    token: Option<Address>,
+    total_amount: i128,
+    schedule: VestingSchedule,
+    revocable: bool,
+) -> u64 {
+    funder.require_auth();
+    validate_grant(total_amount, &schedule);
+
+    let grant_id = get_next_grant_id(env);
+    ledger::deposit(
+        env,
+        &SubAccount::Vesting(grant_id),
+        &resolve_token(env, &token),
+        &funder,
+        total_amount,
+    );
+
+    let grant = VestingGrant {
+        id: grant_id,
+        beneficiary,
+        token,
+        total_amount,
+        claimed_amount: 0,
+        schedule,
+        source: GrantSource::Funder(funder),
+        revocable,
+        status: GrantStatus::Active,
+        revoked_at: 0,
+        funding_tx_id: 0,
+        created_at: env.ledger().timestamp(),
+    };
+    save_new_grant(env, &grant);
+
+    grant_id
+}
+
+/// Propose a vesting grant paid from a treasury.
+///
+/// Creates the grant `Pending` together with a `VestingFunding` transaction
+/// for `total_amount`. Once that transaction is approved and executed the
+/// tokens are locked in the grant and it starts vesting. On revocation the
+/// unvested amount returns to the treasury.
+///
+/// # Events emitted
+/// - `(vesting, created)` → `GrantCreatedEvent`
+#[allow(clippy::too_many_arguments)]
+pub fn propose_treasury_grant(
+    env: &Env,
+    treasury_id: u64,
+    proposer: Address,
+    beneficiary: Address,
+    token: Option<Address>,
+    total_amount: i128,
+    schedule: VestingSchedule,
+    revocable: bool,
+) -> u64 {
+    validate_grant(total_amount, &schedule);
+
+    let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+    if treasury.paused {
+        panic!("treasury is paused");
+    }
+    assert_signer(env, &treasury, &proposer);
+
+    let grant_id = get_next_grant_id(env);
+    let tx_id = get_next_tx_id(env);
+    let now = env.ledger().timestamp();
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+
+    let tx = Transaction {
+        id: tx_id,
+        treasury_id,
+        tx_type: TransactionType::VestingFunding,
+        amount: total_amount,
+        token: token.clone(),
+        recipient: Some(beneficiary.clone()),
+        proposer,
+        approvals,
+        rejections: Vec::new(env),
+        status: TransactionStatus::Pending,
+        created_at: now,
+        expires_at: now + TX_EXPIRY_SECONDS,
+        reason: String::from_str(env, "vesting_grant"),
+        reference_id: Some(grant_id),
+        executable_at: 0,
+        replaces: None,
+        legs: Vec::new(env),
+    };
+    store_transaction(env, &tx);
+
+    let grant = VestingGrant {
+        id: grant_id,
+        beneficiary,
+        token,
+        total_amount,
+        claimed_amount: 0,
+        schedule,
+        source: GrantSource::Treasury(treasury_id),
+        revocable,
+        status: GrantStatus::Pending,
+        revoked_at: 0,
+        funding_tx_id: tx_id,
+        created_at: now,
+    };
+    save_new_grant(env, &grant);
+
+    grant_id
+}
+
+/// Start a treasury grant once its funding transaction has moved the tokens
+/// into the grant's sub-account. Called from `execute_transaction`.
+///
+/// # Events emitted
+/// - `(vesting, funded)` → `GrantFundedEvent`
+pub fn activate_treasury_grant(env: &Env, grant_id: u64) {
+    let mut grant = get_grant(env, grant_id).expect("grant not found");
+    if grant.status != GrantStatus::Pending {
+        panic!("grant not pending");
+    }
+    let treasury_id = match grant.source {
+        GrantSource::Treasury(id) => id,
+        GrantSource::Funder(_) => panic!("grant not treasury funded"),
+    };
+
+    grant.status = GrantStatus::Active;
+    store_grant(env, &grant);
+
+    emit_event(
+        env,
+        MOD_VESTING,
+        ACT_FUNDED,
+        GrantFundedEvent {
+            grant_id,
+            treasury_id,
+            tx_id: grant.funding_tx_id,
+        },
+    );
+}
+
+/// Release everything vested but not yet claimed to the beneficiary.
+/// Returns the amount released.
+///
+/// # Events emitted
+/// - `(vesting, released)` → `TokensReleasedEvent`
+pub fn claim_vested(env: &Env, grant_id: u64, beneficiary: Address) -> i128 {
+    beneficiary.require_auth();
+
+    let mut grant = get_grant(env, grant_id).expect("grant not found");
+    if grant.beneficiary != beneficiary {
+        panic!("only the beneficiary can claim");
+    }
+    if !matches!(grant.status, GrantStatus::Active | GrantStatus::Revoked) {
+        panic!("grant not claimable");
+    }
+
+    let amount = grant.vested_amount(env.ledger().timestamp()) - grant.claimed_amount;
+    if amount <= 0 {
+        panic!("nothing to claim");
+    }
+
+    ledger::withdraw(
+        env,
+        &SubAccount::Vesting(grant_id),
+        &resolve_token(env, &grant.token),
+        &beneficiary,
+        amount,
+    );
+
+    grant.claimed_amount += amount;
+    if grant.status == GrantStatus::Active && grant.claimed_amount == grant.total_amount {
+        grant.status = GrantStatus::Completed;
+    }
+    store_grant(env, &grant);
+
+    emit_event(
+        env,
+        MOD_VESTING,
+        ACT_RELEASED,
+        TokensReleasedEvent {
+            grant_id,
+            beneficiary,
+            amount,
+            total_claimed: grant.claimed_amount,
+        },
+    );
+
+    amount
+}
+
+/// Revoke a grant. Vesting stops; what has vested stays claimable by the
+/// beneficiary and the unvested amount returns to the grant's source.
+///
+/// Treasury grants are revoked by any treasury signer, funder grants by the
+/// funder. Only `revocable` grants can be revoked once active; a pending
+/// treasury grant is cancelled together with its funding transaction.
+///
+/// Returns the amount returned to the source.
+///
+/// # Events emitted
+/// - `(vesting, revoked)` → `GrantRevokedEvent`
+pub fn revoke_grant(env: &Env, grant_id: u64, caller: Address) -> i128 {
+    let mut grant = get_grant(env, grant_id).expect("grant not found");
+    match grant.source {
+        GrantSource::Treasury(treasury_id) => {
+            let treasury = get_treasury(env, treasury_id).expect("treasury not found");
+            assert_signer(env, &treasury, &caller);
+        }
+        GrantSource::Funder(ref funder) => {
+            caller.require_auth();
+            if &caller != funder {
+                panic!("only the funder can revoke");
+            }
+        }
+    }
+
+    let now = env.ledger().timestamp();
+    let returned_amount = match grant.status {
+        GrantStatus::Pending => {
+            let mut tx = get_transaction(env, grant.funding_tx_id).expect("tx not found");
+            if matches!(
+                tx.status,
+                TransactionStatus::Pending | TransactionStatus::Approved
+            ) {
+                tx.status = TransactionStatus::Rejected;
+                store_transaction(env, &tx);
+            }
+            0
+        }
+        GrantStatus::Active => {
+            if !grant.revocable {
+                panic!("grant is not revocable");
+            }
+            grant.total_amount - grant.vested_amount(now)
+        }
+        GrantStatus::Revoked | GrantStatus::Completed | GrantStatus::Cancelled => {
+            panic!("grant already closed")
+        }
+    };
+
+    grant.status = match grant.status {
+        GrantStatus::Pending => GrantStatus::Cancelled,
+        _ => GrantStatus::Revoked,
+    };
+    grant.revoked_at = now;
+    store_grant(env, &grant);
+
+    if returned_amount > 0 {
+        let account = SubAccount::Vesting(grant_id);
+        match grant.source {
+            GrantSource::Treasury(treasury_id) => credit_from_sub_account(
+                env,
+                treasury_id,
+                &account,
+                &grant.token,
+                returned_amount,
+                TransactionType::Refund,
+                "vesting_revoked",
+                grant_id,
+            ),
+            GrantSource::Funder(ref funder) => ledger::withdraw(
+                env,
+                &account,
+                &resolve_token(env, &grant.token),
+                funder,
+                returned_amount,
+            ),
+        }
+    }
+
+    emit_event(
+        env,
+        MOD_VESTING,
+        ACT_REVOKED,
+        GrantRevokedEvent {
+            grant_id,
+            revoked_by: caller,
+            vested_amount: grant.vested_amount(now),
+            returned_amount,
+        },
+    );
+
+    returned_amount
+}
+
+/// Vested, claimed and remaining amounts of a grant as of now.
+pub fn get_vesting_summary(env: &Env, grant_id: u64) -> VestingSummary {
+    let grant = get_grant(env, grant_id).expect("grant not found");
+    let vested_amount = grant.vested_amount(env.ledger().timestamp());
+    let remaining_amount = match grant.status {
+        GrantStatus::Revoked | GrantStatus::Cancelled => 0,
+        _ => grant.total_amount - vested_amount,
+    };
+
+    VestingSummary {
+        grant_id,
+        total_amount: grant.total_amount,
+        vested_amount,
+        claimed_amount: grant.claimed_amount,
+        claimable_amount: vested_amount - grant.claimed_amount,
+        remaining_amount,
+        status: grant.status,
+    }
+}
+
+fn validate_grant(total_amount: i128, schedule: &VestingSchedule) {
+    if total_amount <= 0 {
+        panic!("amount must be positive");
+    }
+    if schedule.end_time <= schedule.start_time {
+        panic!("end must be after start");
+    }
+    if schedule.cliff_time < schedule.start_time || schedule.cliff_time > schedule.end_time {
+        panic!("cliff must be within the schedule");
+    }
+}
+
+fn save_new_grant(env: &Env, grant: &VestingGrant) {
+    store_grant(env, grant);
+    add_beneficiary_grant(env, &grant.beneficiary, grant.id);
+
+    emit_event(
+        env,
+        MOD_VESTING,
+        ACT_CREATED,
+        GrantCreatedEvent {
+            grant_id: grant.id,
+            beneficiary: grant.beneficiary.clone(),
+            source: grant.source.clone(),
+            token: grant.token.clone(),
+            total_amount: grant.total_amount,
+            schedule: grant.schedule.clone(),
+            revocable: grant.revocable,
+        },
+    );
+}
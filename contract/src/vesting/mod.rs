@@ -0,0 +1,35 @@
+/// Vesting Module
+///
+/// Token grants that vest to a beneficiary on a cliff plus linear schedule.
+/// Grant tokens are locked up front in the grant's own ledger sub-account,
+/// either from a funder directly or from a treasury through a multisig-approved
+/// `VestingFunding` transaction. The beneficiary claims whatever has vested at
+/// any time.
+///
+/// Revocable grants can be revoked by their source (a treasury signer or the
+/// funder): vesting stops, the vested amount stays claimable and the unvested
+/// amount returns to the treasury or funder.
+///
+/// # Events emitted
+/// All events follow the `(module, action)` topic convention defined in
+/// `crate::events::topics`.
+///
+/// | Action                 | Topic                 | Payload struct        |
+/// |------------------------|-----------------------|-----------------------|
+/// | Create grant           | `(vesting, created)`  | `GrantCreatedEvent`   |
+/// | Treasury funding done  | `(vesting, funded)`   | `GrantFundedEvent`    |
+/// | Claim vested tokens    | `(vesting, released)` | `TokensReleasedEvent` |
+/// | Revoke grant           | `(vesting, revoked)`  | `GrantRevokedEvent`   |
+pub mod grants;
+pub mod storage;
+pub mod types;
+
+pub use grants::{
+    activate_treasury_grant, claim_vested, create_grant, get_vesting_summary,
+    propose_treasury_grant, revoke_grant,
+};
+#[allow(unused_imports)]
+pub use types::{GrantSource, GrantStatus, VestingGrant, VestingSchedule, VestingSummary};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,63 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::vesting::types::VestingGrant;
+
+#[contracttype]
+pub enum VestingStorageKey {
+    NextGrantId,
+    Grant(u64),
+    BeneficiaryGrants(Address), // Vec<grant_id>
+}
+
+pub fn get_next_grant_id(env: &Env) -> u64 {
+    let mut next: u64 = env
+        .storage()
+        .persistent()
+        .get(&VestingStorageKey::NextGrantId)
+        .unwrap_or(1);
+    let current = next;
+    next += 1;
+    env.storage()
+        .persistent()
+        .set(&VestingStorageKey::NextGrantId, &next);
+    current
+}
+
+pub fn store_grant(env: &Env, grant: &VestingGrant) {
+    env.storage()
+        .persistent()
+        .set(&VestingStorageKey::Grant(grant.id), grant);
+}
+
+pub fn get_grant(env: &Env, grant_id: u64) -> Option<VestingGrant> {
+    env.storage()
+        .persistent()
+        .get(&VestingStorageKey::Grant(grant_id))
+}
+
+pub fn add_beneficiary_grant(env: &Env, beneficiary: &Address, grant_id: u64) {
+    let key = VestingStorageKey::BeneficiaryGrants(beneficiary.clone());
+    let mut ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    ids.push_back(grant_id);
+    env.storage().persistent().set(&key, &ids);
+}
+
+pub fn get_beneficiary_grants(env: &Env, beneficiary: &Address) -> Vec<VestingGrant> {
+    let ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&VestingStorageKey::BeneficiaryGrants(beneficiary.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut result = Vec::new(env);
+    for id in ids.iter() {
+        if let Some(grant) = get_grant(env, id) {
+            result.push_back(grant);
+        }
+    }
+    result
+}
@@ -0,0 +1,321 @@
+//! Vesting Tests
+//!
+//! Coverage for cliff plus linear vesting, claims, revocation and treasury
+//! funded grants.
+
+use super::*;
+use crate::ledger::SubAccount;
+use crate::StellarGuildsContract;
+use crate::StellarGuildsContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+use soroban_sdk::{token, Address, Env, String};
+
+// ============ Test Helpers ============
+
+fn setup_env() -> Env {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    env
+}
+
+fn set_ledger_timestamp(env: &Env, timestamp: u64) {
+    env.ledger().set(LedgerInfo {
+        timestamp,
+        protocol_version: 20,
+        sequence_number: 0,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 100,
+        min_persistent_entry_ttl: 100,
+        max_entry_ttl: 1000000,
+    });
+}
+
+fn register_and_init_contract(env: &Env) -> Address {
+    let contract_id = env.register_contract(None, StellarGuildsContract);
+    let client = StellarGuildsContractClient::new(env, &contract_id);
+    let native = env.register_stellar_asset_contract_v2(Address::generate(env));
+    client
+        .mock_all_auths()
+        .initialize(&Address::generate(env), &native.address());
+    contract_id
+}
+
+fn create_mock_token(env: &Env, admin: &Address) -> Address {
+    env.register_stellar_asset_contract_v2(admin.clone())
+        .address()
+}
+
+fn mint_tokens(env: &Env, token: &Address, to: &Address, amount: i128) {
+    token::StellarAssetClient::new(env, token).mint(to, &amount);
+}
+
+/// 1000 tokens vesting linearly from 1000 to 2000 with a cliff at 1250
+fn schedule() -> VestingSchedule {
+    VestingSchedule {
+        start_time: 1000,
+        cliff_time: 1250,
+        end_time: 2000,
+    }
+}
+
+/// Create a treasury with signers owner + signer (threshold 2) holding 2000 of `token`.
+fn setup_treasury(
+    env: &Env,
+    client: &StellarGuildsContractClient<'_>,
+    owner: &Address,
+    token: &Address,
+) -> (u64, Address) {
+    let guild_id = client.create_guild(
+        &String::from_str(env, "Guild"),
+        &String::from_str(env, "Vesting test guild"),
+        owner,
+    );
+
+    let signer = Address::generate(env);
+    let mut signers = soroban_sdk::Vec::new(env);
+    signers.push_back(owner.clone());
+    signers.push_back(signer.clone());
+    let treasury_id = client.initialize_treasury(&guild_id, &signers, &2u32);
+    mint_tokens(env, token, owner, 2000);
+    client.deposit_treasury(&treasury_id, owner, &2000i128, &Some(token.clone()));
+
+    (treasury_id, signer)
+}
+
+// ============ Funder Grants ============
+
+#[test]
+fn test_grant_vests_after_cliff_and_revocation_returns_unvested() {
+    let env = setup_env();
+    env.mock_all_auths();
+    set_ledger_timestamp(&env, 1000);
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let funder = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let token = create_mock_token(&env, &funder);
+    mint_tokens(&env, &token, &funder, 1000);
+    let token_client = token::Client::new(&env, &token);
+
+    let grant_id = client.create_vesting_grant(
+        &funder,
+        &beneficiary,
+        &Some(token.clone()),
+        &1000i128,
+        &schedule(),
+        &true,
+    );
+    assert_eq!(token_client.balance(&funder), 0);
+
+    set_ledger_timestamp(&env, 1200);
+    assert_eq!(client.get_vesting_summary(&grant_id).vested_amount, 0);
+    assert!(client.try_claim_vested(&grant_id, &beneficiary).is_err());
+
+    // Everything accrued since the start vests at the cliff
+    set_ledger_timestamp(&env, 1250);
+    assert_eq!(client.claim_vested(&grant_id, &beneficiary), 250);
+
+    set_ledger_timestamp(&env, 1500);
+    let summary = client.get_vesting_summary(&grant_id);
+    assert_eq!(summary.vested_amount, 500);
+    assert_eq!(summary.claimed_amount, 250);
+    assert_eq!(summary.claimable_amount, 250);
+    assert_eq!(summary.remaining_amount, 500);
+
+    assert_eq!(client.revoke_vesting_grant(&grant_id, &funder), 500);
+    assert_eq!(token_client.balance(&funder), 500);
+
+    // Vested tokens stay claimable after revocation, nothing more vests
+    set_ledger_timestamp(&env, 1900);
+    assert_eq!(client.claim_vested(&grant_id, &beneficiary), 250);
+    assert_eq!(token_client.balance(&beneficiary), 500);
+
+    let summary = client.get_vesting_summary(&grant_id);
+    assert_eq!(summary.status, GrantStatus::Revoked);
+    assert_eq!(summary.claimable_amount, 0);
+    assert_eq!(summary.remaining_amount, 0);
+    assert_eq!(client.get_beneficiary_grants(&beneficiary).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "grant is not revocable")]
+fn test_revoke_irrevocable_grant_fails() {
+    let env = setup_env();
+    env.mock_all_auths();
+    set_ledger_timestamp(&env, 1000);
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let funder = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let token = create_mock_token(&env, &funder);
+    mint_tokens(&env, &token, &funder, 1000);
+
+    let grant_id = client.create_vesting_grant(
+        &funder,
+        &beneficiary,
+        &Some(token),
+        &1000i128,
+        &schedule(),
+        &false,
+    );
+    client.revoke_vesting_grant(&grant_id, &funder);
+}
+
+#[test]
+#[should_panic(expected = "only the beneficiary can claim")]
+fn test_claim_by_non_beneficiary_fails() {
+    let env = setup_env();
+    env.mock_all_auths();
+    set_ledger_timestamp(&env, 1000);
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let funder = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let token = create_mock_token(&env, &funder);
+    mint_tokens(&env, &token, &funder, 1000);
+
+    let grant_id = client.create_vesting_grant(
+        &funder,
+        &beneficiary,
+        &Some(token),
+        &1000i128,
+        &schedule(),
+        &true,
+    );
+
+    set_ledger_timestamp(&env, 1500);
+    client.claim_vested(&grant_id, &funder);
+}
+
+// ============ Treasury Grants ============
+
+#[test]
+fn test_treasury_grant_vests_fully() {
+    let env = setup_env();
+    env.mock_all_auths();
+    set_ledger_timestamp(&env, 1000);
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+    let (treasury_id, signer) = setup_treasury(&env, &client, &owner, &token);
+
+    let grant_id = client.propose_treasury_vesting_grant(
+        &treasury_id,
+        &owner,
+        &beneficiary,
+        &Some(token.clone()),
+        &1000i128,
+        &schedule(),
+        &true,
+    );
+    let grant = client.get_vesting_grant(&grant_id);
+    assert_eq!(grant.status, GrantStatus::Pending);
+
+    client.approve_transaction(&grant.funding_tx_id, &signer);
+    client.execute_transaction(&grant.funding_tx_id, &owner);
+    assert_eq!(
+        client.get_treasury_balance(&treasury_id, &Some(token.clone())),
+        1000
+    );
+    assert_eq!(
+        client.get_vesting_grant(&grant_id).status,
+        GrantStatus::Active
+    );
+
+    set_ledger_timestamp(&env, 3000);
+    assert_eq!(client.claim_vested(&grant_id, &beneficiary), 1000);
+    assert_eq!(
+        client.get_vesting_grant(&grant_id).status,
+        GrantStatus::Completed
+    );
+    assert_eq!(token::Client::new(&env, &token).balance(&beneficiary), 1000);
+}
+
+#[test]
+fn test_revoked_treasury_grant_returns_unvested_to_treasury() {
+    let env = setup_env();
+    env.mock_all_auths();
+    set_ledger_timestamp(&env, 1000);
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+    let (treasury_id, signer) = setup_treasury(&env, &client, &owner, &token);
+
+    let grant_id = client.propose_treasury_vesting_grant(
+        &treasury_id,
+        &owner,
+        &beneficiary,
+        &Some(token.clone()),
+        &1000i128,
+        &schedule(),
+        &true,
+    );
+    let tx_id = client.get_vesting_grant(&grant_id).funding_tx_id;
+    client.approve_transaction(&tx_id, &signer);
+    client.execute_transaction(&tx_id, &owner);
+
+    set_ledger_timestamp(&env, 1600);
+    assert_eq!(client.revoke_vesting_grant(&grant_id, &signer), 400);
+    assert_eq!(
+        client.get_treasury_balance(&treasury_id, &Some(token.clone())),
+        1400
+    );
+    assert_eq!(
+        client.get_sub_account_balance(&SubAccount::Vesting(grant_id), &Some(token.clone())),
+        600
+    );
+
+    let summary = client.get_spending_summary(&treasury_id, &0u64, &5000u64);
+    assert_eq!(summary.total_withdrawals, 1000);
+    assert_eq!(summary.total_deposits, 2000);
+    assert_eq!(summary.total_refunds, 400);
+    assert_eq!(summary.net_flow, 1400);
+}
+
+#[test]
+fn test_revoke_pending_treasury_grant_rejects_funding() {
+    let env = setup_env();
+    env.mock_all_auths();
+    set_ledger_timestamp(&env, 1000);
+
+    let contract_id = register_and_init_contract(&env);
+    let client = StellarGuildsContractClient::new(&env, &contract_id);
+
+    let owner = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let token = create_mock_token(&env, &owner);
+    let (treasury_id, signer) = setup_treasury(&env, &client, &owner, &token);
+
+    let grant_id = client.propose_treasury_vesting_grant(
+        &treasury_id,
+        &owner,
+        &beneficiary,
+        &Some(token),
+        &1000i128,
+        &schedule(),
+        &false,
+    );
+    let tx_id = client.get_vesting_grant(&grant_id).funding_tx_id;
+
+    assert_eq!(client.revoke_vesting_grant(&grant_id, &signer), 0);
+    assert_eq!(
+        client.get_vesting_grant(&grant_id).status,
+        GrantStatus::Cancelled
+    );
+    assert!(client.try_approve_transaction(&tx_id, &signer).is_err());
+}
@@ -0,0 +1,136 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Where the tokens of a grant came from, and where unvested tokens return
+/// to on revocation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GrantSource {
+    /// Funded by a treasury through a `VestingFunding` transaction
+    Treasury(u64),
+    /// Funded directly by an address
+    Funder(Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GrantStatus {
+    /// Waiting for its treasury funding transaction to execute
+    Pending,
+    Active,
+    /// Revoked; the amount vested at revocation stays claimable
+    Revoked,
+    /// Fully vested and claimed
+    Completed,
+    /// Closed before its treasury funding executed
+    Cancelled,
+}
+
+/// Cliff plus linear vesting schedule
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// Vesting accrues linearly from this timestamp
+    pub start_time: u64,
+    /// Nothing vests before this timestamp; the amount accrued since
+    /// `start_time` vests at once when it passes
+    pub cliff_time: u64,
+    /// Everything is vested at this timestamp
+    pub end_time: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingGrant {
+    pub id: u64,
+    pub beneficiary: Address,
+    pub token: Option<Address>,
+    pub total_amount: i128,
+    pub claimed_amount: i128,
+    pub schedule: VestingSchedule,
+    pub source: GrantSource,
+    pub revocable: bool,
+    pub status: GrantStatus,
+    /// Timestamp of revocation (0 if not revoked)
+    pub revoked_at: u64,
+    /// Treasury transaction funding the grant (0 for funder grants)
+    pub funding_tx_id: u64,
+    pub created_at: u64,
+}
+
+/// Vested, claimed and remaining amounts of a grant at query time
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSummary {
+    pub grant_id: u64,
+    pub total_amount: i128,
+    pub vested_amount: i128,
+    pub claimed_amount: i128,
+    /// Vested but not yet claimed
+    pub claimable_amount: i128,
+    /// Not vested yet (0 once revoked)
+    pub remaining_amount: i128,
+    pub status: GrantStatus,
+}
+
+// ============ Events ============
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantCreatedEvent {
+    pub grant_id: u64,
+    pub beneficiary: Address,
+    pub source: GrantSource,
+    pub token: Option<Address>,
+    pub total_amount: i128,
+    pub schedule: VestingSchedule,
+    pub revocable: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantFundedEvent {
+    pub grant_id: u64,
+    pub treasury_id: u64,
+    pub tx_id: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensReleasedEvent {
+    pub grant_id: u64,
+    pub beneficiary: Address,
+    pub amount: i128,
+    pub total_claimed: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantRevokedEvent {
+    pub grant_id: u64,
+    pub revoked_by: Address,
+    /// Vested amount that stays claimable by the beneficiary
+    pub vested_amount: i128,
+    /// Unvested amount returned to the grant's source
+    pub returned_amount: i128,
+}
+
+impl VestingGrant {
+    /// Amount vested as of `now`; vesting stops at revocation.
+    pub fn vested_amount(&self, now: u64) -> i128 {
+        let clock = match self.status {
+            GrantStatus::Pending | GrantStatus::Cancelled => return 0,
+            GrantStatus::Revoked => self.revoked_at,
+            GrantStatus::Active | GrantStatus::Completed => now,
+        };
+        let schedule = &self.schedule;
+        if clock < schedule.cliff_time {
+            return 0;
+        }
+        if clock >= schedule.end_time {
+            return self.total_amount;
+        }
+        let elapsed = (clock - schedule.start_time) as i128;
+        let duration = (schedule.end_time - schedule.start_time) as i128;
+        self.total_amount * elapsed / duration
+    }
+}